@@ -0,0 +1,334 @@
+use super::error::{BlockchainError, Result};
+
+/// Parses an amount given in atoms. Only plain integers are accepted; inputs
+/// such as `"1e18"` or `"1.0"` are rejected instead of being rounded.
+pub fn parse_atoms(raw: &str) -> Result<i128> {
+    let raw = raw.trim();
+    let digits = raw.strip_prefix('-').unwrap_or(raw);
+    let reason = if digits.is_empty() {
+        Some("is empty")
+    } else if digits.contains(['e', 'E']) {
+        Some("uses scientific notation; pass the exact integer number of atoms")
+    } else if digits.contains('.') {
+        Some("has a decimal point; atoms are integers (use amount_display for whole units)")
+    } else if !digits.chars().all(|c| c.is_ascii_digit()) {
+        Some("is not an integer")
+    } else {
+        None
+    };
+    if let Some(reason) = reason {
+        return Err(BlockchainError::ValidationFailed(format!(
+            "amount {:?} {}",
+            raw, reason
+        )));
+    }
+
+    raw.parse()
+        .map_err(|_| BlockchainError::ValidationFailed(format!("amount {:?} overflows i128", raw)))
+}
+
+/// Converts a human-readable amount such as `"1.5"` into atoms for an asset with
+/// `decimals` decimal places. Trailing fractional zeros are ignored; any other
+/// fractional digits beyond `decimals` are rejected rather than rounded.
+pub fn to_base_units(display: &str, decimals: u8) -> Result<Amount> {
+    let display = display.trim();
+    if display.starts_with('-') {
+        return Err(BlockchainError::ValidationFailed(
+            "amount must be positive".into(),
+        ));
+    }
+
+    let (whole, frac) = display.split_once('.').unwrap_or((display, ""));
+    let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+    if (whole.is_empty() && frac.is_empty()) || !is_digits(whole) || !is_digits(frac) {
+        return Err(BlockchainError::ValidationFailed(format!(
+            "invalid amount: {}",
+            display
+        )));
+    }
+
+    let frac = frac.trim_end_matches('0');
+    if frac.len() > decimals as usize {
+        return Err(BlockchainError::ValidationFailed(format!(
+            "amount has more than {} fractional digits",
+            decimals
+        )));
+    }
+
+    let overflow = || BlockchainError::ValidationFailed("amount overflows i128".into());
+    let scale = 10i128.checked_pow(decimals as u32).ok_or_else(overflow)?;
+    let whole_val: i128 = if whole.is_empty() {
+        0
+    } else {
+        whole.parse().map_err(|_| overflow())?
+    };
+    let frac_val: i128 = if frac.is_empty() {
+        0
+    } else {
+        format!("{:0<width$}", frac, width = decimals as usize)
+            .parse()
+            .map_err(|_| overflow())?
+    };
+
+    let total = whole_val
+        .checked_mul(scale)
+        .and_then(|v| v.checked_add(frac_val))
+        .ok_or_else(overflow)?;
+    if total == 0 {
+        return Err(BlockchainError::ValidationFailed(
+            "amount must be positive".into(),
+        ));
+    }
+    Ok(Amount(total))
+}
+
+/// Formats atoms as a human-readable amount, without trailing fractional zeros.
+pub fn from_base_units(raw: i128, decimals: u8) -> String {
+    let sign = if raw < 0 { "-" } else { "" };
+    let digits = raw.unsigned_abs().to_string();
+    let decimals = decimals as usize;
+
+    if decimals == 0 {
+        return format!("{}{}", sign, digits);
+    }
+
+    let padded = format!("{:0>width$}", digits, width = decimals + 1);
+    let (whole, frac) = padded.split_at(padded.len() - decimals);
+    let frac = frac.trim_end_matches('0');
+
+    if frac.is_empty() {
+        format!("{}{}", sign, whole)
+    } else {
+        format!("{}{}.{}", sign, whole, frac)
+    }
+}
+
+/// A non-negative quantity of atoms. Reads from a JSON integer or an integer
+/// string and is written back as a string, so values beyond 2^53 survive
+/// clients that parse JSON numbers as doubles.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Amount(i128);
+
+impl Amount {
+    pub const ZERO: Self = Self(0);
+
+    pub fn new(atoms: i128) -> Result<Self> {
+        if atoms < 0 {
+            return Err(BlockchainError::ValidationFailed(format!(
+                "amount {} is negative",
+                atoms
+            )));
+        }
+        Ok(Self(atoms))
+    }
+
+    pub fn atoms(self) -> i128 {
+        self.0
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Self)
+    }
+
+    /// `None` on overflow or when the result would be negative.
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).filter(|v| *v >= 0).map(Self)
+    }
+
+    /// Whole units for an asset with `decimals` decimal places, e.g. `"1.5"`.
+    pub fn to_display(self, decimals: u8) -> String {
+        from_base_units(self.0, decimals)
+    }
+}
+
+impl From<u64> for Amount {
+    fn from(atoms: u64) -> Self {
+        Self(atoms as i128)
+    }
+}
+
+impl std::fmt::Display for Amount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::str::FromStr for Amount {
+    type Err = BlockchainError;
+
+    fn from_str(raw: &str) -> Result<Self> {
+        Self::new(parse_atoms(raw)?)
+    }
+}
+
+impl serde::Serialize for Amount {
+    fn serialize<S: serde::Serializer>(&self, ser: S) -> std::result::Result<S::Ok, S::Error> {
+        ser.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Amount {
+    fn deserialize<D: serde::Deserializer<'de>>(de: D) -> std::result::Result<Self, D::Error> {
+        struct AmountVisitor;
+
+        impl serde::de::Visitor<'_> for AmountVisitor {
+            type Value = Amount;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a non-negative integer number of atoms, or a string holding one")
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> std::result::Result<Amount, E> {
+                Ok(Amount::from(v))
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, v: i64) -> std::result::Result<Amount, E> {
+                Amount::new(v as i128).map_err(E::custom)
+            }
+
+            fn visit_u128<E: serde::de::Error>(self, v: u128) -> std::result::Result<Amount, E> {
+                i128::try_from(v)
+                    .map(Amount)
+                    .map_err(|_| E::custom(format!("amount {} overflows i128", v)))
+            }
+
+            fn visit_i128<E: serde::de::Error>(self, v: i128) -> std::result::Result<Amount, E> {
+                Amount::new(v).map_err(E::custom)
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> std::result::Result<Amount, E> {
+                v.parse().map_err(E::custom)
+            }
+        }
+
+        de.deserialize_any(AmountVisitor)
+    }
+}
+
+impl schemars::JsonSchema for Amount {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Amount".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "Amount in atoms, as an integer or an integer string",
+            "anyOf": [
+                { "type": "string", "pattern": "^[0-9]+$" },
+                { "type": "integer", "minimum": 0 }
+            ]
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_atoms_accepts_plain_integers() {
+        assert_eq!(parse_atoms("0").unwrap(), 0);
+        assert_eq!(parse_atoms(" 1500000000 ").unwrap(), 1_500_000_000);
+        assert_eq!(parse_atoms(&i128::MAX.to_string()).unwrap(), i128::MAX);
+    }
+
+    #[test]
+    fn parse_atoms_rejects_non_integers() {
+        for raw in [
+            "", "-", "1e18", "1E18", "1.0", ".5", "1,000", "0x10", "12abc", "+1",
+        ] {
+            assert!(
+                matches!(parse_atoms(raw), Err(BlockchainError::ValidationFailed(_))),
+                "{:?} should be rejected",
+                raw
+            );
+        }
+    }
+
+    #[test]
+    fn parse_atoms_rejects_overflow() {
+        let too_big = format!("{}0", i128::MAX);
+        let err = parse_atoms(&too_big).unwrap_err();
+        assert!(err.to_string().contains("overflows i128"));
+    }
+
+    #[test]
+    fn amounts_deserialize_only_from_integers() {
+        assert_eq!(
+            serde_json::from_str::<Amount>("\"25\"").unwrap(),
+            Amount::new(25).unwrap()
+        );
+        assert_eq!(serde_json::from_str::<Amount>("25").unwrap().atoms(), 25);
+        for raw in ["\"1e18\"", "\"1.0\"", "1.5", "-1", "\"-1\""] {
+            assert!(serde_json::from_str::<Amount>(raw).is_err(), "{raw}");
+        }
+    }
+
+    #[test]
+    fn to_base_units_scales_by_decimals() {
+        assert_eq!(to_base_units("1.5", 9).unwrap().atoms(), 1_500_000_000);
+        assert_eq!(to_base_units(" 42 ", 9).unwrap().atoms(), 42_000_000_000);
+        assert_eq!(to_base_units(".25", 2).unwrap().atoms(), 25);
+        assert_eq!(to_base_units("7", 0).unwrap().atoms(), 7);
+    }
+
+    #[test]
+    fn to_base_units_ignores_trailing_fractional_zeros() {
+        assert_eq!(to_base_units("2.10", 9).unwrap().atoms(), 2_100_000_000);
+        assert_eq!(
+            to_base_units("1.500000000000", 9).unwrap().atoms(),
+            1_500_000_000
+        );
+        assert_eq!(to_base_units("3.000", 0).unwrap().atoms(), 3);
+    }
+
+    #[test]
+    fn to_base_units_rejects_excess_precision() {
+        let err = to_base_units("1.0000000001", 9).unwrap_err();
+        assert!(err.to_string().contains("more than 9 fractional digits"));
+        assert!(to_base_units("0.5", 0).is_err());
+    }
+
+    #[test]
+    fn to_base_units_rejects_non_positive_and_malformed() {
+        for raw in ["-1", "0", "0.000", ".", "", "1.2.3", "1e9", "abc"] {
+            assert!(
+                matches!(
+                    to_base_units(raw, 9),
+                    Err(BlockchainError::ValidationFailed(_))
+                ),
+                "{:?} should be rejected",
+                raw
+            );
+        }
+    }
+
+    #[test]
+    fn to_base_units_rejects_overflow() {
+        let err = to_base_units(&i128::MAX.to_string(), 9).unwrap_err();
+        assert!(err.to_string().contains("overflows i128"));
+        assert!(to_base_units("1", 39).is_err());
+    }
+
+    #[test]
+    fn from_base_units_trims_trailing_zeros() {
+        assert_eq!(from_base_units(1_500_000_000, 9), "1.5");
+        assert_eq!(from_base_units(1, 9), "0.000000001");
+        assert_eq!(from_base_units(42_000_000_000, 9), "42");
+        assert_eq!(from_base_units(0, 9), "0");
+        assert_eq!(from_base_units(-25, 2), "-0.25");
+        assert_eq!(from_base_units(7, 0), "7");
+    }
+
+    #[test]
+    fn base_units_round_trip() {
+        for display in ["1.5", "0.000000001", "42", "1234.56789", "170141183460"] {
+            let atoms = to_base_units(display, 9).unwrap().atoms();
+            assert_eq!(from_base_units(atoms, 9), display);
+        }
+    }
+}
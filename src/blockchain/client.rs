@@ -1,31 +1,199 @@
 use super::{
-    error::{BlockchainError, Result},
+    amount,
+    error::{
+        asset_not_found_if_missing, block_not_found_if_missing, check_content_type,
+        not_a_contract_if_missing, parse_success_body, status_error, unsupported_if_missing,
+        BlockchainError, Result,
+    },
+    http::{
+        CircuitBreaker, HttpExecutor, HttpRequest, HttpResponse, RequestLimiter, ReqwestExecutor,
+    },
+    shared::{
+        build_transfer_locally, check_richlist_symbol, contract_call_body, contract_keys_path,
+        decode_blob, decode_state_value, encode_args, endpoint_url, expect_blob_signed,
+        parse_asset_balance, parse_balance_at_height, parse_block_entries, parse_contract_info,
+        parse_entry_txs, parse_fee_schedule, parse_network_info, parse_supply, parse_top_holders,
+        parse_validator_details, raw_transaction_hash, state_string, transaction_hash,
+    },
     types::*,
 };
 use crate::wasm::tx;
-use reqwest::{header, Client, Response};
-use std::time::Duration;
+use futures::{stream, StreamExt, TryStreamExt};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tokio_retry::{
     strategy::{jitter, ExponentialBackoff},
     Retry,
 };
-use tracing::warn;
+use tracing::{debug, warn};
+
+const DEFAULT_MAX_BLOCK_RANGE: u64 = 100;
+/// Node replies are small JSON documents; even a full block range stays well under this.
+const DEFAULT_MAX_RESPONSE_BYTES: usize = 8 * 1024 * 1024;
+const DEFAULT_USER_AGENT: &str = concat!("amadeus-mcp/", env!("CARGO_PKG_VERSION"));
+const BLOCK_RANGE_CONCURRENCY: usize = 8;
+const BALANCE_LOOKUP_CONCURRENCY: usize = 8;
+const CONTRACT_STATE_CONCURRENCY: usize = 8;
+const HISTORY_PAGE_SIZE: u32 = 100;
+const HISTORY_MAX_PAGES: usize = 50;
+const SEARCH_HISTORY_CAP: usize = 1000;
+const SEARCH_DEFAULT_LIMIT: u32 = 50;
+/// Supply moves slowly, so repeated lookups within this window reuse the last answer.
+const SUPPLY_CACHE_TTL: Duration = Duration::from_secs(60);
+/// An accepted transaction resubmitted within this window is answered from
+/// memory instead of being broadcast again.
+const SUBMIT_DEDUP_TTL: Duration = Duration::from_secs(300);
+
+/// Naming contract mapping `name:<name>` to an address and `addr:<address>` back to its name.
+const NAME_CONTRACT: &str = "Names";
 
 #[derive(Clone)]
 pub struct BlockchainClient {
-    client: Client,
+    executor: Arc<dyn HttpExecutor>,
+    limiter: RequestLimiter,
+    breaker: CircuitBreaker,
+    max_block_range: u64,
+    auth_header: Option<(String, String)>,
+    user_agent: String,
+    max_response_bytes: usize,
+    offline_build: bool,
+    poll_backoff: PollBackoff,
+    signing_params: tx::SigningParams,
+    supply_cache: Arc<Mutex<HashMap<(String, String), (Instant, SupplyInfo)>>>,
+    network_info_cache: Arc<Mutex<HashMap<String, NetworkInfo>>>,
+    recent_submissions: Arc<Mutex<HashMap<(String, String), (Instant, SubmitResponse)>>>,
 }
 
 impl BlockchainClient {
     pub fn new(_base_url: String) -> Result<Self> {
-        let client = Client::builder()
-            .timeout(Duration::from_secs(30))
-            .pool_idle_timeout(Duration::from_secs(90))
-            .user_agent("amadeus-mcp/0.1.0")
-            .build()
-            .map_err(BlockchainError::HttpRequest)?;
+        Ok(Self::with_executor(Arc::new(ReqwestExecutor::new()?)))
+    }
+
+    pub fn with_executor(executor: Arc<dyn HttpExecutor>) -> Self {
+        Self {
+            executor,
+            limiter: RequestLimiter::default(),
+            breaker: CircuitBreaker::default(),
+            max_block_range: DEFAULT_MAX_BLOCK_RANGE,
+            auth_header: None,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            max_response_bytes: DEFAULT_MAX_RESPONSE_BYTES,
+            offline_build: false,
+            poll_backoff: PollBackoff::default(),
+            signing_params: tx::SigningParams::default(),
+            supply_cache: Arc::default(),
+            network_info_cache: Arc::default(),
+            recent_submissions: Arc::default(),
+        }
+    }
+
+    /// Builds transfers without contacting the node: the balance preflight is
+    /// skipped and amounts must be given in atoms, since decimals are unknown.
+    pub fn with_offline_build(mut self, enabled: bool) -> Self {
+        self.offline_build = enabled;
+        self
+    }
+
+    /// Rebuilds the HTTP transport with its own connection pool limits,
+    /// replacing any executor set through `with_executor`. The worker client
+    /// has no equivalent: `worker::Fetch` manages its own connections.
+    pub fn with_pool_config(
+        mut self,
+        max_idle_per_host: usize,
+        idle_timeout: Duration,
+    ) -> Result<Self> {
+        self.executor = Arc::new(ReqwestExecutor::with_pool_config(
+            max_idle_per_host,
+            idle_timeout,
+        )?);
+        Ok(self)
+    }
+
+    /// Fails fast with `CircuitOpen` for `cooldown` once `failure_threshold`
+    /// transport errors or 5xx responses from the same node occur within `window`.
+    pub fn with_circuit_breaker(
+        mut self,
+        failure_threshold: u32,
+        window: Duration,
+        cooldown: Duration,
+    ) -> Self {
+        self.breaker = CircuitBreaker::new(failure_threshold, window, cooldown);
+        self
+    }
+
+    /// Allows at most `max` requests in flight; further requests wait for a slot.
+    pub fn with_max_concurrency(mut self, max: usize) -> Self {
+        self.limiter = self.limiter.with_max_concurrency(max);
+        self
+    }
+
+    /// Spaces request starts at least `interval` apart, for strictly rate-limited nodes.
+    pub fn with_min_request_interval(mut self, interval: Duration) -> Self {
+        self.limiter = self.limiter.with_min_interval(interval);
+        self
+    }
+
+    /// Sends `name: value` with every request, for nodes behind an API gateway.
+    pub fn with_auth_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.auth_header = Some((name.into(), value.into()));
+        self
+    }
+
+    /// Replaces the default `amadeus-mcp/<version>` User-Agent sent to nodes.
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// Sets how `wait_for_transaction` spaces its status polls.
+    pub fn with_poll_backoff(mut self, backoff: PollBackoff) -> Self {
+        self.poll_backoff = backoff;
+        self
+    }
+
+    /// Domain separation tag `verify_signature` and `verify_transaction_blob`
+    /// check signatures under; the default is `tx::SIG_DST`.
+    pub fn with_signing_params(mut self, params: tx::SigningParams) -> Self {
+        self.signing_params = params;
+        self
+    }
+
+    pub fn signing_params(&self) -> &tx::SigningParams {
+        &self.signing_params
+    }
+
+    /// Rejects node replies larger than `limit` bytes with `ResponseTooLarge`.
+    pub fn with_max_response_bytes(mut self, limit: usize) -> Self {
+        self.max_response_bytes = limit;
+        self
+    }
+
+    pub fn with_max_block_range(mut self, max_block_range: u64) -> Self {
+        self.max_block_range = max_block_range;
+        self
+    }
+
+    #[tracing::instrument(skip(self, req), fields(signer=%req.signer))]
+    pub async fn check_spendable_balance(&self, req: &TransactionRequest, url: &str) -> Result<()> {
+        let Some((symbol, amount)) = req.spend() else {
+            return Ok(());
+        };
 
-        Ok(Self { client })
+        let balance = self.get_account_balance(&req.signer, url).await?;
+        let available = balance
+            .balances
+            .iter()
+            .find(|b| b.symbol == symbol)
+            .map_or(0, |b| b.flat.atoms());
+
+        if amount > available {
+            return Err(BlockchainError::InsufficientBalance {
+                required: format!("{} {}", amount, symbol),
+                available: format!("{} {}", available, symbol),
+            });
+        }
+        Ok(())
     }
 
     #[tracing::instrument(skip(self), fields(contract=%req.contract, function=%req.function))]
@@ -37,17 +205,7 @@ impl BlockchainClient {
             .into_vec()
             .map_err(|_| BlockchainError::ValidationFailed("invalid signer base58".into()))?;
 
-        let args: Result<Vec<Vec<u8>>> = req.args.iter().map(|arg| match arg {
-            Argument::String(s) => Ok(s.as_bytes().to_vec()),
-            Argument::Number(n) => Ok(n.to_string().as_bytes().to_vec()),
-            Argument::Base58 { b58 } => bs58::decode(b58)
-                .into_vec()
-                .map_err(|_| BlockchainError::ValidationFailed("invalid base58 arg".into())),
-            Argument::Hex { hex } => hex::decode(hex.trim_start_matches("0x"))
-                .map_err(|_| BlockchainError::ValidationFailed("invalid hex arg".into())),
-            Argument::Utf8 { utf8 } => Ok(utf8.as_bytes().to_vec()),
-        }).collect();
-        let args = args?;
+        let args = encode_args(&req.args)?;
 
         let attached_symbol = req.attached_symbol.as_ref().map(|s| s.as_bytes());
         let attached_amount = req.attached_amount.as_ref().map(|s| s.as_bytes());
@@ -60,7 +218,8 @@ impl BlockchainClient {
             attached_symbol,
             attached_amount,
             req.nonce,
-        ).map_err(|e| BlockchainError::ValidationFailed(e.into()))?;
+        )
+        .map_err(|e| BlockchainError::ValidationFailed(e.into()))?;
 
         Ok(UnsignedTransactionBlob {
             blob: bs58::encode(&unsigned.tx_blob).into_string(),
@@ -70,40 +229,259 @@ impl BlockchainClient {
         })
     }
 
+    #[tracing::instrument(skip(self, req), fields(public_key=%req.public_key))]
+    pub fn verify_signature(&self, req: &VerifySignatureRequest) -> Result<bool> {
+        let pk = bs58::decode(&req.public_key)
+            .into_vec()
+            .map_err(|_| BlockchainError::ValidationFailed("invalid public key base58".into()))?;
+        let payload = tx::SigningPayload::from_hex(&req.signing_payload, &self.signing_params)
+            .map_err(|e| BlockchainError::ValidationFailed(e.into()))?;
+        let signature = bs58::decode(&req.signature)
+            .into_vec()
+            .map_err(|_| BlockchainError::ValidationFailed("invalid signature base58".into()))?;
+
+        payload
+            .verify(&pk, &signature)
+            .map_err(|e| BlockchainError::ValidationFailed(e.into()))
+    }
+
+    #[tracing::instrument(skip(self, blob_b58))]
+    pub fn verify_transaction_blob(&self, blob_b58: &str) -> Result<TransactionBlobReport> {
+        let (blob, _) = decode_blob(blob_b58)?;
+        let check = tx::check_signed(&blob, &self.signing_params.dst)
+            .map_err(|e| BlockchainError::ValidationFailed(e.into()))?;
+
+        Ok(TransactionBlobReport {
+            signer: bs58::encode(&check.signer).into_string(),
+            hash: bs58::encode(&check.stored_hash).into_string(),
+            computed_hash: bs58::encode(check.computed_hash).into_string(),
+            hash_matches: check.hash_matches(),
+            signature_valid: check.signature_valid,
+        })
+    }
+
+    /// Predicts the id a transaction will have once submitted.
+    pub fn compute_transaction_hash(&self, blob_b58: &str) -> Result<TransactionHash> {
+        transaction_hash(blob_b58)
+    }
+
+    #[tracing::instrument(skip(self, blob_b58))]
+    pub fn decode_transaction(&self, blob_b58: &str) -> Result<DecodedTransaction> {
+        let (_, decoded) = decode_blob(blob_b58)?;
+        let action = decoded.tx.action;
+        let as_string = |b: Vec<u8>| String::from_utf8_lossy(&b).into_owned();
+
+        Ok(DecodedTransaction {
+            signer: bs58::encode(&decoded.tx.signer).into_string(),
+            nonce: decoded.tx.nonce,
+            op: action.op,
+            contract: action.contract,
+            function: action.function,
+            args: action
+                .args
+                .iter()
+                .map(|a| DecodedArgument::from_bytes(a))
+                .collect(),
+            attached_symbol: action.attached_symbol.map(as_string),
+            attached_amount: action.attached_amount.map(as_string),
+            hash: decoded.hash.map(|h| bs58::encode(h).into_string()),
+            signature: decoded.signature.map(|s| bs58::encode(s).into_string()),
+        })
+    }
+
+    #[tracing::instrument(skip(self, req))]
+    pub async fn create_transfer_blob(
+        &self,
+        req: TransferRequest,
+        symbol: &str,
+        url: &str,
+    ) -> Result<UnsignedTransactionBlob> {
+        if self.offline_build {
+            return build_transfer_locally(&req, symbol);
+        }
+
+        let amount = match (&req.amount, &req.amount_display) {
+            (Some(atoms), None) => Some(*atoms).filter(|a| !a.is_zero()).ok_or_else(|| {
+                BlockchainError::ValidationFailed("amount must be a positive integer".into())
+            })?,
+            (None, Some(display)) => {
+                let asset = self.get_asset_info(symbol, url).await?;
+                amount::to_base_units(display, asset.decimals)?
+            }
+            _ => {
+                return Err(BlockchainError::ValidationFailed(
+                    "exactly one of amount or amount_display is required".into(),
+                ))
+            }
+        };
+
+        if let Some(memo) = &req.memo {
+            tx::check_memo(memo.as_bytes())
+                .map_err(|e| BlockchainError::ValidationFailed(e.into()))?;
+        }
+
+        let tx_req = req.into_transaction_request(symbol, amount);
+        if tx_req.preflight_balance_check.unwrap_or(false) {
+            self.check_spendable_balance(&tx_req, url).await?;
+        }
+        self.create_transaction_blob(tx_req).await
+    }
+
     #[tracing::instrument(skip(self, tx), fields(tx_hash))]
-    pub async fn submit_signed_transaction(&self, tx: SignedTransaction, url: &str) -> Result<SubmitResponse> {
+    pub async fn submit_signed_transaction(
+        &self,
+        tx: SignedTransaction,
+        url: &str,
+    ) -> Result<SubmitResponse> {
+        expect_blob_signed(&tx.transaction, false)?;
         let finalized = tx::finalize_transaction(&tx.transaction, &tx.signature)
             .map_err(|e| BlockchainError::ValidationFailed(e.into()))?;
-        let tx_hash = bs58::encode(&finalized.hash).into_string();
-        let txu_b58 = bs58::encode(&finalized.packed).into_string();
-        let full_url = format!("{}/api/tx/submit", url);
+        self.submit_packed_transaction(&finalized.packed, &finalized.hash, url)
+            .await
+    }
+
+    /// Broadcasts a transaction signed and packed off-platform, passing the
+    /// caller's envelope through unchanged.
+    #[tracing::instrument(skip(self, blob_b58))]
+    pub async fn submit_raw_transaction(
+        &self,
+        blob_b58: &str,
+        url: &str,
+    ) -> Result<SubmitResponse> {
+        let tx_hash = raw_transaction_hash(blob_b58)?;
+        self.submit_envelope(blob_b58.to_string(), tx_hash, url)
+            .await
+    }
 
-        let response = self.client
-            .post(&full_url)
-            .header(header::CONTENT_TYPE, "text/plain")
-            .body(txu_b58)
-            .send()
+    /// Broadcasts an already signed and packed transaction envelope.
+    #[tracing::instrument(skip(self, packed, hash))]
+    pub async fn submit_packed_transaction(
+        &self,
+        packed: &[u8],
+        hash: &[u8; 32],
+        url: &str,
+    ) -> Result<SubmitResponse> {
+        let tx_hash = bs58::encode(hash).into_string();
+        self.submit_envelope(bs58::encode(packed).into_string(), Some(tx_hash), url)
             .await
-            .map_err(BlockchainError::HttpRequest)?;
+    }
 
-        if !response.status().is_success() {
-            return Err(BlockchainError::InvalidResponse(format!("HTTP {}", response.status())));
+    /// Every submit path ends here, so there is one wire format: `POST /api/tx/submit`
+    /// with the base58 packed `TxU` envelope as a `text/plain` body. Nodes also
+    /// accept `GET /api/tx/submit/{blob}`, but that puts the whole transaction in
+    /// the URL where length limits and access logs apply, so it is not used.
+    ///
+    /// Transaction hashes are deterministic, so an accepted submission is
+    /// remembered for `SUBMIT_DEDUP_TTL` and a repeat of it (an agent retrying
+    /// after a timeout) returns the first result marked `duplicate`.
+    async fn submit_envelope(
+        &self,
+        txu_b58: String,
+        tx_hash: Option<String>,
+        url: &str,
+    ) -> Result<SubmitResponse> {
+        let key = tx_hash.clone().map(|hash| (url.to_string(), hash));
+        if let Some(key) = &key {
+            if let Some((submitted_at, previous)) = self.recent_submissions.lock().unwrap().get(key)
+            {
+                if submitted_at.elapsed() < SUBMIT_DEDUP_TTL {
+                    debug!(tx_hash = %key.1, "transaction already submitted, not broadcasting again");
+                    return Ok(SubmitResponse {
+                        duplicate: true,
+                        ..previous.clone()
+                    });
+                }
+            }
         }
 
-        let api_response: serde_json::Value = self.parse_response(response).await?;
-        let error = api_response.get("error").and_then(|e| e.as_str()).unwrap_or("unknown");
+        let api_response = self.post_envelope("/api/tx/submit", txu_b58, url).await?;
+        let submitted = SubmitResponse::from_node(&api_response, tx_hash);
+        if let (Some(key), true) = (key, submitted.error == "ok") {
+            let mut recent = self.recent_submissions.lock().unwrap();
+            recent.retain(|_, (submitted_at, _)| submitted_at.elapsed() < SUBMIT_DEDUP_TTL);
+            recent.insert(key, (Instant::now(), submitted.clone()));
+        }
+        Ok(submitted)
+    }
 
-        Ok(SubmitResponse {
-            error: error.to_string(),
-            tx_hash: if error == "ok" { Some(tx_hash) } else { None },
-        })
+    /// Runs a signed transaction through the node's simulation endpoint, which
+    /// takes the same envelope as submission but commits nothing. Nodes without
+    /// the endpoint answer 404, reported as `Unsupported`.
+    #[tracing::instrument(skip(self, tx))]
+    pub async fn simulate_signed_transaction(
+        &self,
+        tx: &SignedTransaction,
+        url: &str,
+    ) -> Result<SimulationResult> {
+        expect_blob_signed(&tx.transaction, false)?;
+        let finalized = tx::finalize_transaction(&tx.transaction, &tx.signature)
+            .map_err(|e| BlockchainError::ValidationFailed(e.into()))?;
+        let api_response = self
+            .post_envelope(
+                "/api/tx/simulate",
+                bs58::encode(&finalized.packed).into_string(),
+                url,
+            )
+            .await
+            .map_err(|e| unsupported_if_missing(e, "transaction_simulation"))?;
+        Ok(SimulationResult::from_node(
+            &api_response,
+            bs58::encode(finalized.hash).into_string(),
+        ))
+    }
+
+    /// Executes a view function on the node without a transaction. Nodes
+    /// without the view endpoint answer 404, reported as `Unsupported`.
+    #[tracing::instrument(skip(self, req), fields(contract=%req.contract, function=%req.function))]
+    pub async fn simulate_call(
+        &self,
+        req: &ContractCallRequest,
+        url: &str,
+    ) -> Result<ContractCallResult> {
+        let body = contract_call_body(req)?;
+        let response = self
+            .retry_request_with_url(url, "POST", "/api/contract/view", Some(&body))
+            .await
+            .map_err(|e| unsupported_if_missing(e, "contract_view"))?;
+        let api_response: serde_json::Value = Self::parse_response(response)?;
+        let mut call = ContractCallResult::from_node(&api_response);
+        if let (Some(decoding), Some(result)) = (req.decode_as, &call.result) {
+            call.result = Some(decode_state_value(result, decoding)?);
+        }
+        Ok(call)
+    }
+
+    async fn post_envelope(
+        &self,
+        path: &str,
+        txu_b58: String,
+        url: &str,
+    ) -> Result<serde_json::Value> {
+        let response = self
+            .execute(
+                url,
+                HttpRequest {
+                    method: "POST".to_string(),
+                    url: endpoint_url(url, path),
+                    content_type: "text/plain",
+                    headers: self.request_headers(),
+                    body: Some(txu_b58),
+                    max_response_bytes: self.max_response_bytes,
+                },
+            )
+            .await?;
+
+        if !response.is_success() {
+            return Err(status_error(response.status, path, &response.body));
+        }
+        Self::parse_response(response)
     }
 
     #[tracing::instrument(skip(self), fields(address=%address))]
     pub async fn get_account_balance(&self, address: &str, url: &str) -> Result<AccountBalance> {
         let path = format!("/api/wallet/balance_all/{}", address);
         let response = self.retry_request_with_url(url, "GET", &path, None).await?;
-        let api_response: serde_json::Value = self.parse_response(response).await?;
+        let api_response: serde_json::Value = Self::parse_response(response)?;
 
         if api_response.get("error").and_then(|e| e.as_str()) != Some("ok") {
             return Err(BlockchainError::AccountNotFound {
@@ -115,10 +493,9 @@ impl BlockchainClient {
             BlockchainError::InvalidResponse("missing balances field".to_string())
         })?;
 
-        let balances: Vec<Balance> =
-            serde_json::from_value(balances_data.clone()).map_err(|e| {
-                BlockchainError::InvalidResponse(format!("failed to parse balances: {}", e))
-            })?;
+        let balances: Vec<Balance> = deserialize_balances(balances_data.clone()).map_err(|e| {
+            BlockchainError::InvalidResponse(format!("failed to parse balances: {}", e))
+        })?;
 
         Ok(AccountBalance {
             address: address.to_string(),
@@ -126,10 +503,205 @@ impl BlockchainClient {
         })
     }
 
+    /// Balances as of block `height`. The tip is checked first so a future
+    /// height fails validation instead of reaching the node; nodes without
+    /// snapshot queries answer 404, reported as `Unsupported`.
+    #[tracing::instrument(skip(self), fields(address=%address, height=%height))]
+    pub async fn get_balance_at_height(
+        &self,
+        address: &str,
+        height: u64,
+        url: &str,
+    ) -> Result<BalanceAtHeight> {
+        let tip = self.get_chain_stats(url).await?.height;
+        if height > tip {
+            return Err(BlockchainError::ValidationFailed(format!(
+                "height {} is above the chain tip {}",
+                height, tip
+            )));
+        }
+
+        let path = format!("/api/wallet/balance_all_at/{}/{}", address, height);
+        let response = self
+            .retry_request_with_url(url, "GET", &path, None)
+            .await
+            .map_err(|e| unsupported_if_missing(e, "historical_balance"))?;
+        let api_response: serde_json::Value = Self::parse_response(response)?;
+
+        parse_balance_at_height(address, height, &api_response)
+    }
+
+    #[tracing::instrument(skip(self), fields(address=%address))]
+    pub async fn get_balance_for_symbol(
+        &self,
+        address: &str,
+        symbol: &str,
+        url: &str,
+    ) -> Result<AssetBalance> {
+        let path = format!("/api/wallet/balance/{}/{}", address, symbol);
+        let response = self.retry_request_with_url(url, "GET", &path, None).await?;
+        let api_response: serde_json::Value = Self::parse_response(response)?;
+
+        parse_asset_balance(address, symbol, &api_response)
+    }
+
+    #[tracing::instrument(skip(self), fields(address=%address))]
+    pub async fn get_account_nonce(&self, address: &str, url: &str) -> Result<u64> {
+        let path = format!("/api/chain/nonce/{}", address);
+        let response = self
+            .retry_request_with_url(url, "GET", &path, None)
+            .await
+            .map_err(|e| unsupported_if_missing(e, "account_nonce"))?;
+        let api_response: serde_json::Value = Self::parse_response(response)?;
+
+        api_response
+            .get("nonce")
+            .and_then(|n| n.as_u64())
+            .ok_or_else(|| BlockchainError::InvalidResponse("missing nonce field".to_string()))
+    }
+
+    /// Fetches balance, nonce, and recent history concurrently. Fails only when
+    /// all three fail; otherwise missing parts are reported in `errors`.
+    #[tracing::instrument(skip(self), fields(address=%address, recent=%recent))]
+    pub async fn get_account_summary(
+        &self,
+        address: &str,
+        recent: u32,
+        url: &str,
+    ) -> Result<AccountSummary> {
+        let (balance, nonce, history) = tokio::join!(
+            self.get_account_balance(address, url),
+            self.get_account_nonce(address, url),
+            self.get_transaction_history(address, Some(recent), None, Some("desc"), url),
+        );
+
+        if nonce.is_err() && history.is_err() {
+            if let Err(e) = balance {
+                warn!(error = %e, "account summary: every part failed");
+                return Err(e);
+            }
+        }
+        Ok(AccountSummary::from_parts(address, balance, nonce, history))
+    }
+
+    /// Looks up several accounts concurrently. A failed lookup is recorded
+    /// against its address and does not affect the others.
+    #[tracing::instrument(skip(self, addresses), fields(count=addresses.len()))]
+    pub async fn get_balances(&self, addresses: &[String], url: &str) -> Result<AccountBalances> {
+        let mut unique: Vec<&String> = addresses.iter().collect();
+        unique.sort();
+        unique.dedup();
+
+        let results: Vec<_> = stream::iter(unique)
+            .map(|address| async move {
+                (
+                    address.clone(),
+                    self.get_account_balance(address, url).await,
+                )
+            })
+            .buffer_unordered(BALANCE_LOOKUP_CONCURRENCY)
+            .collect()
+            .await;
+        Ok(AccountBalances::from_results(results))
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn list_assets(&self, url: &str) -> Result<Vec<AssetInfo>> {
+        let response = self
+            .retry_request_with_url(url, "GET", "/api/coin/list", None)
+            .await?;
+        let api_response: serde_json::Value = Self::parse_response(response)?;
+
+        if api_response.get("error").and_then(|e| e.as_str()) != Some("ok") {
+            return Err(BlockchainError::InvalidResponse(
+                "failed to list assets".to_string(),
+            ));
+        }
+
+        let assets = api_response
+            .get("assets")
+            .ok_or_else(|| BlockchainError::InvalidResponse("missing assets field".to_string()))?;
+
+        serde_json::from_value(assets.clone())
+            .map_err(|e| BlockchainError::InvalidResponse(format!("failed to parse assets: {}", e)))
+    }
+
+    #[tracing::instrument(skip(self), fields(symbol=%symbol))]
+    pub async fn get_asset_info(&self, symbol: &str, url: &str) -> Result<AssetInfo> {
+        self.list_assets(url)
+            .await?
+            .into_iter()
+            .find(|a| a.symbol == symbol)
+            .ok_or_else(|| BlockchainError::AssetNotFound {
+                symbol: symbol.to_string(),
+            })
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_supply(&self, symbol: &str, url: &str) -> Result<SupplyInfo> {
+        let key = (url.to_string(), symbol.to_string());
+        if let Some((fetched_at, supply)) = self.supply_cache.lock().unwrap().get(&key) {
+            if fetched_at.elapsed() < SUPPLY_CACHE_TTL {
+                return Ok(supply.clone());
+            }
+        }
+
+        let path = format!("/api/coin/supply/{}", symbol);
+        let response = self
+            .retry_request_with_url(url, "GET", &path, None)
+            .await
+            .map_err(|e| asset_not_found_if_missing(e, symbol))?;
+        let api_response: serde_json::Value = Self::parse_response(response)?;
+        let supply = parse_supply(symbol, &api_response)?;
+
+        self.supply_cache
+            .lock()
+            .unwrap()
+            .insert(key, (Instant::now(), supply.clone()));
+        Ok(supply)
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_top_holders(&self, symbol: &str, limit: u32, url: &str) -> Result<TopHolders> {
+        check_richlist_symbol(symbol)?;
+        let response = self
+            .retry_request_with_url(url, "GET", "/api/contract/richlist", None)
+            .await
+            .map_err(|e| unsupported_if_missing(e, "richlist"))?;
+        let api_response: serde_json::Value = Self::parse_response(response)?;
+
+        parse_top_holders(symbol, &api_response, limit)
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn health_check(&self, url: &str) -> Result<HealthStatus> {
+        let started = Instant::now();
+        let stats = self.get_chain_stats(url).await;
+        let latency_ms = started.elapsed().as_millis() as u64;
+
+        match stats {
+            Ok(stats) => Ok(HealthStatus {
+                reachable: true,
+                height: Some(stats.height),
+                latency_ms,
+            }),
+            Err(
+                BlockchainError::HttpRequest(_) | BlockchainError::NetworkRetryExhausted { .. },
+            ) => Ok(HealthStatus {
+                reachable: false,
+                height: None,
+                latency_ms,
+            }),
+            Err(e) => Err(e),
+        }
+    }
+
     #[tracing::instrument(skip(self))]
     pub async fn get_chain_stats(&self, url: &str) -> Result<ChainStats> {
-        let response = self.retry_request_with_url(url, "GET", "/api/chain/stats", None).await?;
-        let api_response: serde_json::Value = self.parse_response(response).await?;
+        let response = self
+            .retry_request_with_url(url, "GET", "/api/chain/stats", None)
+            .await?;
+        let api_response: serde_json::Value = Self::parse_response(response)?;
 
         if api_response.get("error").and_then(|e| e.as_str()) != Some("ok") {
             return Err(BlockchainError::InvalidResponse(
@@ -145,11 +717,44 @@ impl BlockchainClient {
             .map_err(|e| BlockchainError::InvalidResponse(format!("failed to parse stats: {}", e)))
     }
 
+    #[tracing::instrument(skip(self))]
+    pub async fn get_fee_schedule(&self, url: &str) -> Result<FeeSchedule> {
+        let response = self
+            .retry_request_with_url(url, "GET", "/api/chain/fee_schedule", None)
+            .await
+            .map_err(|e| unsupported_if_missing(e, "fee_schedule"))?;
+        let api_response: serde_json::Value = Self::parse_response(response)?;
+
+        parse_fee_schedule(&api_response)
+    }
+
+    /// Chain identity does not change for a running node, so it is cached per
+    /// URL for the lifetime of the client.
+    #[tracing::instrument(skip(self))]
+    pub async fn get_network_info(&self, url: &str) -> Result<NetworkInfo> {
+        if let Some(info) = self.network_info_cache.lock().unwrap().get(url) {
+            return Ok(info.clone());
+        }
+
+        let response = self
+            .retry_request_with_url(url, "GET", "/api/chain/info", None)
+            .await
+            .map_err(|e| unsupported_if_missing(e, "network_info"))?;
+        let api_response: serde_json::Value = Self::parse_response(response)?;
+        let info = parse_network_info(&api_response)?;
+
+        self.network_info_cache
+            .lock()
+            .unwrap()
+            .insert(url.to_string(), info.clone());
+        Ok(info)
+    }
+
     #[tracing::instrument(skip(self), fields(height=%height))]
     pub async fn get_block_by_height(&self, height: u64, url: &str) -> Result<Vec<BlockEntry>> {
         let path = format!("/api/chain/height/{}", height);
         let response = self.retry_request_with_url(url, "GET", &path, None).await?;
-        let api_response: serde_json::Value = self.parse_response(response).await?;
+        let api_response: serde_json::Value = Self::parse_response(response)?;
 
         if api_response.get("error").and_then(|e| e.as_str()) != Some("ok") {
             return Err(BlockchainError::InvalidResponse(
@@ -166,16 +771,111 @@ impl BlockchainClient {
         })
     }
 
+    /// Looks the entry up through the node's `/api/chain/hash/{hash}`, the
+    /// endpoint the worker's `get_entry_by_hash` tool also uses.
+    #[tracing::instrument(skip(self), fields(hash=%hash))]
+    pub async fn get_block_by_hash(&self, hash: &str, url: &str) -> Result<Vec<BlockEntry>> {
+        let hash_b58 = block_hash_b58(hash).ok_or_else(|| {
+            BlockchainError::ValidationFailed(format!("invalid block hash: {}", hash))
+        })?;
+        let path = format!("/api/chain/hash/{}", hash_b58);
+        let response = self
+            .retry_request_with_url(url, "GET", &path, None)
+            .await
+            .map_err(|e| block_not_found_if_missing(e, hash))?;
+        let api_response: serde_json::Value = Self::parse_response(response)?;
+
+        parse_block_entries(hash, api_response)
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_latest_block(&self, url: &str) -> Result<BlockAtHeight> {
+        let height = self.get_chain_stats(url).await?.height;
+        let entries = self.get_block_by_height(height, url).await?;
+        Ok(BlockAtHeight { height, entries })
+    }
+
+    #[tracing::instrument(skip(self), fields(from=%from_height, to=%to_height))]
+    pub async fn get_block_range(
+        &self,
+        from_height: u64,
+        to_height: u64,
+        strict: bool,
+        url: &str,
+    ) -> Result<BlockRangeResult> {
+        if from_height > to_height {
+            return Err(BlockchainError::ValidationFailed(
+                "from_height must not exceed to_height".into(),
+            ));
+        }
+        if to_height - from_height >= self.max_block_range {
+            return Err(BlockchainError::ValidationFailed(format!(
+                "block range spans more than {} blocks",
+                self.max_block_range
+            )));
+        }
+
+        let fetches = stream::iter(from_height..=to_height)
+            .map(|height| async move {
+                let entries = self.get_block_by_height(height, url).await;
+                (
+                    height,
+                    entries.map(|entries| BlockAtHeight { height, entries }),
+                )
+            })
+            .buffer_unordered(BLOCK_RANGE_CONCURRENCY);
+
+        let mut blocks = Vec::new();
+        let mut failed = Vec::new();
+        if strict {
+            blocks = fetches.map(|(_, block)| block).try_collect().await?;
+        } else {
+            let results: Vec<_> = fetches.collect().await;
+            for (height, block) in results {
+                match block {
+                    Ok(block) => blocks.push(block),
+                    Err(e) => failed.push((height, e)),
+                }
+            }
+        }
+
+        // nothing came back, so the node is more likely down than missing heights
+        if blocks.is_empty() && !failed.is_empty() {
+            failed.sort_by_key(|(height, _)| *height);
+            return Err(failed.swap_remove(0).1);
+        }
+
+        blocks.sort_by_key(|b| b.height);
+        let mut failed_heights: Vec<FailedHeight> = failed
+            .into_iter()
+            .map(|(height, e)| FailedHeight {
+                height,
+                error: e.to_string(),
+            })
+            .collect();
+        failed_heights.sort_by_key(|f| f.height);
+        Ok(BlockRangeResult {
+            count: blocks.len(),
+            blocks,
+            failed_heights,
+        })
+    }
+
     #[tracing::instrument(skip(self), fields(tx_hash=%tx_hash))]
     pub async fn get_transaction(&self, tx_hash: &str, url: &str) -> Result<Transaction> {
         let path = format!("/api/chain/tx/{}", tx_hash);
         let response = self.retry_request_with_url(url, "GET", &path, None).await?;
-        let api_response: serde_json::Value = self.parse_response(response).await?;
+        let api_response: serde_json::Value = Self::parse_response(response)?;
 
-        if api_response.get("result").and_then(|r| r.get("error")).and_then(|e| e.as_str()) == Some("not_found") {
-            return Err(BlockchainError::InvalidResponse(
-                "transaction not found".to_string(),
-            ));
+        if api_response
+            .get("result")
+            .and_then(|r| r.get("error"))
+            .and_then(|e| e.as_str())
+            == Some("not_found")
+        {
+            return Err(BlockchainError::TransactionNotFound {
+                hash: tx_hash.to_string(),
+            });
         }
 
         serde_json::from_value(api_response).map_err(|e| {
@@ -183,6 +883,167 @@ impl BlockchainClient {
         })
     }
 
+    /// Only included transactions have a receipt; a pending or unknown hash
+    /// fails with `TransactionNotFound`.
+    #[tracing::instrument(skip(self), fields(tx_hash=%tx_hash))]
+    pub async fn get_transaction_receipt(
+        &self,
+        tx_hash: &str,
+        url: &str,
+    ) -> Result<TransactionReceiptReport> {
+        let tx = self.get_transaction(tx_hash, url).await?;
+        Ok(TransactionReceiptReport::from_transaction(tx))
+    }
+
+    #[tracing::instrument(skip(self), fields(tx_hash=%tx_hash))]
+    pub async fn get_transaction_status(
+        &self,
+        tx_hash: &str,
+        url: &str,
+    ) -> Result<TransactionStatus> {
+        let path = format!("/api/chain/tx/{}", tx_hash);
+        let response = self.retry_request_with_url(url, "GET", &path, None).await?;
+        let api_response: serde_json::Value = Self::parse_response(response)?;
+
+        Ok(TransactionStatus::from_response(&api_response))
+    }
+
+    /// Polls the transaction's status until it is no longer pending or
+    /// `timeout` passes, backing off between polls as `poll_backoff` sets.
+    /// Retryable node errors count as a pending poll rather than ending the wait.
+    #[tracing::instrument(skip(self))]
+    pub async fn wait_for_transaction(
+        &self,
+        tx_hash: &str,
+        timeout: Duration,
+        url: &str,
+    ) -> Result<TransactionWait> {
+        // the runtime clock, so the timeout follows the same time as the sleeps
+        let started = tokio::time::Instant::now();
+        let mut interval = self.poll_backoff.initial;
+        let mut polls = 0;
+
+        loop {
+            let status = match self.get_transaction_status(tx_hash, url).await {
+                Ok(status) => status,
+                Err(e) if e.is_retryable() => {
+                    debug!(polls, "status poll failed, still waiting: {}", e);
+                    TransactionStatus::Pending
+                }
+                Err(e) => return Err(e),
+            };
+            polls += 1;
+            let elapsed = started.elapsed();
+            let pending = matches!(status, TransactionStatus::Pending);
+            if !pending || elapsed >= timeout {
+                return Ok(TransactionWait {
+                    tx_hash: tx_hash.to_string(),
+                    status,
+                    polls,
+                    waited_ms: elapsed.as_millis() as u64,
+                    timed_out: pending,
+                });
+            }
+
+            debug!(
+                polls,
+                interval_ms = interval.as_millis() as u64,
+                "transaction pending"
+            );
+            tokio::time::sleep(interval.min(timeout - elapsed)).await;
+            interval = self.poll_backoff.next(interval);
+        }
+    }
+
+    /// Polls the chain tip until it is above `after_height` or `timeout`
+    /// passes, backing off between polls as `poll_backoff` sets. Nodes expose
+    /// no block subscription, so polling `/api/chain/stats` is the only source.
+    #[tracing::instrument(skip(self))]
+    pub async fn wait_for_next_block(
+        &self,
+        after_height: u64,
+        timeout: Duration,
+        url: &str,
+    ) -> Result<BlockWait> {
+        let started = Instant::now();
+        let mut interval = self.poll_backoff.initial;
+        let mut polls = 0;
+
+        loop {
+            let height = self.get_chain_stats(url).await?.height;
+            polls += 1;
+            let elapsed = started.elapsed();
+            let advanced = height > after_height;
+            if advanced || elapsed >= timeout {
+                return Ok(BlockWait {
+                    after_height,
+                    height,
+                    polls,
+                    waited_ms: elapsed.as_millis() as u64,
+                    timed_out: !advanced,
+                });
+            }
+
+            debug!(
+                polls,
+                height,
+                interval_ms = interval.as_millis() as u64,
+                "tip unchanged"
+            );
+            tokio::time::sleep(interval.min(timeout - elapsed)).await;
+            interval = self.poll_backoff.next(interval);
+        }
+    }
+
+    /// New tip heights above `after_height`, one item each time the tip moves.
+    /// Blocks landing between two polls are reported once, as the newest
+    /// height. Built on `wait_for_next_block`; the stream ends after an error.
+    pub fn subscribe_blocks<'a>(
+        &'a self,
+        after_height: u64,
+        url: &'a str,
+    ) -> impl futures::Stream<Item = Result<u64>> + 'a {
+        stream::unfold(Some(after_height), move |last| async move {
+            let last = last?;
+            loop {
+                match self
+                    .wait_for_next_block(last, Duration::from_secs(60), url)
+                    .await
+                {
+                    Ok(wait) if wait.timed_out => continue,
+                    Ok(wait) => return Some((Ok(wait.height), Some(wait.height))),
+                    Err(e) => return Some((Err(e), None)),
+                }
+            }
+        })
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_pending_transactions(
+        &self,
+        address: Option<&str>,
+        url: &str,
+    ) -> Result<Vec<PendingTransaction>> {
+        let response = self
+            .retry_request_with_url(url, "GET", "/api/chain/mempool", None)
+            .await
+            .map_err(|e| unsupported_if_missing(e, "mempool"))?;
+        let api_response: serde_json::Value = Self::parse_response(response)?;
+
+        let txs = api_response
+            .get("txs")
+            .ok_or_else(|| BlockchainError::InvalidResponse("missing txs field".to_string()))?;
+
+        let txs: Vec<PendingTransaction> = serde_json::from_value(txs.clone()).map_err(|e| {
+            BlockchainError::InvalidResponse(format!("failed to parse mempool txs: {}", e))
+        })?;
+
+        Ok(match address {
+            Some(address) => txs.into_iter().filter(|t| t.tx.signer == address).collect(),
+            None => txs,
+        })
+    }
+
     #[tracing::instrument(skip(self), fields(address=%address))]
     pub async fn get_transaction_history(
         &self,
@@ -191,7 +1052,7 @@ impl BlockchainClient {
         offset: Option<u32>,
         sort: Option<&str>,
         url: &str,
-    ) -> Result<Vec<Transaction>> {
+    ) -> Result<TransactionHistoryPage> {
         let mut path = format!("/api/chain/tx_events_by_account/{}", address);
         let mut params = vec![];
 
@@ -211,14 +1072,205 @@ impl BlockchainClient {
         }
 
         let response = self.retry_request_with_url(url, "GET", &path, None).await?;
-        let api_response: serde_json::Value = self.parse_response(response).await?;
+        let api_response: serde_json::Value = Self::parse_response(response)?;
 
         let txs = api_response
             .get("txs")
             .ok_or_else(|| BlockchainError::InvalidResponse("missing txs field".to_string()))?;
 
-        serde_json::from_value(txs.clone())
-            .map_err(|e| BlockchainError::InvalidResponse(format!("failed to parse txs: {}", e)))
+        let txs: Vec<Transaction> = serde_json::from_value(txs.clone())
+            .map_err(|e| BlockchainError::InvalidResponse(format!("failed to parse txs: {}", e)))?;
+
+        Ok(TransactionHistoryPage::new(txs, limit, offset))
+    }
+
+    #[tracing::instrument(skip(self), fields(address=%address, cap=%cap))]
+    pub async fn get_transaction_history_all(
+        &self,
+        address: &str,
+        cap: usize,
+        sort: Option<&str>,
+        url: &str,
+    ) -> Result<Vec<Transaction>> {
+        let mut all: Vec<Transaction> = Vec::new();
+        let mut offset = 0u32;
+        let mut last_first_hash: Option<String> = None;
+
+        for _ in 0..HISTORY_MAX_PAGES {
+            let page = self
+                .get_transaction_history(address, Some(HISTORY_PAGE_SIZE), Some(offset), sort, url)
+                .await?
+                .transactions;
+            let page_len = page.len();
+
+            // A node that ignores the offset keeps returning the same page
+            let first_hash = page.first().map(|t| t.hash.clone());
+            if first_hash.is_none() || first_hash == last_first_hash {
+                break;
+            }
+            last_first_hash = first_hash;
+
+            all.extend(page);
+            if all.len() >= cap || page_len < HISTORY_PAGE_SIZE as usize {
+                break;
+            }
+            offset += page_len as u32;
+        }
+
+        all.truncate(cap);
+        Ok(all)
+    }
+
+    /// Returns the account's transactions at or above `min_height`.
+    ///
+    /// Nodes only page history by offset, so this walks it newest first
+    /// (`sort=desc`) and stops at the first page reaching below the watermark.
+    /// That relies on the node ordering history by entry height; pages are
+    /// capped at `HISTORY_MAX_PAGES`, and `complete` is false if the cap was hit.
+    #[tracing::instrument(skip(self), fields(address=%address, min_height=%min_height))]
+    pub async fn get_transactions_since(
+        &self,
+        address: &str,
+        min_height: u64,
+        url: &str,
+    ) -> Result<TransactionsSince> {
+        let mut transactions: Vec<Transaction> = Vec::new();
+        let mut offset = 0u32;
+        let mut last_first_hash: Option<String> = None;
+        let mut complete = false;
+
+        for _ in 0..HISTORY_MAX_PAGES {
+            let page = self
+                .get_transaction_history(
+                    address,
+                    Some(HISTORY_PAGE_SIZE),
+                    Some(offset),
+                    Some("desc"),
+                    url,
+                )
+                .await?
+                .transactions;
+            let page_len = page.len();
+
+            let first_hash = page.first().map(|t| t.hash.clone());
+            if first_hash.is_none() || first_hash == last_first_hash {
+                complete = true;
+                break;
+            }
+            last_first_hash = first_hash;
+
+            let reached_older = page.iter().any(|t| t.metadata.entry_height < min_height);
+            transactions.extend(
+                page.into_iter()
+                    .filter(|t| t.metadata.entry_height >= min_height),
+            );
+            if reached_older || page_len < HISTORY_PAGE_SIZE as usize {
+                complete = true;
+                break;
+            }
+            offset += page_len as u32;
+        }
+
+        transactions.sort_by_key(|t| t.metadata.entry_height);
+        Ok(TransactionsSince {
+            next_min_height: transactions.last().map(|t| t.metadata.entry_height),
+            transactions,
+            min_height,
+            complete,
+        })
+    }
+
+    /// Client-side search: nodes expose no query endpoint, so this scans either
+    /// one account's history (capped at `SEARCH_HISTORY_CAP` transactions) or a
+    /// height range (capped at the max block range, one request per block) and
+    /// filters locally. Expect latency proportional to the span scanned.
+    #[tracing::instrument(skip(self, query))]
+    pub async fn search_transactions(
+        &self,
+        query: &SearchTransactionsQuery,
+        url: &str,
+    ) -> Result<TransactionSearchResult> {
+        if !query.has_filter() {
+            return Err(BlockchainError::ValidationFailed(
+                "at least one search filter is required".into(),
+            ));
+        }
+        let min_amount = match &query.min_amount {
+            Some(m) => Some(amount::parse_atoms(m)?),
+            None => None,
+        };
+
+        let candidates = match &query.counterparty {
+            Some(address) => {
+                self.get_transaction_history_all(address, SEARCH_HISTORY_CAP, None, url)
+                    .await?
+            }
+            None => {
+                let (Some(from), Some(to)) = (query.from_height, query.to_height) else {
+                    return Err(BlockchainError::ValidationFailed(
+                        "from_height and to_height are required unless counterparty is set".into(),
+                    ));
+                };
+                if from > to || to - from >= self.max_block_range {
+                    return Err(BlockchainError::ValidationFailed(format!(
+                        "height range must be ordered and span at most {} blocks",
+                        self.max_block_range
+                    )));
+                }
+                let mut txs: Vec<Transaction> = stream::iter(from..=to)
+                    .map(|height| self.get_transactions_at_height(height, url))
+                    .buffered(BLOCK_RANGE_CONCURRENCY)
+                    .try_collect::<Vec<_>>()
+                    .await?
+                    .into_iter()
+                    .flatten()
+                    .collect();
+                txs.sort_by_key(|t| t.metadata.entry_height);
+                txs
+            }
+        };
+
+        let scanned = candidates.len();
+        let offset = query.offset.unwrap_or(0) as usize;
+        let limit = query.limit.unwrap_or(SEARCH_DEFAULT_LIMIT) as usize;
+        let mut matches = candidates
+            .into_iter()
+            .filter(|t| query.matches(t, min_amount))
+            .skip(offset);
+        let transactions: Vec<Transaction> = matches.by_ref().take(limit).collect();
+        let next_offset = matches.next().map(|_| (offset + transactions.len()) as u32);
+
+        Ok(TransactionSearchResult {
+            transactions,
+            next_offset,
+            scanned,
+        })
+    }
+
+    #[tracing::instrument(skip(self), fields(height=%height))]
+    pub async fn get_transactions_at_height(
+        &self,
+        height: u64,
+        url: &str,
+    ) -> Result<Vec<Transaction>> {
+        let path = format!("/api/chain/height_with_txs/{}", height);
+        let response = self.retry_request_with_url(url, "GET", &path, None).await?;
+        let api_response: serde_json::Value = Self::parse_response(response)?;
+
+        parse_entry_txs(&api_response)
+    }
+
+    /// The `index`th transaction at `height`, counting across the height's
+    /// entries in order.
+    #[tracing::instrument(skip(self), fields(height=%height, index=%index))]
+    pub async fn get_transaction_by_position(
+        &self,
+        height: u64,
+        index: u32,
+        url: &str,
+    ) -> Result<TransactionAtPosition> {
+        let transactions = self.get_transactions_at_height(height, url).await?;
+        TransactionAtPosition::select(height, index, transactions)
     }
 
     #[tracing::instrument(skip(self))]
@@ -226,7 +1278,7 @@ impl BlockchainClient {
         let response = self
             .retry_request_with_url(url, "GET", "/api/peer/trainers", None)
             .await?;
-        let api_response: serde_json::Value = self.parse_response(response).await?;
+        let api_response: serde_json::Value = Self::parse_response(response)?;
 
         if api_response.get("error").and_then(|e| e.as_str()) != Some("ok") {
             return Err(BlockchainError::InvalidResponse(
@@ -243,6 +1295,37 @@ impl BlockchainClient {
         })
     }
 
+    #[tracing::instrument(skip(self), fields(validator=%validator))]
+    pub async fn get_validator_details(
+        &self,
+        validator: &str,
+        url: &str,
+    ) -> Result<ValidatorDetails> {
+        let path = format!("/api/peer/trainer/{}", validator);
+        let response = self
+            .retry_request_with_url(url, "GET", &path, None)
+            .await
+            .map_err(|e| unsupported_if_missing(e, "validator_details"))?;
+        let api_response: serde_json::Value = Self::parse_response(response)?;
+
+        parse_validator_details(validator, api_response)
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_validators_detailed(&self, url: &str) -> Result<Vec<ValidatorDetails>> {
+        let validators = self.get_validators(url).await?;
+
+        stream::iter(validators)
+            .map(|peer| async move {
+                let mut details = self.get_validator_details(&peer.pk, url).await?;
+                details.peer = Some(peer);
+                Ok::<_, BlockchainError>(details)
+            })
+            .buffered(BLOCK_RANGE_CONCURRENCY)
+            .try_collect()
+            .await
+    }
+
     #[tracing::instrument(skip(self), fields(contract=%contract_address, key=%key))]
     pub async fn get_contract_state(
         &self,
@@ -252,75 +1335,695 @@ impl BlockchainClient {
     ) -> Result<serde_json::Value> {
         let path = format!("/api/contract/get/{}/{}", contract_address, key);
         let response = self.retry_request_with_url(url, "GET", &path, None).await?;
-        self.parse_response(response).await
+        Self::parse_response(response)
     }
 
+    /// Reads several storage keys of one contract concurrently. A failed read
+    /// is recorded against its key and does not affect the others.
+    #[tracing::instrument(skip(self, keys), fields(count=keys.len()))]
+    pub async fn get_contract_states(
+        &self,
+        contract_address: &str,
+        keys: &[String],
+        url: &str,
+    ) -> Result<ContractStates> {
+        let mut unique: Vec<&String> = keys.iter().collect();
+        unique.sort();
+        unique.dedup();
+
+        let results: Vec<_> = stream::iter(unique)
+            .map(|key| async move {
+                (
+                    key.clone(),
+                    self.get_contract_state(contract_address, key, url).await,
+                )
+            })
+            .buffer_unordered(CONTRACT_STATE_CONCURRENCY)
+            .collect()
+            .await;
+        Ok(ContractStates::from_results(contract_address, results))
+    }
+
+    #[tracing::instrument(skip(self), fields(name=%name))]
+    pub async fn resolve_name(&self, name: &str, url: &str) -> Result<String> {
+        let value = self
+            .get_contract_state(NAME_CONTRACT, &format!("name:{}", name), url)
+            .await?;
+        state_string(&value).ok_or_else(|| BlockchainError::AccountNotFound {
+            address: name.to_string(),
+        })
+    }
+
+    #[tracing::instrument(skip(self), fields(address=%address))]
+    pub async fn reverse_resolve(&self, address: &str, url: &str) -> Result<String> {
+        let value = self
+            .get_contract_state(NAME_CONTRACT, &format!("addr:{}", address), url)
+            .await?;
+        state_string(&value).ok_or_else(|| BlockchainError::AccountNotFound {
+            address: address.to_string(),
+        })
+    }
+
+    #[tracing::instrument(skip(self), fields(contract=%contract_address))]
+    pub async fn get_contract_code(
+        &self,
+        contract_address: &str,
+        url: &str,
+    ) -> Result<ContractInfo> {
+        let path = format!("/api/contract/info/{}", contract_address);
+        let response = self
+            .retry_request_with_url(url, "GET", &path, None)
+            .await
+            .map_err(|e| not_a_contract_if_missing(e, contract_address))?;
+        let api_response: serde_json::Value = Self::parse_response(response)?;
+
+        parse_contract_info(contract_address, api_response)
+    }
+
+    #[tracing::instrument(skip(self, query), fields(contract=%query.contract_address))]
+    pub async fn list_contract_keys(
+        &self,
+        query: &ContractKeysQuery,
+        url: &str,
+    ) -> Result<ContractKeys> {
+        let path = contract_keys_path(query);
+        let response = self
+            .retry_request_with_url(url, "GET", &path, None)
+            .await
+            .map_err(|e| unsupported_if_missing(e, "contract_keys"))?;
+        let api_response: serde_json::Value = Self::parse_response(response)?;
+
+        let keys = api_response
+            .get("keys")
+            .ok_or_else(|| BlockchainError::InvalidResponse("missing keys field".to_string()))?;
+
+        Ok(ContractKeys {
+            contract_address: query.contract_address.clone(),
+            keys: serde_json::from_value(keys.clone()).map_err(|e| {
+                BlockchainError::InvalidResponse(format!("failed to parse keys: {}", e))
+            })?,
+            next_cursor: api_response
+                .get("next_cursor")
+                .and_then(|c| c.as_str())
+                .map(str::to_string),
+        })
+    }
+
+    #[tracing::instrument(skip(self, base_url, body), fields(path=%path))]
     async fn retry_request_with_url(
         &self,
         base_url: &str,
         method: &str,
         path: &str,
         body: Option<&serde_json::Value>,
-    ) -> Result<Response> {
+    ) -> Result<HttpResponse> {
         let retry_strategy = ExponentialBackoff::from_millis(100).map(jitter).take(3);
 
-        let url = format!("{}{}", base_url.trim_end_matches('/'), path);
-
-        Retry::spawn(retry_strategy, || async {
-            let mut request = match method {
-                "GET" => self.client.get(&url),
-                "POST" => self.client.post(&url),
-                _ => {
-                    return Err(BlockchainError::Configuration(format!(
-                        "unsupported method: {}",
-                        method
-                    )))
-                }
-            };
-
-            request = request.header(header::CONTENT_TYPE, "application/json");
+        let url = endpoint_url(base_url, path);
+        let started = Instant::now();
 
-            if let Some(json) = body {
-                request = request.json(json);
+        let result = Retry::spawn(retry_strategy, || async {
+            let resp = self
+                .execute(
+                    base_url,
+                    HttpRequest {
+                        method: method.to_string(),
+                        url: url.clone(),
+                        content_type: "application/json",
+                        headers: self.request_headers(),
+                        body: body.map(|json| json.to_string()),
+                        max_response_bytes: self.max_response_bytes,
+                    },
+                )
+                .await?;
+            if resp.is_success() {
+                Ok(resp)
+            } else {
+                Err(status_error(resp.status, path, &resp.body))
             }
+        })
+        .await;
 
-            request
-                .send()
-                .await
-                .map_err(BlockchainError::HttpRequest)
-                .and_then(|resp| {
-                    if resp.status().is_success() {
-                        Ok(resp)
-                    } else {
-                        Err(BlockchainError::InvalidResponse(format!(
-                            "HTTP {}: request failed",
-                            resp.status()
-                        )))
-                    }
+        let elapsed_ms = started.elapsed().as_millis() as u64;
+        match result {
+            Ok(resp) => {
+                debug!(elapsed_ms, status = resp.status, "request completed");
+                Ok(resp)
+            }
+            Err(e) => {
+                warn!(elapsed_ms, "retry exhausted: {}", e);
+                Err(match e {
+                    BlockchainError::HttpStatus { .. }
+                    | BlockchainError::NodeError { .. }
+                    | BlockchainError::CircuitOpen { .. }
+                    | BlockchainError::ResponseTooLarge { .. } => e,
+                    _ => BlockchainError::NetworkRetryExhausted { attempts: 3 },
                 })
-        })
-        .await
-        .map_err(|e| {
-            warn!("retry exhausted: {}", e);
-            BlockchainError::NetworkRetryExhausted { attempts: 3 }
-        })
+            }
+        }
     }
 
-    async fn parse_response<T: serde::de::DeserializeOwned>(
-        &self,
-        response: Response,
-    ) -> Result<T> {
-        let status = response.status();
-        let body = response
-            .text()
-            .await
-            .map_err(BlockchainError::HttpRequest)?;
+    async fn execute(&self, base_url: &str, request: HttpRequest) -> Result<HttpResponse> {
+        self.breaker.check(base_url)?;
+        let _permit = self.limiter.acquire().await;
 
-        serde_json::from_str(&body).map_err(|e| {
+        let result = self.executor.execute(request).await;
+        match &result {
+            Ok(resp) if resp.status < 500 => self.breaker.record_success(base_url),
+            _ => self.breaker.record_failure(base_url),
+        }
+        result
+    }
+
+    fn parse_response<T: serde::de::DeserializeOwned>(response: HttpResponse) -> Result<T> {
+        check_content_type(response.content_type.as_deref(), &response.body)?;
+        parse_success_body(&response.body).map_err(|e| {
             BlockchainError::InvalidResponse(format!(
                 "failed to parse response (status {}): {}",
-                status, e
+                response.status, e
             ))
         })
     }
+
+    fn request_headers(&self) -> Vec<(String, String)> {
+        let mut headers = vec![("User-Agent".to_string(), self.user_agent.clone())];
+        headers.extend(self.auth_header.iter().cloned());
+        headers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blockchain::http::MockExecutor;
+
+    fn mock_client(mock: MockExecutor) -> BlockchainClient {
+        BlockchainClient::with_executor(Arc::new(mock))
+    }
+
+    fn test_key() -> tx::SecretKey {
+        tx::SecretKey::from_b58(&bs58::encode([7u8; 64]).into_string()).unwrap()
+    }
+
+    #[test]
+    fn verification_uses_configured_dst() {
+        let sk = test_key();
+        let custom = tx::SigningParams::from_dst(Some("AMADEUS_SIG_TEST_DST_"));
+        let build = |params: &tx::SigningParams| {
+            tx::build_with_params(&sk, "Coin", "transfer", &[], None, Some(1), params).unwrap()
+        };
+        let signed_default = build(&tx::SigningParams::default());
+        let signed_custom = build(&custom);
+
+        // same transaction, so the same hash, but a different signature
+        assert_eq!(signed_default.hash, signed_custom.hash);
+        let decoded = tx::decode(&signed_custom.packed).unwrap();
+        let signature = decoded.signature.unwrap();
+        assert_ne!(
+            Some(&signature),
+            tx::decode(&signed_default.packed)
+                .unwrap()
+                .signature
+                .as_ref()
+        );
+
+        let blob = bs58::encode(&signed_custom.packed).into_string();
+        let request = VerifySignatureRequest {
+            public_key: bs58::encode(&decoded.tx.signer).into_string(),
+            signing_payload: hex::encode(signed_custom.hash),
+            signature: bs58::encode(&signature).into_string(),
+        };
+
+        let default_client = mock_client(MockExecutor::new());
+        assert!(
+            !default_client
+                .verify_transaction_blob(&blob)
+                .unwrap()
+                .signature_valid
+        );
+        assert!(!default_client.verify_signature(&request).unwrap());
+
+        let custom_client = mock_client(MockExecutor::new()).with_signing_params(custom);
+        assert!(
+            custom_client
+                .verify_transaction_blob(&blob)
+                .unwrap()
+                .signature_valid
+        );
+        assert!(custom_client.verify_signature(&request).unwrap());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn wait_for_transaction_treats_node_errors_as_pending() {
+        let client = mock_client(MockExecutor::new().with_response(
+            "/api/chain/tx/abc",
+            503,
+            serde_json::json!({"error": "overloaded"}),
+        ));
+
+        let wait = client
+            .wait_for_transaction("abc", Duration::from_secs(30), "http://node")
+            .await
+            .unwrap();
+        assert!(wait.timed_out);
+        assert!(matches!(wait.status, TransactionStatus::Pending));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn wait_for_transaction_fails_on_rejected_request() {
+        let client = mock_client(MockExecutor::new().with_response(
+            "/api/chain/tx/abc",
+            400,
+            serde_json::json!({"error": "invalid hash"}),
+        ));
+
+        let err = client
+            .wait_for_transaction("abc", Duration::from_secs(30), "http://node")
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            BlockchainError::NodeError { status: 400, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn get_account_balance_maps_node_error_to_account_not_found() {
+        let client = mock_client(MockExecutor::new().with_response(
+            "/api/wallet/balance_all/someone",
+            200,
+            serde_json::json!({"error": "not_found"}),
+        ));
+
+        let err = client
+            .get_account_balance("someone", "http://node")
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, BlockchainError::AccountNotFound { address } if address == "someone")
+        );
+    }
+
+    #[tokio::test]
+    async fn get_account_balance_reads_balances_when_ok() {
+        let client = mock_client(MockExecutor::new().with_response(
+            "/api/wallet/balance_all/someone",
+            200,
+            serde_json::json!({"error": "ok", "balances": {"AMA": "5"}}),
+        ));
+
+        let account = client
+            .get_account_balance("someone", "http://node")
+            .await
+            .unwrap();
+        assert_eq!(account.balances.len(), 1);
+        assert_eq!(account.balances[0].flat, amount::Amount::new(5).unwrap());
+    }
+
+    #[tokio::test]
+    async fn get_transaction_maps_not_found() {
+        let client = mock_client(MockExecutor::new().with_response(
+            "/api/chain/tx/abc",
+            200,
+            serde_json::json!({"result": {"error": "not_found"}}),
+        ));
+
+        let err = client
+            .get_transaction("abc", "http://node")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, BlockchainError::TransactionNotFound { hash } if hash == "abc"));
+    }
+
+    #[tokio::test]
+    async fn offline_transfer_matches_generic_transaction_build() {
+        let signer = bs58::encode(tx::public_key_from_secret(&test_key()).unwrap()).into_string();
+        let receiver = bs58::encode([9u8; 48]).into_string();
+        let fixture = serde_json::json!({
+            "transfer": {
+                "signer": signer,
+                "receiver": receiver,
+                "symbol": "AMA",
+                "amount": "1500000000",
+                "memo": "invoice 17",
+                "nonce": 1700000000000000042i64,
+            },
+            "transaction": {
+                "signer": signer,
+                "contract": "Coin",
+                "function": "transfer",
+                "args": [{"b58": receiver}, "1500000000", "AMA", {"utf8": "invoice 17"}],
+                "nonce": 1700000000000000042i64,
+            },
+        });
+        let transfer: TransferRequest =
+            serde_json::from_value(fixture["transfer"].clone()).unwrap();
+        let transaction: TransactionRequest =
+            serde_json::from_value(fixture["transaction"].clone()).unwrap();
+
+        let local = build_transfer_locally(&transfer, "AMA").unwrap();
+        let offline_client = mock_client(MockExecutor::new()).with_offline_build(true);
+        let via_client = offline_client
+            .create_transfer_blob(transfer, "AMA", "http://node")
+            .await
+            .unwrap();
+        let generic = offline_client
+            .create_transaction_blob(transaction)
+            .await
+            .unwrap();
+
+        for built in [&via_client, &generic] {
+            assert_eq!(built.blob, local.blob);
+            assert_eq!(built.tx_bytes, local.tx_bytes);
+            assert_eq!(built.signing_payload, local.signing_payload);
+            assert_eq!(built.transaction_hash, local.transaction_hash);
+        }
+    }
+
+    #[tokio::test]
+    async fn resubmission_within_ttl_is_not_broadcast_again() {
+        let mock = Arc::new(MockExecutor::new().with_response(
+            "/api/tx/submit",
+            200,
+            serde_json::json!({"error": "ok"}),
+        ));
+        let client = BlockchainClient::with_executor(mock.clone());
+        let built = tx::build_with_params(
+            &test_key(),
+            "Coin",
+            "transfer",
+            &[],
+            None,
+            Some(1),
+            &tx::SigningParams::default(),
+        )
+        .unwrap();
+
+        let first = client
+            .submit_packed_transaction(&built.packed, &built.hash, "http://node")
+            .await
+            .unwrap();
+        let second = client
+            .submit_packed_transaction(&built.packed, &built.hash, "http://node")
+            .await
+            .unwrap();
+
+        assert!(!first.duplicate);
+        assert!(second.duplicate);
+        assert_eq!(second.tx_hash, first.tx_hash);
+        assert_eq!(mock.requests(), vec!["POST /api/tx/submit".to_string()]);
+
+        // another node has not seen it
+        client
+            .submit_packed_transaction(&built.packed, &built.hash, "http://other")
+            .await
+            .unwrap();
+        assert_eq!(mock.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn rejected_submission_is_not_remembered() {
+        let mock = Arc::new(MockExecutor::new().with_response(
+            "/api/tx/submit",
+            200,
+            serde_json::json!({"error": "invalid_nonce"}),
+        ));
+        let client = BlockchainClient::with_executor(mock.clone());
+        let built = tx::build_with_params(
+            &test_key(),
+            "Coin",
+            "transfer",
+            &[],
+            None,
+            Some(1),
+            &tx::SigningParams::default(),
+        )
+        .unwrap();
+
+        for _ in 0..2 {
+            let response = client
+                .submit_packed_transaction(&built.packed, &built.hash, "http://node")
+                .await
+                .unwrap();
+            assert_eq!(response.error, "invalid_nonce");
+            assert!(!response.duplicate);
+        }
+        assert_eq!(mock.requests().len(), 2);
+    }
+
+    fn history_tx(hash: &str, height: u64, symbol: &str) -> serde_json::Value {
+        serde_json::json!({
+            "hash": hash,
+            "metadata": {"entry_hash": "entry", "entry_height": height},
+            "signature": "sig",
+            "result": {"error": "ok"},
+            "tx": {
+                "action": {
+                    "args": ["bob", "100", symbol],
+                    "function": "transfer",
+                    "op": "call",
+                    "contract": "Coin",
+                },
+                "nonce": height,
+                "signer": "alice",
+            },
+            "receipt": {"success": true},
+        })
+    }
+
+    /// One history page holding a transaction per height, in the given order.
+    fn history_page(heights: impl Iterator<Item = u64>) -> serde_json::Value {
+        let txs: Vec<_> = heights
+            .map(|h| history_tx(&format!("tx{}", h), h, "AMA"))
+            .collect();
+        serde_json::json!({ "txs": txs })
+    }
+
+    fn hashes(txs: &[Transaction]) -> Vec<&str> {
+        txs.iter().map(|t| t.hash.as_str()).collect()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn block_range_reports_missing_heights_unless_strict() {
+        let block = serde_json::json!({"error": "ok", "entries": []});
+        let mock = || {
+            MockExecutor::new()
+                .with_response("/api/chain/height/5", 200, block.clone())
+                .with_response("/api/chain/height/7", 200, block.clone())
+        };
+
+        let partial = mock_client(mock())
+            .get_block_range(5, 7, false, "http://node")
+            .await
+            .unwrap();
+        assert_eq!(partial.count, 2);
+        let heights: Vec<u64> = partial.blocks.iter().map(|b| b.height).collect();
+        assert_eq!(heights, vec![5, 7]);
+        assert_eq!(partial.failed_heights.len(), 1);
+        assert_eq!(partial.failed_heights[0].height, 6);
+
+        assert!(mock_client(mock())
+            .get_block_range(5, 7, true, "http://node")
+            .await
+            .is_err());
+        // nothing fetched at all is an error even when lenient
+        assert!(mock_client(MockExecutor::new())
+            .get_block_range(5, 7, false, "http://node")
+            .await
+            .is_err());
+
+        let err = mock_client(mock())
+            .with_max_block_range(2)
+            .get_block_range(5, 7, false, "http://node")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, BlockchainError::ValidationFailed(_)));
+    }
+
+    #[tokio::test]
+    async fn full_history_follows_offsets_until_a_short_page() {
+        let path = |offset: u32| {
+            format!(
+                "/api/chain/tx_events_by_account/alice?limit={}&offset={}",
+                HISTORY_PAGE_SIZE, offset
+            )
+        };
+        let mock = Arc::new(
+            MockExecutor::new()
+                .with_response(&path(0), 200, history_page(0..100))
+                .with_response(&path(100), 200, history_page(100..103)),
+        );
+        let client = BlockchainClient::with_executor(mock.clone());
+
+        let all = client
+            .get_transaction_history_all("alice", 1000, None, "http://node")
+            .await
+            .unwrap();
+        assert_eq!(all.len(), 103);
+        assert_eq!(all[102].hash, "tx102");
+        assert_eq!(mock.requests().len(), 2);
+
+        let capped = client
+            .get_transaction_history_all("alice", 10, None, "http://node")
+            .await
+            .unwrap();
+        assert_eq!(capped.len(), 10);
+    }
+
+    #[tokio::test]
+    async fn full_history_stops_when_the_node_ignores_the_offset() {
+        let page = history_page(0..100);
+        let mock = MockExecutor::new()
+            .with_response(
+                "/api/chain/tx_events_by_account/alice?limit=100&offset=0",
+                200,
+                page.clone(),
+            )
+            .with_response(
+                "/api/chain/tx_events_by_account/alice?limit=100&offset=100",
+                200,
+                page,
+            );
+
+        let all = mock_client(mock)
+            .get_transaction_history_all("alice", 1000, None, "http://node")
+            .await
+            .unwrap();
+        assert_eq!(all.len(), 100);
+    }
+
+    #[tokio::test]
+    async fn transactions_since_stops_below_the_watermark() {
+        let path = |offset: u32| {
+            format!(
+                "/api/chain/tx_events_by_account/alice?limit=100&offset={}&sort=desc",
+                offset
+            )
+        };
+        let mock = MockExecutor::new()
+            .with_response(&path(0), 200, history_page((50..150).rev()))
+            .with_response(&path(100), 200, history_page((40..50).rev()));
+
+        let since = mock_client(mock)
+            .get_transactions_since("alice", 45, "http://node")
+            .await
+            .unwrap();
+        assert!(since.complete);
+        assert_eq!(since.min_height, 45);
+        assert_eq!(since.transactions.len(), 105);
+        assert_eq!(since.transactions[0].metadata.entry_height, 45);
+        assert_eq!(since.next_min_height, Some(149));
+    }
+
+    #[tokio::test]
+    async fn search_filters_a_height_range_locally() {
+        let entry = |txs: Vec<serde_json::Value>| serde_json::json!({"entries": [{"txs": txs}]});
+        let mock = MockExecutor::new()
+            .with_response(
+                "/api/chain/height_with_txs/10",
+                200,
+                entry(vec![
+                    history_tx("a", 10, "AMA"),
+                    history_tx("b", 10, "USDC"),
+                ]),
+            )
+            .with_response(
+                "/api/chain/height_with_txs/11",
+                200,
+                entry(vec![history_tx("c", 11, "USDC")]),
+            );
+        let query: SearchTransactionsQuery = serde_json::from_value(serde_json::json!({
+            "from_height": 10,
+            "to_height": 11,
+            "symbol": "USDC",
+            "limit": 1,
+        }))
+        .unwrap();
+
+        let found = mock_client(mock)
+            .search_transactions(&query, "http://node")
+            .await
+            .unwrap();
+        assert_eq!(hashes(&found.transactions), vec!["b"]);
+        assert_eq!(found.next_offset, Some(1));
+        assert_eq!(found.scanned, 3);
+    }
+
+    #[tokio::test]
+    async fn search_by_counterparty_scans_account_history() {
+        let mock = MockExecutor::new().with_response(
+            "/api/chain/tx_events_by_account/bob?limit=100&offset=0",
+            200,
+            serde_json::json!({"txs": [
+                history_tx("a", 3, "AMA"),
+                history_tx("b", 4, "USDC"),
+            ]}),
+        );
+        let query: SearchTransactionsQuery =
+            serde_json::from_value(serde_json::json!({"counterparty": "bob", "symbol": "AMA"}))
+                .unwrap();
+
+        let found = mock_client(mock)
+            .search_transactions(&query, "http://node")
+            .await
+            .unwrap();
+        assert_eq!(hashes(&found.transactions), vec!["a"]);
+        assert_eq!(found.next_offset, None);
+        assert_eq!(found.scanned, 2);
+
+        let unfiltered: SearchTransactionsQuery =
+            serde_json::from_value(serde_json::json!({})).unwrap();
+        assert!(matches!(
+            mock_client(MockExecutor::new())
+                .search_transactions(&unfiltered, "http://node")
+                .await,
+            Err(BlockchainError::ValidationFailed(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn supply_is_cached_per_node_and_symbol() {
+        let mock = Arc::new(MockExecutor::new().with_response(
+            "/api/coin/supply/AMA",
+            200,
+            serde_json::json!({"error": "ok", "supply": {"total_supply": "1000", "burned": "10"}}),
+        ));
+        let client = BlockchainClient::with_executor(mock.clone());
+
+        for _ in 0..2 {
+            let supply = client.get_supply("AMA", "http://node").await.unwrap();
+            assert_eq!(supply.circulating_supply.atoms(), 990);
+        }
+        assert_eq!(mock.requests().len(), 1);
+
+        client.get_supply("AMA", "http://other").await.unwrap();
+        assert_eq!(mock.requests().len(), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn network_info_is_cached_per_node_but_failures_are_not() {
+        let mock = Arc::new(MockExecutor::new().with_response(
+            "/api/chain/info",
+            200,
+            serde_json::json!({"error": "ok", "info": {"chain_id": 7, "version": "1.2.0"}}),
+        ));
+        let client = BlockchainClient::with_executor(mock.clone());
+
+        for _ in 0..2 {
+            let info = client.get_network_info("http://node").await.unwrap();
+            assert_eq!(info.chain_id.as_deref(), Some("7"));
+            assert_eq!(info.node_version.as_deref(), Some("1.2.0"));
+        }
+        assert_eq!(mock.requests().len(), 1);
+
+        let missing = Arc::new(MockExecutor::new());
+        let client = BlockchainClient::with_executor(missing.clone());
+        for _ in 0..2 {
+            assert!(matches!(
+                client.get_network_info("http://node").await,
+                Err(BlockchainError::Unsupported { .. })
+            ));
+        }
+        assert_eq!(missing.requests().len(), 8);
+    }
 }
@@ -1,16 +1,126 @@
 use super::{
-    error::{BlockchainError, Result},
+    amount,
+    error::{
+        asset_not_found_if_missing, block_not_found_if_missing, check_content_type,
+        not_a_contract_if_missing, parse_success_body, status_error, unsupported_if_missing,
+        BlockchainError, Result,
+    },
+    shared::{
+        build_transfer_locally, check_richlist_symbol, contract_call_body, contract_keys_path,
+        decode_blob, decode_state_value, encode_args, endpoint_url, expect_blob_signed,
+        parse_asset_balance, parse_balance_at_height, parse_block_entries, parse_contract_info,
+        parse_entry_txs, parse_fee_schedule, parse_network_info, parse_supply, parse_top_holders,
+        parse_validator_details, raw_transaction_hash, state_string, transaction_hash,
+    },
     types::*,
 };
 use crate::wasm::tx;
+use futures::{stream, StreamExt, TryStreamExt};
 use worker::{Fetch, Method, Request, RequestInit};
 
+const DEFAULT_MAX_BLOCK_RANGE: u64 = 100;
+/// Same default as the native client.
+const DEFAULT_MAX_RESPONSE_BYTES: usize = 8 * 1024 * 1024;
+const DEFAULT_USER_AGENT: &str = concat!("amadeus-mcp/", env!("CARGO_PKG_VERSION"));
+const BLOCK_RANGE_CONCURRENCY: usize = 8;
+const BALANCE_LOOKUP_CONCURRENCY: usize = 8;
+const CONTRACT_STATE_CONCURRENCY: usize = 8;
+const HISTORY_PAGE_SIZE: u32 = 100;
+const HISTORY_MAX_PAGES: usize = 50;
+const SEARCH_HISTORY_CAP: usize = 1000;
+const SEARCH_DEFAULT_LIMIT: u32 = 50;
+
+/// Naming contract mapping `name:<name>` to an address and `addr:<address>` back to its name.
+const NAME_CONTRACT: &str = "Names";
+
 #[derive(Clone)]
-pub struct BlockchainClient {}
+pub struct BlockchainClient {
+    max_block_range: u64,
+    auth_header: Option<(String, String)>,
+    user_agent: String,
+    max_response_bytes: usize,
+    offline_build: bool,
+    poll_backoff: PollBackoff,
+    signing_params: tx::SigningParams,
+}
 
 impl BlockchainClient {
     pub fn new(_base_url: String) -> Result<Self> {
-        Ok(Self {})
+        Ok(Self {
+            max_block_range: DEFAULT_MAX_BLOCK_RANGE,
+            auth_header: None,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            max_response_bytes: DEFAULT_MAX_RESPONSE_BYTES,
+            offline_build: false,
+            poll_backoff: PollBackoff::default(),
+            signing_params: tx::SigningParams::default(),
+        })
+    }
+
+    /// Builds transfers without contacting the node; see the native client.
+    pub fn with_offline_build(mut self, enabled: bool) -> Self {
+        self.offline_build = enabled;
+        self
+    }
+
+    /// Sends `name: value` with every request, for nodes behind an API gateway.
+    pub fn with_auth_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.auth_header = Some((name.into(), value.into()));
+        self
+    }
+
+    /// Replaces the default `amadeus-mcp/<version>` User-Agent sent to nodes.
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    pub fn with_poll_backoff(mut self, backoff: PollBackoff) -> Self {
+        self.poll_backoff = backoff;
+        self
+    }
+
+    /// Domain separation tag `verify_signature` and `verify_transaction_blob`
+    /// check signatures under; the default is `tx::SIG_DST`.
+    pub fn with_signing_params(mut self, params: tx::SigningParams) -> Self {
+        self.signing_params = params;
+        self
+    }
+
+    pub fn signing_params(&self) -> &tx::SigningParams {
+        &self.signing_params
+    }
+
+    /// Rejects node replies larger than `limit` bytes with `ResponseTooLarge`.
+    pub fn with_max_response_bytes(mut self, limit: usize) -> Self {
+        self.max_response_bytes = limit;
+        self
+    }
+
+    pub fn with_max_block_range(mut self, max_block_range: u64) -> Self {
+        self.max_block_range = max_block_range;
+        self
+    }
+
+    pub async fn check_spendable_balance(&self, req: &TransactionRequest, url: &str) -> Result<()> {
+        let Some((symbol, amount)) = req.spend() else {
+            return Ok(());
+        };
+
+        let balance = self.get_account_balance(&req.signer, url).await?;
+        let available = balance
+            .balances
+            .iter()
+            .find(|b| b.symbol == symbol)
+            .map_or(0, |b| b.flat.atoms());
+
+        if amount > available {
+            return Err(BlockchainError::InsufficientBalance {
+                required: format!("{} {}", amount, symbol),
+                available: format!("{} {}", available, symbol),
+            });
+        }
+        Ok(())
     }
 
     pub async fn create_transaction_blob(
@@ -21,17 +131,7 @@ impl BlockchainClient {
             .into_vec()
             .map_err(|_| BlockchainError::ValidationFailed("invalid signer base58".into()))?;
 
-        let args: Result<Vec<Vec<u8>>> = req.args.iter().map(|arg| match arg {
-            Argument::String(s) => Ok(s.as_bytes().to_vec()),
-            Argument::Number(n) => Ok(n.to_string().as_bytes().to_vec()),
-            Argument::Base58 { b58 } => bs58::decode(b58)
-                .into_vec()
-                .map_err(|_| BlockchainError::ValidationFailed("invalid base58 arg".into())),
-            Argument::Hex { hex } => hex::decode(hex.trim_start_matches("0x"))
-                .map_err(|_| BlockchainError::ValidationFailed("invalid hex arg".into())),
-            Argument::Utf8 { utf8 } => Ok(utf8.as_bytes().to_vec()),
-        }).collect();
-        let args = args?;
+        let args = encode_args(&req.args)?;
 
         let attached_symbol = req.attached_symbol.as_ref().map(|s| s.as_bytes());
         let attached_amount = req.attached_amount.as_ref().map(|s| s.as_bytes());
@@ -44,7 +144,8 @@ impl BlockchainClient {
             attached_symbol,
             attached_amount,
             req.nonce,
-        ).map_err(|e| BlockchainError::ValidationFailed(e.into()))?;
+        )
+        .map_err(|e| BlockchainError::ValidationFailed(e.into()))?;
 
         Ok(UnsignedTransactionBlob {
             blob: bs58::encode(&unsigned.tx_blob).into_string(),
@@ -54,44 +155,220 @@ impl BlockchainClient {
         })
     }
 
-    pub async fn submit_signed_transaction(&self, tx: SignedTransaction, url: &str) -> Result<SubmitResponse> {
+    pub fn verify_signature(&self, req: &VerifySignatureRequest) -> Result<bool> {
+        let pk = bs58::decode(&req.public_key)
+            .into_vec()
+            .map_err(|_| BlockchainError::ValidationFailed("invalid public key base58".into()))?;
+        let payload = tx::SigningPayload::from_hex(&req.signing_payload, &self.signing_params)
+            .map_err(|e| BlockchainError::ValidationFailed(e.into()))?;
+        let signature = bs58::decode(&req.signature)
+            .into_vec()
+            .map_err(|_| BlockchainError::ValidationFailed("invalid signature base58".into()))?;
+
+        payload
+            .verify(&pk, &signature)
+            .map_err(|e| BlockchainError::ValidationFailed(e.into()))
+    }
+
+    pub fn verify_transaction_blob(&self, blob_b58: &str) -> Result<TransactionBlobReport> {
+        let (blob, _) = decode_blob(blob_b58)?;
+        let check = tx::check_signed(&blob, &self.signing_params.dst)
+            .map_err(|e| BlockchainError::ValidationFailed(e.into()))?;
+
+        Ok(TransactionBlobReport {
+            signer: bs58::encode(&check.signer).into_string(),
+            hash: bs58::encode(&check.stored_hash).into_string(),
+            computed_hash: bs58::encode(check.computed_hash).into_string(),
+            hash_matches: check.hash_matches(),
+            signature_valid: check.signature_valid,
+        })
+    }
+
+    /// Predicts the id a transaction will have once submitted.
+    pub fn compute_transaction_hash(&self, blob_b58: &str) -> Result<TransactionHash> {
+        transaction_hash(blob_b58)
+    }
+
+    pub fn decode_transaction(&self, blob_b58: &str) -> Result<DecodedTransaction> {
+        let (_, decoded) = decode_blob(blob_b58)?;
+        let action = decoded.tx.action;
+        let as_string = |b: Vec<u8>| String::from_utf8_lossy(&b).into_owned();
+
+        Ok(DecodedTransaction {
+            signer: bs58::encode(&decoded.tx.signer).into_string(),
+            nonce: decoded.tx.nonce,
+            op: action.op,
+            contract: action.contract,
+            function: action.function,
+            args: action
+                .args
+                .iter()
+                .map(|a| DecodedArgument::from_bytes(a))
+                .collect(),
+            attached_symbol: action.attached_symbol.map(as_string),
+            attached_amount: action.attached_amount.map(as_string),
+            hash: decoded.hash.map(|h| bs58::encode(h).into_string()),
+            signature: decoded.signature.map(|s| bs58::encode(s).into_string()),
+        })
+    }
+
+    pub async fn create_transfer_blob(
+        &self,
+        req: TransferRequest,
+        symbol: &str,
+        url: &str,
+    ) -> Result<UnsignedTransactionBlob> {
+        if self.offline_build {
+            return build_transfer_locally(&req, symbol);
+        }
+
+        let amount = match (&req.amount, &req.amount_display) {
+            (Some(atoms), None) => Some(*atoms).filter(|a| !a.is_zero()).ok_or_else(|| {
+                BlockchainError::ValidationFailed("amount must be a positive integer".into())
+            })?,
+            (None, Some(display)) => {
+                let asset = self.get_asset_info(symbol, url).await?;
+                amount::to_base_units(display, asset.decimals)?
+            }
+            _ => {
+                return Err(BlockchainError::ValidationFailed(
+                    "exactly one of amount or amount_display is required".into(),
+                ))
+            }
+        };
+
+        if let Some(memo) = &req.memo {
+            tx::check_memo(memo.as_bytes())
+                .map_err(|e| BlockchainError::ValidationFailed(e.into()))?;
+        }
+
+        let tx_req = req.into_transaction_request(symbol, amount);
+        if tx_req.preflight_balance_check.unwrap_or(false) {
+            self.check_spendable_balance(&tx_req, url).await?;
+        }
+        self.create_transaction_blob(tx_req).await
+    }
+
+    pub async fn submit_signed_transaction(
+        &self,
+        tx: SignedTransaction,
+        url: &str,
+    ) -> Result<SubmitResponse> {
+        expect_blob_signed(&tx.transaction, false)?;
+        let finalized = tx::finalize_transaction(&tx.transaction, &tx.signature)
+            .map_err(|e| BlockchainError::ValidationFailed(e.into()))?;
+        self.submit_packed_transaction(&finalized.packed, &finalized.hash, url)
+            .await
+    }
+
+    /// Broadcasts a transaction signed and packed off-platform, passing the
+    /// caller's envelope through unchanged.
+    pub async fn submit_raw_transaction(
+        &self,
+        blob_b58: &str,
+        url: &str,
+    ) -> Result<SubmitResponse> {
+        let tx_hash = raw_transaction_hash(blob_b58)?;
+        self.submit_envelope(blob_b58.to_string(), tx_hash, url)
+            .await
+    }
+
+    /// Broadcasts an already signed and packed transaction envelope.
+    pub async fn submit_packed_transaction(
+        &self,
+        packed: &[u8],
+        hash: &[u8; 32],
+        url: &str,
+    ) -> Result<SubmitResponse> {
+        let tx_hash = bs58::encode(hash).into_string();
+        self.submit_envelope(bs58::encode(packed).into_string(), Some(tx_hash), url)
+            .await
+    }
+
+    /// Same wire format as the native client: `POST /api/tx/submit` with the
+    /// base58 packed envelope as a `text/plain` body.
+    async fn submit_envelope(
+        &self,
+        txu_b58: String,
+        tx_hash: Option<String>,
+        url: &str,
+    ) -> Result<SubmitResponse> {
+        let api_response = self.post_envelope("/api/tx/submit", txu_b58, url).await?;
+        Ok(SubmitResponse::from_node(&api_response, tx_hash))
+    }
+
+    /// Simulates without broadcasting; see the native client.
+    pub async fn simulate_signed_transaction(
+        &self,
+        tx: &SignedTransaction,
+        url: &str,
+    ) -> Result<SimulationResult> {
+        expect_blob_signed(&tx.transaction, false)?;
         let finalized = tx::finalize_transaction(&tx.transaction, &tx.signature)
             .map_err(|e| BlockchainError::ValidationFailed(e.into()))?;
-        let tx_hash = bs58::encode(&finalized.hash).into_string();
-        let txu_b58 = bs58::encode(&finalized.packed).into_string();
-        let full_url = format!("{}/api/tx/submit", url);
+        let api_response = self
+            .post_envelope(
+                "/api/tx/simulate",
+                bs58::encode(&finalized.packed).into_string(),
+                url,
+            )
+            .await
+            .map_err(|e| unsupported_if_missing(e, "transaction_simulation"))?;
+        Ok(SimulationResult::from_node(
+            &api_response,
+            bs58::encode(finalized.hash).into_string(),
+        ))
+    }
+
+    pub async fn simulate_call(
+        &self,
+        req: &ContractCallRequest,
+        url: &str,
+    ) -> Result<ContractCallResult> {
+        let body = contract_call_body(req)?;
+        let resp: serde_json::Value = self
+            .request_with_url(url, "POST", "/api/contract/view", Some(&body))
+            .await
+            .map_err(|e| unsupported_if_missing(e, "contract_view"))?;
+        let mut call = ContractCallResult::from_node(&resp);
+        if let (Some(decoding), Some(result)) = (req.decode_as, &call.result) {
+            call.result = Some(decode_state_value(result, decoding)?);
+        }
+        Ok(call)
+    }
+
+    async fn post_envelope(
+        &self,
+        path: &str,
+        txu_b58: String,
+        url: &str,
+    ) -> Result<serde_json::Value> {
+        let full_url = endpoint_url(url, path);
 
         let mut init = RequestInit::new();
         init.with_method(Method::Post);
 
-        let mut headers = worker::Headers::new();
-        headers.set("Content-Type", "text/plain")
-            .map_err(|e| BlockchainError::HttpRequestWasm(e.to_string()))?;
-        init.with_headers(headers);
+        init.with_headers(self.headers("text/plain")?);
         init.with_body(Some(txu_b58.into()));
 
         let request = Request::new_with_init(&full_url, &init)
             .map_err(|e| BlockchainError::HttpRequestWasm(e.to_string()))?;
 
-        let mut response = Fetch::Request(request).send().await
+        let mut response = Fetch::Request(request)
+            .send()
+            .await
             .map_err(|e| BlockchainError::HttpRequestWasm(e.to_string()))?;
 
         let status = response.status_code();
         if !(200..300).contains(&status) {
-            return Err(BlockchainError::InvalidResponse(format!("HTTP {}", status)));
+            return Err(self.read_status_error(path, status, &mut response).await);
         }
 
-        let text = response.text().await
-            .map_err(|e| BlockchainError::HttpRequestWasm(e.to_string()))?;
-
-        let api_response: serde_json::Value = serde_json::from_str(&text)
-            .map_err(|e| BlockchainError::InvalidResponse(e.to_string()))?;
-        let error = api_response.get("error").and_then(|e| e.as_str()).unwrap_or("unknown");
+        let content_type = Self::content_type(&response);
+        let text = self.read_body(&mut response).await?;
+        check_content_type(content_type.as_deref(), &text)?;
 
-        Ok(SubmitResponse {
-            error: error.to_string(),
-            tx_hash: if error == "ok" { Some(tx_hash) } else { None },
-        })
+        parse_success_body(&text).map_err(|e| BlockchainError::InvalidResponse(e.to_string()))
     }
 
     pub async fn get_account_balance(&self, address: &str, url: &str) -> Result<AccountBalance> {
@@ -110,13 +387,167 @@ impl BlockchainClient {
 
         Ok(AccountBalance {
             address: address.to_string(),
-            balances: serde_json::from_value(balances.clone())
+            balances: deserialize_balances(balances.clone())
                 .map_err(|e| BlockchainError::InvalidResponse(e.to_string()))?,
         })
     }
 
+    /// Balances as of block `height`; see the native client.
+    pub async fn get_balance_at_height(
+        &self,
+        address: &str,
+        height: u64,
+        url: &str,
+    ) -> Result<BalanceAtHeight> {
+        let tip = self.get_chain_stats(url).await?.height;
+        if height > tip {
+            return Err(BlockchainError::ValidationFailed(format!(
+                "height {} is above the chain tip {}",
+                height, tip
+            )));
+        }
+
+        let path = format!("/api/wallet/balance_all_at/{}/{}", address, height);
+        let resp: serde_json::Value = self
+            .request_with_url(url, "GET", &path, None)
+            .await
+            .map_err(|e| unsupported_if_missing(e, "historical_balance"))?;
+        parse_balance_at_height(address, height, &resp)
+    }
+
+    pub async fn get_balance_for_symbol(
+        &self,
+        address: &str,
+        symbol: &str,
+        url: &str,
+    ) -> Result<AssetBalance> {
+        let path = format!("/api/wallet/balance/{}/{}", address, symbol);
+        let resp: serde_json::Value = self.request_with_url(url, "GET", &path, None).await?;
+        parse_asset_balance(address, symbol, &resp)
+    }
+
+    pub async fn get_account_nonce(&self, address: &str, url: &str) -> Result<u64> {
+        let path = format!("/api/chain/nonce/{}", address);
+        let resp: serde_json::Value = self
+            .request_with_url(url, "GET", &path, None)
+            .await
+            .map_err(|e| unsupported_if_missing(e, "account_nonce"))?;
+
+        resp.get("nonce")
+            .and_then(|n| n.as_u64())
+            .ok_or_else(|| BlockchainError::InvalidResponse("missing nonce".into()))
+    }
+
+    pub async fn get_account_summary(
+        &self,
+        address: &str,
+        recent: u32,
+        url: &str,
+    ) -> Result<AccountSummary> {
+        let (balance, nonce, history) = futures::join!(
+            self.get_account_balance(address, url),
+            self.get_account_nonce(address, url),
+            self.get_transaction_history(address, Some(recent), None, Some("desc"), url),
+        );
+
+        if nonce.is_err() && history.is_err() {
+            if let Err(e) = balance {
+                return Err(e);
+            }
+        }
+        Ok(AccountSummary::from_parts(address, balance, nonce, history))
+    }
+
+    pub async fn get_balances(&self, addresses: &[String], url: &str) -> Result<AccountBalances> {
+        let mut unique: Vec<&String> = addresses.iter().collect();
+        unique.sort();
+        unique.dedup();
+
+        let results: Vec<_> = stream::iter(unique)
+            .map(|address| async move {
+                (
+                    address.clone(),
+                    self.get_account_balance(address, url).await,
+                )
+            })
+            .buffer_unordered(BALANCE_LOOKUP_CONCURRENCY)
+            .collect()
+            .await;
+        Ok(AccountBalances::from_results(results))
+    }
+
+    pub async fn list_assets(&self, url: &str) -> Result<Vec<AssetInfo>> {
+        let resp: serde_json::Value = self
+            .request_with_url(url, "GET", "/api/coin/list", None)
+            .await?;
+
+        let assets = resp
+            .get("assets")
+            .ok_or_else(|| BlockchainError::InvalidResponse("missing assets".into()))?;
+
+        serde_json::from_value(assets.clone())
+            .map_err(|e| BlockchainError::InvalidResponse(e.to_string()))
+    }
+
+    pub async fn get_asset_info(&self, symbol: &str, url: &str) -> Result<AssetInfo> {
+        self.list_assets(url)
+            .await?
+            .into_iter()
+            .find(|a| a.symbol == symbol)
+            .ok_or_else(|| BlockchainError::AssetNotFound {
+                symbol: symbol.to_string(),
+            })
+    }
+
+    /// Uncached, unlike the native client: a Worker isolate rarely lives long
+    /// enough for a cache to pay off.
+    pub async fn get_supply(&self, symbol: &str, url: &str) -> Result<SupplyInfo> {
+        let path = format!("/api/coin/supply/{}", symbol);
+        let resp: serde_json::Value = self
+            .request_with_url(url, "GET", &path, None)
+            .await
+            .map_err(|e| asset_not_found_if_missing(e, symbol))?;
+        parse_supply(symbol, &resp)
+    }
+
+    pub async fn get_top_holders(&self, symbol: &str, limit: u32, url: &str) -> Result<TopHolders> {
+        check_richlist_symbol(symbol)?;
+        let resp: serde_json::Value = self
+            .request_with_url(url, "GET", "/api/contract/richlist", None)
+            .await
+            .map_err(|e| unsupported_if_missing(e, "richlist"))?;
+        parse_top_holders(symbol, &resp, limit)
+    }
+
+    pub async fn health_check(&self, url: &str) -> Result<HealthStatus> {
+        let started = js_sys::Date::now();
+        let stats = self.get_chain_stats(url).await;
+        let latency_ms = (js_sys::Date::now() - started) as u64;
+
+        match stats {
+            Ok(stats) => Ok(HealthStatus {
+                reachable: true,
+                height: Some(stats.height),
+                latency_ms,
+            }),
+            Err(BlockchainError::HttpRequestWasm(_)) => Ok(HealthStatus {
+                reachable: false,
+                height: None,
+                latency_ms,
+            }),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// GETs a node endpoint whose reply the worker passes through unchanged.
+    pub async fn get_raw(&self, path: &str, url: &str) -> Result<serde_json::Value> {
+        self.request_with_url(url, "GET", path, None).await
+    }
+
     pub async fn get_chain_stats(&self, url: &str) -> Result<ChainStats> {
-        let resp: serde_json::Value = self.request_with_url(url, "GET", "/api/chain/stats", None).await?;
+        let resp: serde_json::Value = self
+            .request_with_url(url, "GET", "/api/chain/stats", None)
+            .await?;
 
         let stats = resp
             .get("stats")
@@ -126,6 +557,22 @@ impl BlockchainClient {
             .map_err(|e| BlockchainError::InvalidResponse(e.to_string()))
     }
 
+    pub async fn get_fee_schedule(&self, url: &str) -> Result<FeeSchedule> {
+        let resp: serde_json::Value = self
+            .request_with_url(url, "GET", "/api/chain/fee_schedule", None)
+            .await
+            .map_err(|e| unsupported_if_missing(e, "fee_schedule"))?;
+        parse_fee_schedule(&resp)
+    }
+
+    pub async fn get_network_info(&self, url: &str) -> Result<NetworkInfo> {
+        let resp: serde_json::Value = self
+            .request_with_url(url, "GET", "/api/chain/info", None)
+            .await
+            .map_err(|e| unsupported_if_missing(e, "network_info"))?;
+        parse_network_info(&resp)
+    }
+
     pub async fn get_block_by_height(&self, height: u64, url: &str) -> Result<Vec<BlockEntry>> {
         let path = format!("/api/chain/height/{}", height);
         let resp: serde_json::Value = self.request_with_url(url, "GET", &path, None).await?;
@@ -138,18 +585,219 @@ impl BlockchainClient {
             .map_err(|e| BlockchainError::InvalidResponse(e.to_string()))
     }
 
+    pub async fn get_block_by_hash(&self, hash: &str, url: &str) -> Result<Vec<BlockEntry>> {
+        let hash_b58 = block_hash_b58(hash).ok_or_else(|| {
+            BlockchainError::ValidationFailed(format!("invalid block hash: {}", hash))
+        })?;
+        let path = format!("/api/chain/hash/{}", hash_b58);
+        let resp: serde_json::Value = self
+            .request_with_url(url, "GET", &path, None)
+            .await
+            .map_err(|e| block_not_found_if_missing(e, hash))?;
+
+        parse_block_entries(hash, resp)
+    }
+
+    pub async fn get_latest_block(&self, url: &str) -> Result<BlockAtHeight> {
+        let height = self.get_chain_stats(url).await?.height;
+        let entries = self.get_block_by_height(height, url).await?;
+        Ok(BlockAtHeight { height, entries })
+    }
+
+    pub async fn get_block_range(
+        &self,
+        from_height: u64,
+        to_height: u64,
+        strict: bool,
+        url: &str,
+    ) -> Result<BlockRangeResult> {
+        if from_height > to_height {
+            return Err(BlockchainError::ValidationFailed(
+                "from_height must not exceed to_height".into(),
+            ));
+        }
+        if to_height - from_height >= self.max_block_range {
+            return Err(BlockchainError::ValidationFailed(format!(
+                "block range spans more than {} blocks",
+                self.max_block_range
+            )));
+        }
+
+        let fetches = stream::iter(from_height..=to_height)
+            .map(|height| async move {
+                let entries = self.get_block_by_height(height, url).await;
+                (
+                    height,
+                    entries.map(|entries| BlockAtHeight { height, entries }),
+                )
+            })
+            .buffer_unordered(BLOCK_RANGE_CONCURRENCY);
+
+        let mut blocks = Vec::new();
+        let mut failed = Vec::new();
+        if strict {
+            blocks = fetches.map(|(_, block)| block).try_collect().await?;
+        } else {
+            let results: Vec<_> = fetches.collect().await;
+            for (height, block) in results {
+                match block {
+                    Ok(block) => blocks.push(block),
+                    Err(e) => failed.push((height, e)),
+                }
+            }
+        }
+
+        // nothing came back, so the node is more likely down than missing heights
+        if blocks.is_empty() && !failed.is_empty() {
+            failed.sort_by_key(|(height, _)| *height);
+            return Err(failed.swap_remove(0).1);
+        }
+
+        blocks.sort_by_key(|b| b.height);
+        let mut failed_heights: Vec<FailedHeight> = failed
+            .into_iter()
+            .map(|(height, e)| FailedHeight {
+                height,
+                error: e.to_string(),
+            })
+            .collect();
+        failed_heights.sort_by_key(|f| f.height);
+        Ok(BlockRangeResult {
+            count: blocks.len(),
+            blocks,
+            failed_heights,
+        })
+    }
+
     pub async fn get_transaction(&self, tx_hash: &str, url: &str) -> Result<Transaction> {
         let path = format!("/api/chain/tx/{}", tx_hash);
         let resp: serde_json::Value = self.request_with_url(url, "GET", &path, None).await?;
 
-        if resp.get("result").and_then(|r| r.get("error")).and_then(|e| e.as_str()) == Some("not_found") {
-            return Err(BlockchainError::InvalidResponse(
-                "transaction not found".into(),
-            ));
+        if resp
+            .get("result")
+            .and_then(|r| r.get("error"))
+            .and_then(|e| e.as_str())
+            == Some("not_found")
+        {
+            return Err(BlockchainError::TransactionNotFound {
+                hash: tx_hash.to_string(),
+            });
         }
 
-        serde_json::from_value(resp)
-            .map_err(|e| BlockchainError::InvalidResponse(e.to_string()))
+        serde_json::from_value(resp).map_err(|e| BlockchainError::InvalidResponse(e.to_string()))
+    }
+
+    pub async fn get_transaction_receipt(
+        &self,
+        tx_hash: &str,
+        url: &str,
+    ) -> Result<TransactionReceiptReport> {
+        let tx = self.get_transaction(tx_hash, url).await?;
+        Ok(TransactionReceiptReport::from_transaction(tx))
+    }
+
+    pub async fn get_transaction_status(
+        &self,
+        tx_hash: &str,
+        url: &str,
+    ) -> Result<TransactionStatus> {
+        let path = format!("/api/chain/tx/{}", tx_hash);
+        let resp: serde_json::Value = self.request_with_url(url, "GET", &path, None).await?;
+
+        Ok(TransactionStatus::from_response(&resp))
+    }
+
+    /// Same polling as the native client. Workers have no monotonic clock, so
+    /// elapsed time is the sum of the sleeps between polls.
+    pub async fn wait_for_transaction(
+        &self,
+        tx_hash: &str,
+        timeout: std::time::Duration,
+        url: &str,
+    ) -> Result<TransactionWait> {
+        let mut waited = std::time::Duration::ZERO;
+        let mut interval = self.poll_backoff.initial;
+        let mut polls = 0;
+
+        loop {
+            let status = match self.get_transaction_status(tx_hash, url).await {
+                Ok(status) => status,
+                // an unreachable or overloaded node says nothing about the transaction
+                Err(e) if e.is_retryable() => TransactionStatus::Pending,
+                Err(e) => return Err(e),
+            };
+            polls += 1;
+            let pending = matches!(status, TransactionStatus::Pending);
+            if !pending || waited >= timeout {
+                return Ok(TransactionWait {
+                    tx_hash: tx_hash.to_string(),
+                    status,
+                    polls,
+                    waited_ms: waited.as_millis() as u64,
+                    timed_out: pending,
+                });
+            }
+
+            let delay = interval.min(timeout - waited);
+            worker::Delay::from(delay).await;
+            waited += delay;
+            interval = self.poll_backoff.next(interval);
+        }
+    }
+
+    /// Same tip polling as the native client, timed like `wait_for_transaction`.
+    pub async fn wait_for_next_block(
+        &self,
+        after_height: u64,
+        timeout: std::time::Duration,
+        url: &str,
+    ) -> Result<BlockWait> {
+        let mut waited = std::time::Duration::ZERO;
+        let mut interval = self.poll_backoff.initial;
+        let mut polls = 0;
+
+        loop {
+            let height = self.get_chain_stats(url).await?.height;
+            polls += 1;
+            let advanced = height > after_height;
+            if advanced || waited >= timeout {
+                return Ok(BlockWait {
+                    after_height,
+                    height,
+                    polls,
+                    waited_ms: waited.as_millis() as u64,
+                    timed_out: !advanced,
+                });
+            }
+
+            let delay = interval.min(timeout - waited);
+            worker::Delay::from(delay).await;
+            waited += delay;
+            interval = self.poll_backoff.next(interval);
+        }
+    }
+
+    pub async fn get_pending_transactions(
+        &self,
+        address: Option<&str>,
+        url: &str,
+    ) -> Result<Vec<PendingTransaction>> {
+        let resp: serde_json::Value = self
+            .request_with_url(url, "GET", "/api/chain/mempool", None)
+            .await
+            .map_err(|e| unsupported_if_missing(e, "mempool"))?;
+
+        let txs = resp
+            .get("txs")
+            .ok_or_else(|| BlockchainError::InvalidResponse("missing txs".into()))?;
+
+        let txs: Vec<PendingTransaction> = serde_json::from_value(txs.clone())
+            .map_err(|e| BlockchainError::InvalidResponse(e.to_string()))?;
+
+        Ok(match address {
+            Some(address) => txs.into_iter().filter(|t| t.tx.signer == address).collect(),
+            None => txs,
+        })
     }
 
     pub async fn get_transaction_history(
@@ -159,7 +807,7 @@ impl BlockchainClient {
         offset: Option<u32>,
         sort: Option<&str>,
         url: &str,
-    ) -> Result<Vec<Transaction>> {
+    ) -> Result<TransactionHistoryPage> {
         let mut path = format!("/api/chain/tx_events_by_account/{}", address);
         let mut params = vec![];
         if let Some(l) = limit {
@@ -181,12 +829,188 @@ impl BlockchainClient {
             .get("txs")
             .ok_or_else(|| BlockchainError::InvalidResponse("missing txs".into()))?;
 
-        serde_json::from_value(txs.clone())
-            .map_err(|e| BlockchainError::InvalidResponse(e.to_string()))
+        let txs: Vec<Transaction> = serde_json::from_value(txs.clone())
+            .map_err(|e| BlockchainError::InvalidResponse(e.to_string()))?;
+
+        Ok(TransactionHistoryPage::new(txs, limit, offset))
+    }
+
+    pub async fn get_transaction_history_all(
+        &self,
+        address: &str,
+        cap: usize,
+        sort: Option<&str>,
+        url: &str,
+    ) -> Result<Vec<Transaction>> {
+        let mut all: Vec<Transaction> = Vec::new();
+        let mut offset = 0u32;
+        let mut last_first_hash: Option<String> = None;
+
+        for _ in 0..HISTORY_MAX_PAGES {
+            let page = self
+                .get_transaction_history(address, Some(HISTORY_PAGE_SIZE), Some(offset), sort, url)
+                .await?
+                .transactions;
+            let page_len = page.len();
+
+            // A node that ignores the offset keeps returning the same page
+            let first_hash = page.first().map(|t| t.hash.clone());
+            if first_hash.is_none() || first_hash == last_first_hash {
+                break;
+            }
+            last_first_hash = first_hash;
+
+            all.extend(page);
+            if all.len() >= cap || page_len < HISTORY_PAGE_SIZE as usize {
+                break;
+            }
+            offset += page_len as u32;
+        }
+
+        all.truncate(cap);
+        Ok(all)
+    }
+
+    pub async fn get_transactions_since(
+        &self,
+        address: &str,
+        min_height: u64,
+        url: &str,
+    ) -> Result<TransactionsSince> {
+        let mut transactions: Vec<Transaction> = Vec::new();
+        let mut offset = 0u32;
+        let mut last_first_hash: Option<String> = None;
+        let mut complete = false;
+
+        for _ in 0..HISTORY_MAX_PAGES {
+            let page = self
+                .get_transaction_history(
+                    address,
+                    Some(HISTORY_PAGE_SIZE),
+                    Some(offset),
+                    Some("desc"),
+                    url,
+                )
+                .await?
+                .transactions;
+            let page_len = page.len();
+
+            let first_hash = page.first().map(|t| t.hash.clone());
+            if first_hash.is_none() || first_hash == last_first_hash {
+                complete = true;
+                break;
+            }
+            last_first_hash = first_hash;
+
+            let reached_older = page.iter().any(|t| t.metadata.entry_height < min_height);
+            transactions.extend(
+                page.into_iter()
+                    .filter(|t| t.metadata.entry_height >= min_height),
+            );
+            if reached_older || page_len < HISTORY_PAGE_SIZE as usize {
+                complete = true;
+                break;
+            }
+            offset += page_len as u32;
+        }
+
+        transactions.sort_by_key(|t| t.metadata.entry_height);
+        Ok(TransactionsSince {
+            next_min_height: transactions.last().map(|t| t.metadata.entry_height),
+            transactions,
+            min_height,
+            complete,
+        })
+    }
+
+    /// Client-side search; see the native client for the scan limits.
+    pub async fn search_transactions(
+        &self,
+        query: &SearchTransactionsQuery,
+        url: &str,
+    ) -> Result<TransactionSearchResult> {
+        if !query.has_filter() {
+            return Err(BlockchainError::ValidationFailed(
+                "at least one search filter is required".into(),
+            ));
+        }
+        let min_amount = match &query.min_amount {
+            Some(m) => Some(amount::parse_atoms(m)?),
+            None => None,
+        };
+
+        let candidates = match &query.counterparty {
+            Some(address) => {
+                self.get_transaction_history_all(address, SEARCH_HISTORY_CAP, None, url)
+                    .await?
+            }
+            None => {
+                let (Some(from), Some(to)) = (query.from_height, query.to_height) else {
+                    return Err(BlockchainError::ValidationFailed(
+                        "from_height and to_height are required unless counterparty is set".into(),
+                    ));
+                };
+                if from > to || to - from >= self.max_block_range {
+                    return Err(BlockchainError::ValidationFailed(format!(
+                        "height range must be ordered and span at most {} blocks",
+                        self.max_block_range
+                    )));
+                }
+                let mut txs: Vec<Transaction> = stream::iter(from..=to)
+                    .map(|height| self.get_transactions_at_height(height, url))
+                    .buffered(BLOCK_RANGE_CONCURRENCY)
+                    .try_collect::<Vec<_>>()
+                    .await?
+                    .into_iter()
+                    .flatten()
+                    .collect();
+                txs.sort_by_key(|t| t.metadata.entry_height);
+                txs
+            }
+        };
+
+        let scanned = candidates.len();
+        let offset = query.offset.unwrap_or(0) as usize;
+        let limit = query.limit.unwrap_or(SEARCH_DEFAULT_LIMIT) as usize;
+        let mut matches = candidates
+            .into_iter()
+            .filter(|t| query.matches(t, min_amount))
+            .skip(offset);
+        let transactions: Vec<Transaction> = matches.by_ref().take(limit).collect();
+        let next_offset = matches.next().map(|_| (offset + transactions.len()) as u32);
+
+        Ok(TransactionSearchResult {
+            transactions,
+            next_offset,
+            scanned,
+        })
+    }
+
+    pub async fn get_transactions_at_height(
+        &self,
+        height: u64,
+        url: &str,
+    ) -> Result<Vec<Transaction>> {
+        let path = format!("/api/chain/height_with_txs/{}", height);
+        let resp: serde_json::Value = self.request_with_url(url, "GET", &path, None).await?;
+
+        parse_entry_txs(&resp)
+    }
+
+    pub async fn get_transaction_by_position(
+        &self,
+        height: u64,
+        index: u32,
+        url: &str,
+    ) -> Result<TransactionAtPosition> {
+        let transactions = self.get_transactions_at_height(height, url).await?;
+        TransactionAtPosition::select(height, index, transactions)
     }
 
     pub async fn get_validators(&self, url: &str) -> Result<Vec<ValidatorInfo>> {
-        let resp: serde_json::Value = self.request_with_url(url, "GET", "/api/peer/trainers", None).await?;
+        let resp: serde_json::Value = self
+            .request_with_url(url, "GET", "/api/peer/trainers", None)
+            .await?;
 
         let trainers = resp
             .get("trainers")
@@ -196,6 +1020,34 @@ impl BlockchainClient {
             .map_err(|e| BlockchainError::InvalidResponse(e.to_string()))
     }
 
+    pub async fn get_validator_details(
+        &self,
+        validator: &str,
+        url: &str,
+    ) -> Result<ValidatorDetails> {
+        let path = format!("/api/peer/trainer/{}", validator);
+        let resp: serde_json::Value = self
+            .request_with_url(url, "GET", &path, None)
+            .await
+            .map_err(|e| unsupported_if_missing(e, "validator_details"))?;
+
+        parse_validator_details(validator, resp)
+    }
+
+    pub async fn get_validators_detailed(&self, url: &str) -> Result<Vec<ValidatorDetails>> {
+        let validators = self.get_validators(url).await?;
+
+        stream::iter(validators)
+            .map(|peer| async move {
+                let mut details = self.get_validator_details(&peer.pk, url).await?;
+                details.peer = Some(peer);
+                Ok::<_, BlockchainError>(details)
+            })
+            .buffered(BLOCK_RANGE_CONCURRENCY)
+            .try_collect()
+            .await
+    }
+
     pub async fn get_contract_state(
         &self,
         contract_address: &str,
@@ -206,6 +1058,106 @@ impl BlockchainClient {
         self.request_with_url(url, "GET", &path, None).await
     }
 
+    pub async fn get_contract_states(
+        &self,
+        contract_address: &str,
+        keys: &[String],
+        url: &str,
+    ) -> Result<ContractStates> {
+        let mut unique: Vec<&String> = keys.iter().collect();
+        unique.sort();
+        unique.dedup();
+
+        let results: Vec<_> = stream::iter(unique)
+            .map(|key| async move {
+                (
+                    key.clone(),
+                    self.get_contract_state(contract_address, key, url).await,
+                )
+            })
+            .buffer_unordered(CONTRACT_STATE_CONCURRENCY)
+            .collect()
+            .await;
+        Ok(ContractStates::from_results(contract_address, results))
+    }
+
+    pub async fn resolve_name(&self, name: &str, url: &str) -> Result<String> {
+        let value = self
+            .get_contract_state(NAME_CONTRACT, &format!("name:{}", name), url)
+            .await?;
+        state_string(&value).ok_or_else(|| BlockchainError::AccountNotFound {
+            address: name.to_string(),
+        })
+    }
+
+    pub async fn reverse_resolve(&self, address: &str, url: &str) -> Result<String> {
+        let value = self
+            .get_contract_state(NAME_CONTRACT, &format!("addr:{}", address), url)
+            .await?;
+        state_string(&value).ok_or_else(|| BlockchainError::AccountNotFound {
+            address: address.to_string(),
+        })
+    }
+
+    pub async fn get_contract_code(
+        &self,
+        contract_address: &str,
+        url: &str,
+    ) -> Result<ContractInfo> {
+        let path = format!("/api/contract/info/{}", contract_address);
+        let resp: serde_json::Value = self
+            .request_with_url(url, "GET", &path, None)
+            .await
+            .map_err(|e| not_a_contract_if_missing(e, contract_address))?;
+
+        parse_contract_info(contract_address, resp)
+    }
+
+    pub async fn list_contract_keys(
+        &self,
+        query: &ContractKeysQuery,
+        url: &str,
+    ) -> Result<ContractKeys> {
+        let path = contract_keys_path(query);
+        let resp: serde_json::Value = self
+            .request_with_url(url, "GET", &path, None)
+            .await
+            .map_err(|e| unsupported_if_missing(e, "contract_keys"))?;
+
+        let keys = resp
+            .get("keys")
+            .ok_or_else(|| BlockchainError::InvalidResponse("missing keys".into()))?;
+
+        Ok(ContractKeys {
+            contract_address: query.contract_address.clone(),
+            keys: serde_json::from_value(keys.clone())
+                .map_err(|e| BlockchainError::InvalidResponse(e.to_string()))?,
+            next_cursor: resp
+                .get("next_cursor")
+                .and_then(|c| c.as_str())
+                .map(str::to_string),
+        })
+    }
+
+    fn headers(&self, content_type: &str) -> Result<worker::Headers> {
+        let mut headers = worker::Headers::new();
+        headers
+            .set("Content-Type", content_type)
+            .map_err(|e| BlockchainError::HttpRequestWasm(e.to_string()))?;
+        headers
+            .set("User-Agent", &self.user_agent)
+            .map_err(|e| BlockchainError::HttpRequestWasm(e.to_string()))?;
+        if let Some((name, value)) = &self.auth_header {
+            headers.set(name, value).map_err(|_| {
+                BlockchainError::Configuration(format!("invalid value for header {}", name))
+            })?;
+        }
+        Ok(headers)
+    }
+
+    /// Compression needs no handling here: the Workers runtime negotiates
+    /// `Accept-Encoding` and decompresses fetch responses itself, matching the
+    /// gzip/deflate support enabled on the native client.
     async fn request_with_url<T: serde::de::DeserializeOwned>(
         &self,
         base_url: &str,
@@ -213,7 +1165,7 @@ impl BlockchainClient {
         path: &str,
         body: Option<&serde_json::Value>,
     ) -> Result<T> {
-        let url = format!("{}{}", base_url.trim_end_matches('/'), path);
+        let url = endpoint_url(base_url, path);
         let mut init = RequestInit::new();
         init.with_method(if method == "GET" {
             Method::Get
@@ -221,11 +1173,7 @@ impl BlockchainClient {
             Method::Post
         });
 
-        let mut headers = worker::Headers::new();
-        headers
-            .set("Content-Type", "application/json")
-            .map_err(|e| BlockchainError::HttpRequestWasm(e.to_string()))?;
-        init.with_headers(headers);
+        init.with_headers(self.headers("application/json")?);
 
         if let Some(json) = body {
             init.with_body(Some(
@@ -245,14 +1193,55 @@ impl BlockchainClient {
 
         let status = response.status_code();
         if !(200..300).contains(&status) {
-            return Err(BlockchainError::InvalidResponse(format!("HTTP {}", status)));
+            return Err(self.read_status_error(path, status, &mut response).await);
+        }
+
+        let content_type = Self::content_type(&response);
+        let text = self.read_body(&mut response).await?;
+        check_content_type(content_type.as_deref(), &text)?;
+
+        parse_success_body(&text).map_err(|e| BlockchainError::InvalidResponse(e.to_string()))
+    }
+
+    /// Fetch offers no streaming byte counter here, so the cap is enforced from
+    /// `Content-Length` up front and checked again once the body is read.
+    async fn read_body(&self, response: &mut worker::Response) -> Result<String> {
+        let limit = self.max_response_bytes;
+        let declared = response
+            .headers()
+            .get("Content-Length")
+            .ok()
+            .flatten()
+            .and_then(|v| v.parse::<u64>().ok());
+        if declared.is_some_and(|len| len > limit as u64) {
+            return Err(BlockchainError::ResponseTooLarge { limit });
         }
 
         let text = response
             .text()
             .await
             .map_err(|e| BlockchainError::HttpRequestWasm(e.to_string()))?;
+        if text.len() > limit {
+            return Err(BlockchainError::ResponseTooLarge { limit });
+        }
+        Ok(text)
+    }
 
-        serde_json::from_str(&text).map_err(|e| BlockchainError::InvalidResponse(e.to_string()))
+    fn content_type(response: &worker::Response) -> Option<String> {
+        response.headers().get("Content-Type").ok().flatten()
+    }
+
+    /// Error bodies are held to the same cap as successful ones.
+    async fn read_status_error(
+        &self,
+        path: &str,
+        status: u16,
+        response: &mut worker::Response,
+    ) -> BlockchainError {
+        match self.read_body(response).await {
+            Ok(body) => status_error(status, path, &body),
+            Err(e @ BlockchainError::ResponseTooLarge { .. }) => e,
+            Err(_) => status_error(status, path, ""),
+        }
     }
 }
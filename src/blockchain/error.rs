@@ -10,6 +10,16 @@ pub enum BlockchainError {
     #[error("HTTP request failed: {0}")]
     HttpRequestWasm(String),
 
+    #[error("HTTP {status} from {path}: {body_snippet}")]
+    HttpStatus {
+        status: u16,
+        path: String,
+        body_snippet: String,
+    },
+
+    #[error("Node rejected request (HTTP {status}): {message}")]
+    NodeError { status: u16, message: String },
+
     #[error("Invalid response from blockchain: {0}")]
     InvalidResponse(String),
 
@@ -19,12 +29,39 @@ pub enum BlockchainError {
     #[error("Account not found: {address}")]
     AccountNotFound { address: String },
 
+    #[error("Address is not a contract: {address}")]
+    NotAContract { address: String },
+
+    #[error("Block not found: {hash}")]
+    BlockNotFound { hash: String },
+
+    #[error("Transaction not found: {hash}")]
+    TransactionNotFound { hash: String },
+
+    #[error("Asset not found: {symbol}")]
+    AssetNotFound { symbol: String },
+
     #[error("Insufficient balance: required {required}, available {available}")]
     InsufficientBalance { required: String, available: String },
 
     #[error("Network error after {attempts} retries")]
     NetworkRetryExhausted { attempts: usize },
 
+    #[error("Node unavailable after repeated failures, retry in {retry_after_secs}s")]
+    CircuitOpen { retry_after_secs: u64 },
+
+    #[error("Expected JSON but node returned {content_type}: {body_snippet}")]
+    UnexpectedContentType {
+        content_type: String,
+        body_snippet: String,
+    },
+
+    #[error("Response exceeds the {limit}-byte limit")]
+    ResponseTooLarge { limit: usize },
+
+    #[error("Not supported by this node: {feature}")]
+    Unsupported { feature: String },
+
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
 
@@ -33,3 +70,161 @@ pub enum BlockchainError {
 }
 
 pub type Result<T> = std::result::Result<T, BlockchainError>;
+
+impl BlockchainError {
+    /// Whether the failure says nothing about the request itself: the node
+    /// was unreachable, overloaded or rate limiting, so asking again later
+    /// may succeed.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            #[cfg(not(target_arch = "wasm32"))]
+            BlockchainError::HttpRequest(_) => true,
+            #[cfg(target_arch = "wasm32")]
+            BlockchainError::HttpRequestWasm(_) => true,
+            BlockchainError::HttpStatus { status, .. }
+            | BlockchainError::NodeError { status, .. } => *status >= 500 || *status == 429,
+            BlockchainError::NetworkRetryExhausted { .. } | BlockchainError::CircuitOpen { .. } => {
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+const BODY_SNIPPET_MAX_CHARS: usize = 256;
+
+/// Maps a 404 from an optional node endpoint to `Unsupported`.
+pub fn unsupported_if_missing(error: BlockchainError, feature: &str) -> BlockchainError {
+    match error {
+        BlockchainError::HttpStatus { status: 404, .. }
+        | BlockchainError::NodeError { status: 404, .. } => BlockchainError::Unsupported {
+            feature: feature.to_string(),
+        },
+        e => e,
+    }
+}
+
+/// Maps a 404 from a contract lookup to `NotAContract`.
+pub fn not_a_contract_if_missing(error: BlockchainError, address: &str) -> BlockchainError {
+    match error {
+        BlockchainError::HttpStatus { status: 404, .. }
+        | BlockchainError::NodeError { status: 404, .. } => BlockchainError::NotAContract {
+            address: address.to_string(),
+        },
+        e => e,
+    }
+}
+
+/// Maps a 404 from a per-asset lookup to `AssetNotFound`.
+pub fn asset_not_found_if_missing(error: BlockchainError, symbol: &str) -> BlockchainError {
+    match error {
+        BlockchainError::HttpStatus { status: 404, .. }
+        | BlockchainError::NodeError { status: 404, .. } => BlockchainError::AssetNotFound {
+            symbol: symbol.to_string(),
+        },
+        e => e,
+    }
+}
+
+/// Maps a 404 from a block lookup to `BlockNotFound`.
+pub fn block_not_found_if_missing(error: BlockchainError, hash: &str) -> BlockchainError {
+    match error {
+        BlockchainError::HttpStatus { status: 404, .. }
+        | BlockchainError::NodeError { status: 404, .. } => BlockchainError::BlockNotFound {
+            hash: hash.to_string(),
+        },
+        e => e,
+    }
+}
+
+/// Builds the error for a non-2xx response, preferring the node's own
+/// `error`/`message` explanation over the raw body.
+pub fn status_error(status: u16, path: &str, body: &str) -> BlockchainError {
+    let message = serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .and_then(|v| {
+            v.get("error")
+                .or_else(|| v.get("message"))
+                .and_then(|m| m.as_str())
+                .map(str::to_string)
+        });
+
+    match message {
+        Some(message) => BlockchainError::NodeError { status, message },
+        None => BlockchainError::HttpStatus {
+            status,
+            path: path.to_string(),
+            body_snippet: body_snippet(body),
+        },
+    }
+}
+
+/// Rejects a 2xx body whose `Content-Type` is not JSON, e.g. an HTML page from a
+/// misconfigured gateway. A missing header passes, since older nodes omit it.
+pub fn check_content_type(content_type: Option<&str>, body: &str) -> Result<()> {
+    let Some(content_type) = content_type else {
+        return Ok(());
+    };
+    let mime = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    let is_json = mime.is_empty()
+        || mime == "application/json"
+        || mime == "text/json"
+        || mime.ends_with("+json");
+    if is_json || body.trim().is_empty() {
+        return Ok(());
+    }
+    Err(BlockchainError::UnexpectedContentType {
+        content_type: content_type.to_string(),
+        body_snippet: body_snippet(body),
+    })
+}
+
+/// Parses a 2xx body. An empty one (e.g. a 204) reads as JSON `null`, so it
+/// deserializes into `()`, `Option<_>` or `Value::Null` instead of failing.
+pub fn parse_success_body<T: serde::de::DeserializeOwned>(body: &str) -> serde_json::Result<T> {
+    if body.trim().is_empty() {
+        serde_json::from_value(serde_json::Value::Null)
+    } else {
+        serde_json::from_str(body)
+    }
+}
+
+/// Truncates a response body so error details never carry large payloads.
+pub fn body_snippet(body: &str) -> String {
+    match body.char_indices().nth(BODY_SNIPPET_MAX_CHARS) {
+        Some((idx, _)) => format!("{}...", &body[..idx]),
+        None => body.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_success_body_reads_as_null() {
+        for body in ["", "  \n"] {
+            parse_success_body::<()>(body).unwrap();
+            assert_eq!(parse_success_body::<Option<u64>>(body).unwrap(), None);
+            assert_eq!(
+                parse_success_body::<serde_json::Value>(body).unwrap(),
+                serde_json::Value::Null
+            );
+        }
+    }
+
+    #[test]
+    fn empty_success_body_still_fails_for_required_shapes() {
+        assert!(parse_success_body::<u64>("").is_err());
+        assert!(parse_success_body::<Vec<u64>>("").is_err());
+        assert_eq!(
+            parse_success_body::<Vec<u64>>("[1, 2]").unwrap(),
+            vec![1, 2]
+        );
+    }
+}
@@ -0,0 +1,554 @@
+use super::error::{BlockchainError, Result};
+use futures::future::BoxFuture;
+use reqwest::{header, Client};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio::time::Instant;
+
+pub struct HttpRequest {
+    pub method: String,
+    pub url: String,
+    pub content_type: &'static str,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+    /// Bodies longer than this fail with `ResponseTooLarge` instead of being buffered.
+    pub max_response_bytes: usize,
+}
+
+pub struct HttpResponse {
+    pub status: u16,
+    /// `Content-Type` header, if the server sent one
+    pub content_type: Option<String>,
+    pub body: String,
+}
+
+impl HttpResponse {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+/// Transport used by the native `BlockchainClient`, so JSON handling can be
+/// exercised against canned responses instead of a live node.
+pub trait HttpExecutor: Send + Sync {
+    fn execute(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>>;
+}
+
+/// Caps requests in flight and spaces out request starts. Clones share the
+/// same permits and schedule, so one limiter bounds every handle to a client.
+#[derive(Clone, Default)]
+pub struct RequestLimiter {
+    permits: Option<Arc<Semaphore>>,
+    min_interval: Option<Duration>,
+    next_start: Arc<tokio::sync::Mutex<Option<Instant>>>,
+}
+
+impl RequestLimiter {
+    pub fn with_max_concurrency(mut self, max: usize) -> Self {
+        self.permits = Some(Arc::new(Semaphore::new(max.max(1))));
+        self
+    }
+
+    pub fn with_min_interval(mut self, interval: Duration) -> Self {
+        self.min_interval = Some(interval);
+        self
+    }
+
+    /// Waits for a free slot; the request may proceed while the permit is held.
+    pub async fn acquire(&self) -> Option<OwnedSemaphorePermit> {
+        let permit = match &self.permits {
+            Some(permits) => permits.clone().acquire_owned().await.ok(),
+            None => None,
+        };
+
+        if let Some(interval) = self.min_interval {
+            let mut next_start = self.next_start.lock().await;
+            if let Some(at) = *next_start {
+                tokio::time::sleep_until(at).await;
+            }
+            *next_start = Some(Instant::now() + interval);
+        }
+
+        permit
+    }
+}
+
+/// Opens after `failure_threshold` consecutive failures within `window`, then
+/// rejects requests for `cooldown` before letting a single probe through. A
+/// successful probe closes the circuit; a failed one reopens it. Each node
+/// base URL has its own circuit, so a failing testnet does not block mainnet.
+#[derive(Clone)]
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    window: Duration,
+    cooldown: Duration,
+    states: Arc<Mutex<HashMap<String, BreakerState>>>,
+}
+
+#[derive(Default)]
+struct BreakerState {
+    failures: u32,
+    first_failure: Option<Instant>,
+    open_until: Option<Instant>,
+    probing: bool,
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self::new(5, Duration::from_secs(30), Duration::from_secs(30))
+    }
+}
+
+impl CircuitBreaker {
+    pub fn new(failure_threshold: u32, window: Duration, cooldown: Duration) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            window,
+            cooldown,
+            states: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    fn node_key(base_url: &str) -> String {
+        base_url.trim_end_matches('/').to_string()
+    }
+
+    /// Admits a request to `base_url`, or returns `CircuitOpen` while that
+    /// node is considered down.
+    pub fn check(&self, base_url: &str) -> Result<()> {
+        let mut states = self.states.lock().unwrap();
+        let Some(state) = states.get_mut(&Self::node_key(base_url)) else {
+            return Ok(());
+        };
+        let Some(open_until) = state.open_until else {
+            return Ok(());
+        };
+
+        let now = Instant::now();
+        if now < open_until || state.probing {
+            return Err(BlockchainError::CircuitOpen {
+                retry_after_secs: open_until.saturating_duration_since(now).as_secs().max(1),
+            });
+        }
+        state.probing = true;
+        Ok(())
+    }
+
+    pub fn record_success(&self, base_url: &str) {
+        self.states
+            .lock()
+            .unwrap()
+            .remove(&Self::node_key(base_url));
+    }
+
+    pub fn record_failure(&self, base_url: &str) {
+        let mut states = self.states.lock().unwrap();
+        let state = states.entry(Self::node_key(base_url)).or_default();
+        let now = Instant::now();
+
+        if state.probing {
+            state.probing = false;
+            state.open_until = Some(now + self.cooldown);
+            return;
+        }
+
+        match state.first_failure {
+            Some(first) if now.duration_since(first) <= self.window => state.failures += 1,
+            _ => {
+                state.first_failure = Some(now);
+                state.failures = 1;
+            }
+        }
+        if state.failures >= self.failure_threshold {
+            state.open_until = Some(now + self.cooldown);
+        }
+    }
+}
+
+/// Idle connections kept per node. A server talks to one or two nodes with
+/// moderate concurrency, so a small pool covers bursts without hoarding sockets.
+pub const DEFAULT_POOL_MAX_IDLE_PER_HOST: usize = 16;
+pub const DEFAULT_POOL_IDLE_TIMEOUT: Duration = Duration::from_secs(90);
+
+pub struct ReqwestExecutor {
+    client: Client,
+}
+
+impl ReqwestExecutor {
+    pub fn new() -> Result<Self> {
+        Self::with_pool_config(DEFAULT_POOL_MAX_IDLE_PER_HOST, DEFAULT_POOL_IDLE_TIMEOUT)
+    }
+
+    /// Keeps at most `max_idle_per_host` idle connections per host, closing
+    /// each after `idle_timeout` unused.
+    pub fn with_pool_config(max_idle_per_host: usize, idle_timeout: Duration) -> Result<Self> {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(30))
+            .pool_max_idle_per_host(max_idle_per_host)
+            .pool_idle_timeout(idle_timeout)
+            .user_agent(concat!("amadeus-mcp/", env!("CARGO_PKG_VERSION")))
+            // advertises Accept-Encoding and decodes bodies transparently; block
+            // ranges and full histories compress well
+            .gzip(true)
+            .deflate(true)
+            .build()
+            .map_err(BlockchainError::HttpRequest)?;
+
+        Ok(Self { client })
+    }
+}
+
+impl HttpExecutor for ReqwestExecutor {
+    fn execute(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+        Box::pin(async move {
+            let mut builder = match request.method.as_str() {
+                "GET" => self.client.get(&request.url),
+                "POST" => self.client.post(&request.url),
+                other => {
+                    return Err(BlockchainError::Configuration(format!(
+                        "unsupported method: {}",
+                        other
+                    )))
+                }
+            };
+
+            builder = builder.header(header::CONTENT_TYPE, request.content_type);
+            for (name, value) in &request.headers {
+                let mut value = header::HeaderValue::from_str(value).map_err(|_| {
+                    BlockchainError::Configuration(format!("invalid value for header {}", name))
+                })?;
+                // keeps credentials out of reqwest's Debug output
+                value.set_sensitive(true);
+                builder = builder.header(name.as_str(), value);
+            }
+            if let Some(body) = request.body {
+                builder = builder.body(body);
+            }
+
+            let mut response = builder.send().await.map_err(BlockchainError::HttpRequest)?;
+            let status = response.status().as_u16();
+            let content_type = response
+                .headers()
+                .get(header::CONTENT_TYPE)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string);
+            let limit = request.max_response_bytes;
+            let too_large = || BlockchainError::ResponseTooLarge { limit };
+            if response
+                .content_length()
+                .is_some_and(|len| len > limit as u64)
+            {
+                return Err(too_large());
+            }
+
+            // counts decoded bytes as they arrive, so a lying or missing
+            // Content-Length cannot make us buffer past the limit
+            let mut bytes = Vec::new();
+            while let Some(chunk) = response
+                .chunk()
+                .await
+                .map_err(BlockchainError::HttpRequest)?
+            {
+                if bytes.len() + chunk.len() > limit {
+                    return Err(too_large());
+                }
+                bytes.extend_from_slice(&chunk);
+            }
+            let body = String::from_utf8_lossy(&bytes).into_owned();
+
+            Ok(HttpResponse {
+                status,
+                content_type,
+                body,
+            })
+        })
+    }
+}
+
+/// Serves canned responses keyed by request path (including any query
+/// string); unknown paths answer 404. Built for tests and for downstream
+/// crates enabling the `test-util` feature.
+#[cfg(any(test, feature = "test-util"))]
+#[derive(Default)]
+pub struct MockExecutor {
+    responses: Mutex<HashMap<String, (u16, Option<String>, String)>>,
+    requests: Mutex<Vec<String>>,
+}
+
+#[cfg(any(test, feature = "test-util"))]
+impl MockExecutor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_response(self, path: &str, status: u16, body: serde_json::Value) -> Self {
+        self.with_raw_response(path, status, Some("application/json"), &body.to_string())
+    }
+
+    /// Serves `body` verbatim with the given `Content-Type`, e.g. an HTML
+    /// error page from a gateway in front of the node.
+    pub fn with_raw_response(
+        self,
+        path: &str,
+        status: u16,
+        content_type: Option<&str>,
+        body: &str,
+    ) -> Self {
+        self.responses.lock().unwrap().insert(
+            path.to_string(),
+            (status, content_type.map(str::to_string), body.to_string()),
+        );
+        self
+    }
+
+    /// Every request served so far as `"METHOD /api/path"`, oldest first.
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Loads a fixture set mapping request paths to responses, either
+    /// `{"status": 500, "body": ...}` or a bare body served with status 200:
+    ///
+    /// ```json
+    /// { "/api/chain/stats": {"height": 42}, "/api/coin/list": {"status": 503, "body": null} }
+    /// ```
+    ///
+    /// Only an object whose keys are a numeric `status` and an optional `body`
+    /// is a wrapper; a node reply that merely has a `status` field among
+    /// others is served as a bare body.
+    pub fn with_fixtures(self, fixtures: &serde_json::Value) -> Result<Self> {
+        let entries = fixtures.as_object().ok_or_else(|| {
+            BlockchainError::ValidationFailed("fixtures must be an object keyed by path".into())
+        })?;
+        let mut mock = self;
+        for (path, fixture) in entries {
+            let wrapper_status = fixture.as_object().and_then(|object| {
+                let only_wrapper_keys = object.keys().all(|k| k == "status" || k == "body");
+                object
+                    .get("status")
+                    .and_then(|s| s.as_u64())
+                    .filter(|_| only_wrapper_keys)
+            });
+            let (status, body) = match wrapper_status {
+                Some(status) => {
+                    let status = u16::try_from(status).map_err(|_| {
+                        BlockchainError::ValidationFailed(format!(
+                            "fixture {} has an invalid status",
+                            path
+                        ))
+                    })?;
+                    (status, fixture.get("body").cloned().unwrap_or_default())
+                }
+                None => (200, fixture.clone()),
+            };
+            mock = mock.with_response(path, status, body);
+        }
+        Ok(mock)
+    }
+}
+
+#[cfg(any(test, feature = "test-util"))]
+impl HttpExecutor for MockExecutor {
+    fn execute(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+        let path = request
+            .url
+            .find("/api/")
+            .map(|idx| request.url[idx..].to_string())
+            .unwrap_or(request.url);
+        self.requests
+            .lock()
+            .unwrap()
+            .push(format!("{} {}", request.method, path));
+        let (status, content_type, body) = self
+            .responses
+            .lock()
+            .unwrap()
+            .get(&path)
+            .cloned()
+            .unwrap_or((404, None, String::new()));
+        let limit = request.max_response_bytes;
+
+        Box::pin(async move {
+            if body.len() > limit {
+                return Err(BlockchainError::ResponseTooLarge { limit });
+            }
+            Ok(HttpResponse {
+                status,
+                content_type,
+                body,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAINNET: &str = "https://nodes.amadeus.bot";
+    const TESTNET: &str = "https://testnet.amadeus.bot";
+
+    /// Serves one connection with `response` written verbatim, returning the URL.
+    async fn serve_once(response: Vec<u8>) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let n = socket.read(&mut buf).await.unwrap();
+                if n == 0 {
+                    return;
+                }
+                request.extend_from_slice(&buf[..n]);
+            }
+            let _ = socket.write_all(&response).await;
+            let _ = socket.shutdown().await;
+        });
+        format!("http://{}/api/chain/stats", addr)
+    }
+
+    /// A 200 whose body is sent in 1 KiB chunks without a `Content-Length`.
+    fn chunked_response(body_len: usize) -> Vec<u8> {
+        let mut response =
+            b"HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ntransfer-encoding: chunked\r\nconnection: close\r\n\r\n"
+                .to_vec();
+        let body = vec![b'1'; body_len];
+        for chunk in body.chunks(1024) {
+            response.extend_from_slice(format!("{:x}\r\n", chunk.len()).as_bytes());
+            response.extend_from_slice(chunk);
+            response.extend_from_slice(b"\r\n");
+        }
+        response.extend_from_slice(b"0\r\n\r\n");
+        response
+    }
+
+    fn get(url: String, max_response_bytes: usize) -> HttpRequest {
+        HttpRequest {
+            method: "GET".to_string(),
+            url,
+            content_type: "application/json",
+            headers: Vec::new(),
+            body: None,
+            max_response_bytes,
+        }
+    }
+
+    #[test]
+    fn breaker_opens_per_node() {
+        let breaker = CircuitBreaker::new(2, Duration::from_secs(60), Duration::from_secs(60));
+        breaker.record_failure(TESTNET);
+        assert!(breaker.check(TESTNET).is_ok());
+        breaker.record_failure(TESTNET);
+
+        assert!(matches!(
+            breaker.check(TESTNET),
+            Err(BlockchainError::CircuitOpen { .. })
+        ));
+        assert!(matches!(
+            breaker.check("https://testnet.amadeus.bot/"),
+            Err(BlockchainError::CircuitOpen { .. })
+        ));
+        assert!(breaker.check(MAINNET).is_ok());
+    }
+
+    #[tokio::test]
+    async fn breaker_probes_after_cooldown() {
+        let cooldown = Duration::from_millis(50);
+        let breaker = CircuitBreaker::new(1, Duration::from_secs(60), cooldown);
+        breaker.record_failure(MAINNET);
+        assert!(breaker.check(MAINNET).is_err());
+
+        // half-open: one probe is admitted, others wait for its outcome
+        tokio::time::sleep(cooldown * 2).await;
+        assert!(breaker.check(MAINNET).is_ok());
+        assert!(breaker.check(MAINNET).is_err());
+
+        // a failed probe reopens the circuit for another cooldown
+        breaker.record_failure(MAINNET);
+        assert!(breaker.check(MAINNET).is_err());
+
+        tokio::time::sleep(cooldown * 2).await;
+        assert!(breaker.check(MAINNET).is_ok());
+        breaker.record_success(MAINNET);
+        assert!(breaker.check(MAINNET).is_ok());
+        assert!(breaker.check(MAINNET).is_ok());
+    }
+
+    #[tokio::test]
+    async fn streamed_body_over_the_limit_is_rejected() {
+        let executor = ReqwestExecutor::new().unwrap();
+        let url = serve_once(chunked_response(64 * 1024)).await;
+
+        let result = executor.execute(get(url, 4 * 1024)).await;
+        assert!(matches!(
+            result,
+            Err(BlockchainError::ResponseTooLarge { limit: 4096 })
+        ));
+    }
+
+    #[tokio::test]
+    async fn declared_length_over_the_limit_is_rejected() {
+        let executor = ReqwestExecutor::new().unwrap();
+        let mut response =
+            b"HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 8192\r\nconnection: close\r\n\r\n"
+                .to_vec();
+        response.extend_from_slice(&[b'1'; 8192]);
+        let url = serve_once(response).await;
+
+        let result = executor.execute(get(url, 4 * 1024)).await;
+        assert!(matches!(
+            result,
+            Err(BlockchainError::ResponseTooLarge { limit: 4096 })
+        ));
+    }
+
+    #[tokio::test]
+    async fn streamed_body_within_the_limit_is_read() {
+        let executor = ReqwestExecutor::new().unwrap();
+        let url = serve_once(chunked_response(3000)).await;
+
+        let response = executor.execute(get(url, 4 * 1024)).await.unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body.len(), 3000);
+    }
+
+    #[tokio::test]
+    async fn fixtures_only_unwrap_status_and_body_objects() {
+        let mock = MockExecutor::new()
+            .with_fixtures(&serde_json::json!({
+                "/api/chain/stats": {"height": 42},
+                "/api/coin/list": {"status": 503, "body": {"error": "busy"}},
+                "/api/chain/tx/abc": {"status": 2, "hash": "abc"},
+                "/api/peer/nodes": {"status": 204},
+            }))
+            .unwrap();
+        let fetch = |path: &str| mock.execute(get(format!("http://node{}", path), 1024));
+
+        let stats = fetch("/api/chain/stats").await.unwrap();
+        assert_eq!(
+            (stats.status, stats.body.as_str()),
+            (200, r#"{"height":42}"#)
+        );
+
+        let busy = fetch("/api/coin/list").await.unwrap();
+        assert_eq!(
+            (busy.status, busy.body.as_str()),
+            (503, r#"{"error":"busy"}"#)
+        );
+
+        // a reply with a numeric `status` among other fields is a plain body
+        let tx = fetch("/api/chain/tx/abc").await.unwrap();
+        assert_eq!(tx.status, 200);
+        let body: serde_json::Value = serde_json::from_str(&tx.body).unwrap();
+        assert_eq!(body, serde_json::json!({"status": 2, "hash": "abc"}));
+
+        let empty = fetch("/api/peer/nodes").await.unwrap();
+        assert_eq!((empty.status, empty.body.as_str()), (204, "null"));
+    }
+}
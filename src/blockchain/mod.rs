@@ -3,7 +3,11 @@ pub mod client;
 #[cfg(target_arch = "wasm32")]
 pub mod client_wasm;
 
+pub mod amount;
 pub mod error;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod http;
+pub mod shared;
 pub mod types;
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -12,4 +16,5 @@ pub use client::BlockchainClient;
 pub use client_wasm::BlockchainClient;
 
 pub use error::BlockchainError;
+pub use shared::endpoint_url;
 pub use types::*;
@@ -0,0 +1,617 @@
+//! Path building and response parsing used by both the native and the WASM
+//! `BlockchainClient`, so the two cannot drift apart on endpoint shapes.
+
+use super::amount::Amount;
+use super::error::{BlockchainError, Result};
+use super::types::*;
+use crate::wasm::tx;
+
+/// Joins a node base URL and an `/api/...` path. The base may carry a path
+/// prefix (`https://gw.example.com/amadeus/`) and a query string, which is
+/// merged with any query on `path`; a base already ending in `/api` does not
+/// get the segment twice.
+pub fn endpoint_url(base_url: &str, path: &str) -> String {
+    let base_url = base_url.split('#').next().unwrap_or(base_url);
+    let (base, base_query) = base_url
+        .split_once('?')
+        .map_or((base_url, None), |(b, q)| (b, Some(q)));
+    let (path, path_query) = path
+        .split_once('?')
+        .map_or((path, None), |(p, q)| (p, Some(q)));
+
+    let base = base.trim_end_matches('/');
+    let path = match path.strip_prefix("/api") {
+        Some(rest) if base.ends_with("/api") && rest.starts_with('/') => rest,
+        _ => path,
+    };
+    let query: Vec<&str> = [path_query, base_query]
+        .into_iter()
+        .flatten()
+        .filter(|q| !q.is_empty())
+        .collect();
+
+    if query.is_empty() {
+        format!("{}{}", base, path)
+    } else {
+        format!("{}{}?{}", base, path, query.join("&"))
+    }
+}
+
+/// Reads `balance.flat` from a single-asset balance reply; an account that
+/// holds none of the asset reads as zero.
+pub fn parse_asset_balance(
+    address: &str,
+    symbol: &str,
+    resp: &serde_json::Value,
+) -> Result<AssetBalance> {
+    if resp.get("error").and_then(|e| e.as_str()) != Some("ok") {
+        return Err(BlockchainError::AccountNotFound {
+            address: address.to_string(),
+        });
+    }
+
+    let balance = match resp.get("balance").and_then(|b| b.get("flat")) {
+        Some(flat) if !flat.is_null() => serde_json::from_value(flat.clone()).map_err(|e| {
+            BlockchainError::InvalidResponse(format!("failed to parse balance: {}", e))
+        })?,
+        _ => Amount::ZERO,
+    };
+    Ok(AssetBalance {
+        address: address.to_string(),
+        symbol: symbol.to_string(),
+        balance,
+    })
+}
+
+/// Accepts the schedule bare or under `fee_schedule`/`fees`.
+pub fn parse_fee_schedule(resp: &serde_json::Value) -> Result<FeeSchedule> {
+    if resp
+        .get("error")
+        .and_then(|e| e.as_str())
+        .is_some_and(|e| e != "ok")
+    {
+        return Err(BlockchainError::InvalidResponse(
+            "failed to get fee schedule".to_string(),
+        ));
+    }
+
+    let mut schedule = resp
+        .get("fee_schedule")
+        .or_else(|| resp.get("fees"))
+        .unwrap_or(resp)
+        .clone();
+    if let Some(map) = schedule.as_object_mut() {
+        map.remove("error");
+    }
+    serde_json::from_value(schedule).map_err(|e| {
+        BlockchainError::InvalidResponse(format!("failed to parse fee schedule: {}", e))
+    })
+}
+
+pub fn parse_network_info(resp: &serde_json::Value) -> Result<NetworkInfo> {
+    if resp
+        .get("error")
+        .and_then(|e| e.as_str())
+        .is_some_and(|e| e != "ok")
+    {
+        return Err(BlockchainError::InvalidResponse(
+            "failed to get network info".to_string(),
+        ));
+    }
+
+    let mut info = resp
+        .get("info")
+        .or_else(|| resp.get("network"))
+        .filter(|v| v.is_object())
+        .unwrap_or(resp)
+        .clone();
+    if let Some(map) = info.as_object_mut() {
+        map.remove("error");
+        // versions and ids may come back as numbers
+        for key in [
+            "chain_id",
+            "network_id",
+            "protocol_version",
+            "consensus_version",
+            "version",
+            "node_version",
+        ] {
+            if let Some(v) = map.get_mut(key) {
+                if v.is_number() {
+                    *v = serde_json::Value::String(v.to_string());
+                }
+            }
+        }
+    }
+    serde_json::from_value(info).map_err(|e| {
+        BlockchainError::InvalidResponse(format!("failed to parse network info: {}", e))
+    })
+}
+
+/// `/api/contract/keys/{address}` is served by the node but is not part of
+/// amadeus-api.yaml, so its parameters follow the node's behaviour rather than
+/// the spec. `prefix` and `cursor` are free-form and get percent-encoded.
+pub fn contract_keys_path(query: &ContractKeysQuery) -> String {
+    let mut path = format!("/api/contract/keys/{}", query.contract_address);
+    let mut params = vec![];
+    if let Some(p) = &query.prefix {
+        params.push(format!("prefix={}", encode_query_value(p)));
+    }
+    if let Some(c) = &query.cursor {
+        params.push(format!("cursor={}", encode_query_value(c)));
+    }
+    if let Some(l) = query.limit {
+        params.push(format!("limit={}", l));
+    }
+    if !params.is_empty() {
+        path.push('?');
+        path.push_str(&params.join("&"));
+    }
+    path
+}
+
+fn encode_query_value(value: &str) -> String {
+    form_urlencoded::byte_serialize(value.as_bytes()).collect()
+}
+
+pub fn parse_contract_info(
+    contract_address: &str,
+    resp: serde_json::Value,
+) -> Result<ContractInfo> {
+    if resp
+        .get("error")
+        .and_then(|e| e.as_str())
+        .is_some_and(|e| e != "ok")
+    {
+        return Err(BlockchainError::NotAContract {
+            address: contract_address.to_string(),
+        });
+    }
+
+    let contract = match resp.get("contract") {
+        Some(c) if !c.is_null() => c.clone(),
+        _ => {
+            return Err(BlockchainError::NotAContract {
+                address: contract_address.to_string(),
+            })
+        }
+    };
+
+    let mut info: ContractInfo = serde_json::from_value(contract).map_err(|e| {
+        BlockchainError::InvalidResponse(format!("failed to parse contract info: {}", e))
+    })?;
+    info.address = contract_address.to_string();
+    Ok(info)
+}
+
+pub fn parse_supply(symbol: &str, resp: &serde_json::Value) -> Result<SupplyInfo> {
+    if resp
+        .get("error")
+        .and_then(|e| e.as_str())
+        .is_some_and(|e| e != "ok")
+    {
+        return Err(BlockchainError::AssetNotFound {
+            symbol: symbol.to_string(),
+        });
+    }
+    SupplyInfo::from_node(symbol, resp)
+        .ok_or_else(|| BlockchainError::InvalidResponse("missing supply field".to_string()))
+}
+
+/// Reads a historical balance reply. A node that keeps no state for `height`
+/// (pruned, or no snapshots at all) reports `Unsupported` rather than an
+/// unknown account.
+pub fn parse_balance_at_height(
+    address: &str,
+    height: u64,
+    resp: &serde_json::Value,
+) -> Result<BalanceAtHeight> {
+    match resp.get("error").and_then(|e| e.as_str()) {
+        Some("ok") => {}
+        Some("pruned" | "state_unavailable" | "unsupported") => {
+            return Err(BlockchainError::Unsupported {
+                feature: "historical_balance".to_string(),
+            })
+        }
+        _ => {
+            return Err(BlockchainError::AccountNotFound {
+                address: address.to_string(),
+            })
+        }
+    }
+
+    let balances = resp
+        .get("balances")
+        .ok_or_else(|| BlockchainError::InvalidResponse("missing balances field".to_string()))?;
+    Ok(BalanceAtHeight {
+        address: address.to_string(),
+        height,
+        balances: deserialize_balances(balances.clone()).map_err(|e| {
+            BlockchainError::InvalidResponse(format!("failed to parse balances: {}", e))
+        })?,
+    })
+}
+
+/// The richlist only ranks `RICHLIST_SYMBOL` holders, so other assets are
+/// rejected before any request is made.
+pub fn check_richlist_symbol(symbol: &str) -> Result<()> {
+    if symbol == RICHLIST_SYMBOL {
+        return Ok(());
+    }
+    Err(BlockchainError::Unsupported {
+        feature: format!("richlist for {}", symbol),
+    })
+}
+
+pub fn parse_top_holders(symbol: &str, resp: &serde_json::Value, limit: u32) -> Result<TopHolders> {
+    match resp.get("error").and_then(|e| e.as_str()) {
+        Some("ok") | None => {}
+        Some(other) => {
+            return Err(BlockchainError::InvalidResponse(format!(
+                "richlist lookup failed: {}",
+                other
+            )))
+        }
+    }
+    TopHolders::from_node(symbol, resp, limit)
+        .ok_or_else(|| BlockchainError::InvalidResponse("malformed richlist".to_string()))
+}
+
+pub fn parse_validator_details(
+    validator: &str,
+    resp: serde_json::Value,
+) -> Result<ValidatorDetails> {
+    let found = resp.get("error").and_then(|e| e.as_str()) == Some("ok");
+    let trainer = match resp.get("trainer") {
+        Some(t) if found && !t.is_null() => t.clone(),
+        _ => {
+            return Err(BlockchainError::AccountNotFound {
+                address: validator.to_string(),
+            })
+        }
+    };
+
+    let mut details: ValidatorDetails = serde_json::from_value(trainer).map_err(|e| {
+        BlockchainError::InvalidResponse(format!("failed to parse validator details: {}", e))
+    })?;
+    details.pk = validator.to_string();
+    Ok(details)
+}
+
+/// Contract state values come back either bare or wrapped as `{"value": ...}`;
+/// missing keys read as null or an empty string.
+pub fn state_string(value: &serde_json::Value) -> Option<String> {
+    value
+        .as_str()
+        .or_else(|| value.get("value").and_then(|v| v.as_str()))
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+}
+
+/// Turns call arguments into the raw bytes a transaction carries.
+pub fn encode_args(args: &[Argument]) -> Result<Vec<Vec<u8>>> {
+    args.iter()
+        .map(|arg| match arg {
+            Argument::String(s) => Ok(s.as_bytes().to_vec()),
+            Argument::Number(n) => Ok(n.to_string().as_bytes().to_vec()),
+            Argument::Base58 { b58 } => bs58::decode(b58)
+                .into_vec()
+                .map_err(|_| BlockchainError::ValidationFailed("invalid base58 arg".into())),
+            Argument::Hex { hex } => hex::decode(hex.trim_start_matches("0x"))
+                .map_err(|_| BlockchainError::ValidationFailed("invalid hex arg".into())),
+            Argument::Utf8 { utf8 } => Ok(utf8.as_bytes().to_vec()),
+        })
+        .collect()
+}
+
+/// JSON body for the node's view-call endpoint; arguments travel as base58
+/// of their encoded bytes, as they would inside a transaction.
+pub fn contract_call_body(req: &ContractCallRequest) -> Result<serde_json::Value> {
+    let args: Vec<String> = encode_args(&req.args)?
+        .iter()
+        .map(|a| bs58::encode(a).into_string())
+        .collect();
+    let mut body = serde_json::json!({
+        "contract": req.contract,
+        "function": req.function,
+        "args": args,
+    });
+    if let Some(caller) = &req.caller {
+        body["caller"] = serde_json::Value::String(caller.clone());
+    }
+    Ok(body)
+}
+
+/// Converts a contract state value as `decoding` asks. The node's
+/// `{"value": ...}` wrapper is removed first; a missing key (null) stays null.
+/// Byte values may arrive as a string or as an array of byte numbers.
+pub fn decode_state_value(
+    value: &serde_json::Value,
+    decoding: StateDecoding,
+) -> Result<serde_json::Value> {
+    use serde_json::Value;
+
+    let raw = value.get("value").unwrap_or(value);
+    if raw.is_null() {
+        return Ok(Value::Null);
+    }
+    let fail = |reason: &str| {
+        BlockchainError::ValidationFailed(format!(
+            "state value cannot be decoded as {:?}: {}",
+            decoding, reason
+        ))
+    };
+    let bytes = || -> Result<Vec<u8>> {
+        match raw {
+            Value::String(s) => Ok(s.as_bytes().to_vec()),
+            Value::Array(items) => items
+                .iter()
+                .map(|b| b.as_u64().and_then(|b| u8::try_from(b).ok()))
+                .collect::<Option<Vec<u8>>>()
+                .ok_or_else(|| fail("array is not a list of bytes")),
+            _ => Err(fail("expected a string or byte array")),
+        }
+    };
+
+    match decoding {
+        StateDecoding::I128 => {
+            let digits = match raw {
+                Value::Number(n) => n.to_string(),
+                _ => String::from_utf8(bytes()?).map_err(|_| fail("not UTF-8"))?,
+            };
+            digits
+                .trim()
+                .parse::<i128>()
+                .map(|n| Value::String(n.to_string()))
+                .map_err(|_| fail("not an integer"))
+        }
+        StateDecoding::String => match raw {
+            Value::Number(n) => Ok(Value::String(n.to_string())),
+            _ => String::from_utf8(bytes()?)
+                .map(Value::String)
+                .map_err(|_| fail("not UTF-8")),
+        },
+        StateDecoding::BytesHex => Ok(Value::String(hex::encode(bytes()?))),
+        StateDecoding::Json => match raw {
+            Value::String(s) => serde_json::from_str(s).map_err(|e| fail(&e.to_string())),
+            other => Ok(other.clone()),
+        },
+    }
+}
+
+/// Accepts either an `entries` list or a single `entry`; `not_found` maps to
+/// `BlockNotFound`.
+pub fn parse_block_entries(hash: &str, resp: serde_json::Value) -> Result<Vec<BlockEntry>> {
+    match resp.get("error").and_then(|e| e.as_str()) {
+        Some("ok") | None => {}
+        Some("not_found") => {
+            return Err(BlockchainError::BlockNotFound {
+                hash: hash.to_string(),
+            })
+        }
+        Some(other) => {
+            return Err(BlockchainError::InvalidResponse(format!(
+                "block lookup failed: {}",
+                other
+            )))
+        }
+    }
+
+    let entries = match (resp.get("entries"), resp.get("entry")) {
+        (Some(entries), _) => entries.clone(),
+        (None, Some(entry)) => serde_json::Value::Array(vec![entry.clone()]),
+        (None, None) => {
+            return Err(BlockchainError::InvalidResponse(
+                "missing entries field".to_string(),
+            ))
+        }
+    };
+    serde_json::from_value(entries)
+        .map_err(|e| BlockchainError::InvalidResponse(format!("failed to parse entries: {}", e)))
+}
+
+pub fn parse_entry_txs(resp: &serde_json::Value) -> Result<Vec<Transaction>> {
+    let entries = resp
+        .get("entries")
+        .and_then(|e| e.as_array())
+        .ok_or_else(|| BlockchainError::InvalidResponse("missing entries field".to_string()))?;
+
+    let mut txs = Vec::new();
+    for entry in entries {
+        if let Some(entry_txs) = entry.get("txs") {
+            let parsed: Vec<Transaction> =
+                serde_json::from_value(entry_txs.clone()).map_err(|e| {
+                    BlockchainError::InvalidResponse(format!("failed to parse entry txs: {}", e))
+                })?;
+            txs.extend(parsed);
+        }
+    }
+    Ok(txs)
+}
+
+pub fn build_transfer_locally(
+    req: &TransferRequest,
+    symbol: &str,
+) -> Result<UnsignedTransactionBlob> {
+    let Some(amount) = req.amount else {
+        return Err(BlockchainError::ValidationFailed(
+            "offline building needs amount in atoms; amount_display requires the node".into(),
+        ));
+    };
+    let address = |field: &str, value: &str| {
+        tx::Address::from_b58(value)
+            .map_err(|e| BlockchainError::ValidationFailed(format!("{}: {}", field, e)))
+    };
+
+    let unsigned = tx::build_unsigned_transfer(
+        address("signer", &req.signer)?.as_bytes(),
+        &address("receiver", &req.receiver)?,
+        symbol,
+        amount,
+        req.memo.as_deref(),
+        req.nonce,
+    )
+    .map_err(|e| BlockchainError::ValidationFailed(e.into()))?;
+
+    Ok(UnsignedTransactionBlob {
+        blob: bs58::encode(&unsigned.tx_blob).into_string(),
+        signing_payload: hex::encode(unsigned.signing_hash),
+        transaction_hash: bs58::encode(unsigned.signing_hash).into_string(),
+        tx_bytes: unsigned.tx_blob,
+    })
+}
+
+/// Decodes a transaction blob, which is always base58 of a vecpak-encoded
+/// `Tx` or `TxU`. A blob that is not, e.g. one re-encoded as hex or base64 on
+/// the way to a signer, is rejected naming the encoding it appears to use.
+pub fn decode_blob(blob_b58: &str) -> Result<(Vec<u8>, tx::DecodedTx)> {
+    bs58::decode(blob_b58)
+        .into_vec()
+        .ok()
+        .and_then(|bytes| tx::decode(&bytes).ok().map(|decoded| (bytes, decoded)))
+        .ok_or_else(|| {
+            BlockchainError::ValidationFailed(format!(
+                "transaction blob is not a base58-encoded transaction{}",
+                blob_encoding_hint(blob_b58)
+            ))
+        })
+}
+
+fn blob_encoding_hint(blob: &str) -> &'static str {
+    let hex = blob.strip_prefix("0x").unwrap_or(blob);
+    if !hex.is_empty() && hex.len() % 2 == 0 && hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return " (it looks like hex)";
+    }
+    let base64_only = |b: u8| b"+/=-_0OIl".contains(&b);
+    if blob.bytes().any(base64_only)
+        && blob
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || base64_only(b))
+    {
+        return " (it looks like base64)";
+    }
+    ""
+}
+
+/// Decodes a blob, rejecting a signed one where an unsigned one is expected or
+/// the reverse, and pointing at the submit tool that takes it.
+pub fn expect_blob_signed(blob_b58: &str, signed: bool) -> Result<(Vec<u8>, tx::DecodedTx)> {
+    let (blob, decoded) = decode_blob(blob_b58)?;
+    match (decoded.signature.is_some(), signed) {
+        (true, false) => Err(BlockchainError::ValidationFailed(
+            "transaction is already signed; submit it with submit_raw_transaction".into(),
+        )),
+        (false, true) => Err(BlockchainError::ValidationFailed(
+            "transaction is unsigned; submit it with submit_transaction and its signature".into(),
+        )),
+        _ => Ok((blob, decoded)),
+    }
+}
+
+/// Checks that `tx.signature` is the blob signer's signature over the blob's
+/// signing payload, so a signature over the wrong bytes or under the wrong DST
+/// fails here instead of at the node.
+pub fn check_transaction_signature(
+    tx: &SignedTransaction,
+    params: &tx::SigningParams,
+) -> Result<()> {
+    let (blob, decoded) = expect_blob_signed(&tx.transaction, false)?;
+    let signature = bs58::decode(&tx.signature)
+        .into_vec()
+        .map_err(|_| BlockchainError::ValidationFailed("invalid signature base58".into()))?;
+    let payload = tx::SigningPayload::from_blob(&blob, params)
+        .map_err(|e| BlockchainError::ValidationFailed(e.into()))?;
+
+    match payload.verify(&decoded.tx.signer, &signature) {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(BlockchainError::ValidationFailed(format!(
+            "signature does not verify for signer {} over signing payload {}",
+            bs58::encode(&decoded.tx.signer).into_string(),
+            payload.to_hex()
+        ))),
+        Err(e) => Err(BlockchainError::ValidationFailed(e.into())),
+    }
+}
+
+pub fn transaction_hash(blob_b58: &str) -> Result<TransactionHash> {
+    let (blob, decoded) = decode_blob(blob_b58)?;
+    let hash = tx::compute_transaction_hash(&blob)
+        .map_err(|e| BlockchainError::ValidationFailed(e.into()))?;
+
+    Ok(TransactionHash {
+        hash: bs58::encode(hash).into_string(),
+        hash_hex: hex::encode(hash),
+        signed: decoded.signature.is_some(),
+    })
+}
+
+/// Checks a raw blob is a base58 signed envelope and returns the hash it carries.
+pub fn raw_transaction_hash(blob_b58: &str) -> Result<Option<String>> {
+    let (_, decoded) = expect_blob_signed(blob_b58, true)?;
+    Ok(decoded.hash.map(|h| bs58::encode(h).into_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys_query(prefix: Option<&str>, cursor: Option<&str>) -> ContractKeysQuery {
+        ContractKeysQuery {
+            contract_address: "Coin".to_string(),
+            prefix: prefix.map(str::to_string),
+            cursor: cursor.map(str::to_string),
+            limit: Some(10),
+            network: None,
+        }
+    }
+
+    #[test]
+    fn contract_keys_path_encodes_prefix_and_cursor() {
+        let path = contract_keys_path(&keys_query(Some("bal:a&b=c"), Some("x y/z?")));
+        assert_eq!(
+            path,
+            "/api/contract/keys/Coin?prefix=bal%3Aa%26b%3Dc&cursor=x+y%2Fz%3F&limit=10"
+        );
+    }
+
+    #[test]
+    fn contract_keys_path_without_params() {
+        let path = contract_keys_path(&keys_query(None, None));
+        assert_eq!(path, "/api/contract/keys/Coin?limit=10");
+    }
+
+    #[test]
+    fn endpoint_url_keeps_base_path_prefix() {
+        assert_eq!(
+            endpoint_url("https://gw.example.com/amadeus/", "/api/chain/stats"),
+            "https://gw.example.com/amadeus/api/chain/stats"
+        );
+        assert_eq!(
+            endpoint_url("https://gw.example.com/amadeus/api", "/api/chain/stats"),
+            "https://gw.example.com/amadeus/api/chain/stats"
+        );
+        assert_eq!(
+            endpoint_url("https://node.example.com", "/api/chain/stats"),
+            "https://node.example.com/api/chain/stats"
+        );
+    }
+
+    #[test]
+    fn endpoint_url_merges_queries() {
+        assert_eq!(
+            endpoint_url("https://gw.example.com/amadeus?key=k1", "/api/chain/stats"),
+            "https://gw.example.com/amadeus/api/chain/stats?key=k1"
+        );
+        assert_eq!(
+            endpoint_url(
+                "https://gw.example.com/amadeus/?key=k1#frag",
+                "/api/contract/keys/Coin?limit=10"
+            ),
+            "https://gw.example.com/amadeus/api/contract/keys/Coin?limit=10&key=k1"
+        );
+        assert_eq!(
+            endpoint_url("https://node.example.com/?", "/api/chain/stats?"),
+            "https://node.example.com/api/chain/stats"
+        );
+    }
+}
@@ -1,9 +1,16 @@
+use super::amount::Amount;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use validator::Validate;
+use validator::{Validate, ValidationError, ValidationErrors, ValidationErrorsKind};
 
+const MAX_SYMBOL_LEN: usize = 32;
+
+/// Transaction blobs have one encoding end to end: base58 of the vecpak-encoded
+/// transaction. Every create tool builds it locally, and every submit tool
+/// rejects anything else before contacting the node.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UnsignedTransactionBlob {
+    /// Unsigned transaction (base58)
     pub blob: String,
     pub signing_payload: String,
     pub transaction_hash: String,
@@ -11,110 +18,1582 @@ pub struct UnsignedTransactionBlob {
     pub tx_bytes: Vec<u8>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthStatus {
+    pub reachable: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<u64>,
+    pub latency_ms: u64,
+}
+
+/// Answer to `ping`: built from configuration only, without a node request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerInfo {
+    pub version: String,
+    pub mainnet_host: String,
+    pub testnet_host: String,
+    /// Result of the most recent node health check, if one has run
+    pub last_health_check: Option<HealthStatus>,
+}
+
+impl ServerInfo {
+    pub fn new(
+        mainnet_url: &str,
+        testnet_url: &str,
+        last_health_check: Option<HealthStatus>,
+    ) -> Self {
+        Self {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            mainnet_host: url_host(mainnet_url),
+            testnet_host: url_host(testnet_url),
+            last_health_check,
+        }
+    }
+}
+
+/// Call counts for one tool since the server started, as returned by `get_metrics`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolMetrics {
+    pub tool: String,
+    pub invocations: u64,
+    pub successes: u64,
+    /// Calls that returned an MCP error or an error result
+    pub failures: u64,
+    pub total_latency_ms: u64,
+    pub avg_latency_ms: u64,
+}
+
+/// Host and port of a URL, dropping the scheme, any credentials, and the path.
+fn url_host(url: &str) -> String {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or(rest);
+    authority
+        .rsplit_once('@')
+        .map_or(authority, |(_, host)| host)
+        .to_string()
+}
+
+/// The node's `error` field; an empty 2xx body (read as `null`) means "ok".
+fn node_error(response: &serde_json::Value) -> &str {
+    if response.is_null() {
+        return "ok";
+    }
+    response
+        .get("error")
+        .and_then(|e| e.as_str())
+        .unwrap_or("unknown")
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct SignedTransaction {
+    /// Unsigned transaction blob exactly as returned by a create tool (base58)
+    #[validate(length(min = 1))]
+    pub transaction: String,
+    /// BLS12-381 signature over the signing payload (base58)
+    #[validate(length(min = 1))]
+    pub signature: String,
+    /// Verify the signature against the transaction's signer and signing
+    /// payload before broadcasting, rejecting a mismatch
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub check_signature: Option<bool>,
+    /// Simulate against the node instead of broadcasting
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+/// Outcome of simulating a signed transaction; nothing is committed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulationResult {
+    pub tx_hash: String,
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fee: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_changes: Option<serde_json::Value>,
+}
+
+impl SimulationResult {
+    pub fn from_node(response: &serde_json::Value, tx_hash: String) -> Self {
+        let error = node_error(response);
+        let fee = response.get("fee").and_then(|f| match f {
+            serde_json::Value::String(s) => Some(s.clone()),
+            serde_json::Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        });
+
+        Self {
+            tx_hash,
+            success: error == "ok",
+            error: (error != "ok").then(|| error.to_string()),
+            fee,
+            state_changes: response
+                .get("state_changes")
+                .or_else(|| response.get("muts"))
+                .cloned(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct ContractCallRequest {
+    /// Contract name or address
+    #[validate(length(min = 1))]
+    pub contract: String,
+    /// View function to execute
+    #[validate(length(min = 1))]
+    pub function: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<Argument>,
+    /// Account the call runs as (base58), for functions that read the caller
+    #[validate(custom(function = "validate_address"))]
+    #[serde(
+        default,
+        deserialize_with = "normalized_opt_address",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub caller: Option<String>,
+    /// Convert the return value; omit to get it as the node returned it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decode_as: Option<StateDecoding>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+/// Outcome of executing a view function; nothing is committed. A revert is a
+/// normal result with `success: false` and the node's reason under `error`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractCallResult {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exec_used: Option<u64>,
+}
+
+impl ContractCallResult {
+    pub fn from_node(response: &serde_json::Value) -> Self {
+        let error = node_error(response);
+        let reason = response
+            .get("reason")
+            .and_then(|r| r.as_str())
+            .filter(|r| !r.is_empty());
+
+        Self {
+            success: error == "ok",
+            error: (error != "ok").then(|| reason.unwrap_or(error).to_string()),
+            result: response
+                .get("result")
+                .or_else(|| response.get("return_value"))
+                .filter(|r| !r.is_null())
+                .cloned(),
+            exec_used: response.get("exec_used").and_then(|e| e.as_u64()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct RawTransaction {
+    /// Fully signed and packed transaction envelope (base58)
+    #[validate(length(min = 1))]
+    pub transaction: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct VerifySignatureRequest {
+    /// Signer public key (base58)
+    #[validate(custom(function = "validate_address"))]
+    #[serde(deserialize_with = "normalized_address")]
+    pub public_key: String,
+    /// Signing payload as returned by create_transaction (hex)
+    #[validate(length(min = 1))]
+    pub signing_payload: String,
+    /// BLS12-381 signature (base58)
+    #[validate(length(min = 1))]
+    pub signature: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct DecodeTransactionRequest {
+    /// Unsigned or signed transaction blob (base58)
+    #[validate(length(min = 1))]
+    pub transaction: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecodedTransaction {
+    pub signer: String,
+    pub nonce: i128,
+    pub op: String,
+    pub contract: String,
+    pub function: String,
+    pub args: Vec<DecodedArgument>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attached_symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attached_amount: Option<String>,
+    /// Present only for signed transactions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+}
+
+/// Offline integrity check of a signed transaction envelope.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionBlobReport {
+    pub signer: String,
+    /// Hash stored in the envelope (base58)
+    pub hash: String,
+    /// Sha256 of the encoded inner transaction (base58)
+    pub computed_hash: String,
+    pub hash_matches: bool,
+    /// Signature verifies over the computed hash for the embedded signer
+    pub signature_valid: bool,
+}
+
+/// Id of a transaction as the node reports it: the full SHA-256 of the
+/// encoded inner transaction, whether given the unsigned blob or the signed envelope.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionHash {
+    /// Base58, the form used by `get_transaction`
+    pub hash: String,
+    pub hash_hex: String,
+    /// Whether the blob was a signed envelope
+    pub signed: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecodedArgument {
+    pub hex: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub utf8: Option<String>,
+}
+
+impl DecodedArgument {
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self {
+            hex: hex::encode(bytes),
+            utf8: std::str::from_utf8(bytes).ok().map(str::to_string),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubmitResponse {
+    pub error: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tx_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<u64>,
+    /// Set when this is the remembered result of an earlier submission of the
+    /// same transaction, which was not broadcast again
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub duplicate: bool,
+}
+
+impl SubmitResponse {
+    /// Reads the node's `{"error": ...}` reply. The hash is reported only on
+    /// success, preferring the node's own over the locally computed one.
+    pub fn from_node(response: &serde_json::Value, local_hash: Option<String>) -> Self {
+        let error = node_error(response).to_string();
+        let accepted = error == "ok";
+        let node_hash = response
+            .get("hash")
+            .or_else(|| response.get("tx_hash"))
+            .and_then(|h| h.as_str())
+            .map(str::to_string);
+
+        Self {
+            tx_hash: accepted.then(|| node_hash.or(local_hash)).flatten(),
+            height: response
+                .get("height")
+                .or_else(|| response.get("entry_height"))
+                .and_then(|h| h.as_u64()),
+            error,
+            duplicate: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct AccountQuery {
+    #[validate(custom(function = "validate_address"))]
+    #[serde(deserialize_with = "normalized_address")]
+    pub address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct BalanceAtHeightQuery {
+    #[validate(custom(function = "validate_address"))]
+    #[serde(deserialize_with = "normalized_address")]
+    pub address: String,
+    /// Block height to read balances at; must not be above the chain tip
+    pub height: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+/// An account's balances as of the end of block `height`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BalanceAtHeight {
+    pub address: String,
+    pub height: u64,
+    pub balances: Vec<Balance>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct BalancesQuery {
+    /// Accounts to look up (base58), at most 50
+    #[validate(length(min = 1, max = 50), custom(function = "validate_addresses"))]
+    #[serde(deserialize_with = "normalized_addresses")]
+    pub addresses: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct AccountAssetQuery {
+    #[validate(custom(function = "validate_address"))]
+    #[serde(deserialize_with = "normalized_address")]
+    pub address: String,
+    #[validate(custom(function = "validate_symbol"))]
+    pub symbol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+/// Balance of a single asset; zero when the account holds none of it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetBalance {
+    pub address: String,
+    pub symbol: String,
+    /// Balance in smallest unit (atoms)
+    pub balance: Amount,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountBalance {
+    pub address: String,
+    #[serde(deserialize_with = "deserialize_balances")]
+    pub balances: Vec<Balance>,
+}
+
+/// Per-account outcome of a multi-account balance lookup.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BalanceLookup {
+    Balances(Vec<Balance>),
+    Error(String),
+}
+
+/// Balances of several accounts keyed by address. A lookup that fails is
+/// recorded as an error for that address only.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountBalances {
+    pub accounts: std::collections::BTreeMap<String, BalanceLookup>,
+}
+
+impl AccountBalances {
+    pub fn from_results<E: std::fmt::Display>(
+        results: impl IntoIterator<Item = (String, Result<AccountBalance, E>)>,
+    ) -> Self {
+        let accounts = results
+            .into_iter()
+            .map(|(address, result)| {
+                let lookup = match result {
+                    Ok(b) => BalanceLookup::Balances(b.balances),
+                    Err(e) => BalanceLookup::Error(e.to_string()),
+                };
+                (address, lookup)
+            })
+            .collect();
+        Self { accounts }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct AccountSummaryQuery {
+    #[validate(custom(function = "validate_address"))]
+    #[serde(deserialize_with = "normalized_address")]
+    pub address: String,
+    /// Number of recent transactions to include (default 10)
+    #[validate(range(min = 1, max = 100))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recent: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+/// Balance, nonce, and recent history fetched together. A part that could not
+/// be fetched is left out and its error recorded under `errors`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountSummary {
+    pub address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub balances: Option<Vec<Balance>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recent_transactions: Option<Vec<Transaction>>,
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub errors: std::collections::BTreeMap<String, String>,
+}
+
+impl AccountSummary {
+    pub fn from_parts<E: std::fmt::Display>(
+        address: &str,
+        balance: Result<AccountBalance, E>,
+        nonce: Result<u64, E>,
+        history: Result<TransactionHistoryPage, E>,
+    ) -> Self {
+        let mut errors = std::collections::BTreeMap::new();
+        let mut record = |field: &str, e: E| {
+            errors.insert(field.to_string(), e.to_string());
+        };
+
+        let balances = balance
+            .map(|b| b.balances)
+            .map_err(|e| record("balances", e))
+            .ok();
+        let nonce = nonce.map_err(|e| record("nonce", e)).ok();
+        let recent_transactions = history
+            .map(|p| p.transactions)
+            .map_err(|e| record("recent_transactions", e))
+            .ok();
+
+        Self {
+            address: address.to_string(),
+            balances,
+            nonce,
+            recent_transactions,
+            errors,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Balance {
+    pub symbol: String,
+    /// Balance in smallest unit (atoms)
+    #[serde(alias = "amount")]
+    pub flat: Amount,
+    /// Human-readable balance, when the node reports one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub float: Option<f64>,
+}
+
+/// Shapes nodes use for the `balances` field: older nodes send a list of
+/// `{symbol, flat, float}` (or `{symbol, amount}`) entries, newer ones an
+/// object keyed by symbol whose values are either atoms or `{flat, float}`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BalancesWire {
+    List(Vec<Balance>),
+    BySymbol(std::collections::BTreeMap<String, BalanceValue>),
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BalanceValue {
+    Detailed {
+        #[serde(alias = "amount")]
+        flat: Amount,
+        #[serde(default)]
+        float: Option<f64>,
+    },
+    Atoms(Amount),
+}
+
+/// Reads a node's `balances` field in any of the shapes in [`BalancesWire`]
+/// and normalizes it to a list ordered as the node sent it (by symbol for the
+/// keyed shape).
+pub(crate) fn deserialize_balances<'de, D>(de: D) -> Result<Vec<Balance>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let balances = match BalancesWire::deserialize(de).map_err(|_| {
+        serde::de::Error::custom(
+            "balances must be a list of {symbol, flat} entries or an object keyed by symbol",
+        )
+    })? {
+        BalancesWire::List(list) => list,
+        BalancesWire::BySymbol(by_symbol) => by_symbol
+            .into_iter()
+            .map(|(symbol, value)| match value {
+                BalanceValue::Detailed { flat, float } => Balance {
+                    symbol,
+                    flat,
+                    float,
+                },
+                BalanceValue::Atoms(flat) => Balance {
+                    symbol,
+                    flat,
+                    float: None,
+                },
+            })
+            .collect(),
+    };
+    Ok(balances)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct ListAssetsQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct AssetQuery {
+    #[validate(custom(function = "validate_symbol"))]
+    pub symbol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetInfo {
+    pub symbol: String,
+    /// Number of decimal places between atoms and whole units
+    pub decimals: u8,
+    /// Total supply in smallest unit (atoms)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_supply: Option<u64>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Supply of one asset in atoms. `burned` and `locked` appear only when the
+/// node reports them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SupplyInfo {
+    pub symbol: String,
+    pub total_supply: Amount,
+    pub circulating_supply: Amount,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub burned: Option<Amount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locked: Option<Amount>,
+}
+
+impl SupplyInfo {
+    /// Reads the node's supply object, bare or under `supply`. Without an
+    /// explicit circulating figure, burned and locked coins are subtracted
+    /// from the total.
+    pub fn from_node(symbol: &str, response: &serde_json::Value) -> Option<Self> {
+        let supply = response.get("supply").unwrap_or(response);
+        let field = |names: &[&str]| {
+            names
+                .iter()
+                .find_map(|n| supply.get(*n))
+                .and_then(|v| serde_json::from_value::<Amount>(v.clone()).ok())
+        };
+
+        let total_supply = field(&["total_supply", "total"])?;
+        let burned = field(&["burned"]);
+        let locked = field(&["locked"]);
+        let circulating_supply = match field(&["circulating_supply", "circulating"]) {
+            Some(circulating) => circulating,
+            None => total_supply
+                .checked_sub(burned.unwrap_or_default())?
+                .checked_sub(locked.unwrap_or_default())?,
+        };
+
+        Some(Self {
+            symbol: symbol.to_string(),
+            total_supply,
+            circulating_supply,
+            burned,
+            locked,
+        })
+    }
+}
+
+/// Holders returned when `get_top_holders` is called without a limit.
+pub const DEFAULT_TOP_HOLDERS: u32 = 20;
+
+/// The node's richlist takes no symbol and ranks holders of this asset.
+pub const RICHLIST_SYMBOL: &str = "AMA";
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct TopHoldersQuery {
+    #[validate(custom(function = "validate_symbol"))]
+    pub symbol: String,
+    /// Number of holders to return, 1-100 (default 20)
+    #[validate(range(min = 1, max = 100))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+/// One holder of an asset, ranked from 1 by balance in atoms.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HolderEntry {
+    pub rank: u32,
+    pub address: String,
+    pub balance: Amount,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopHolders {
+    pub symbol: String,
+    pub holders: Vec<HolderEntry>,
+}
+
+impl TopHolders {
+    /// Reads `/api/contract/richlist`'s `richlist` of `{address, balance, rank}`
+    /// entries. The node's list is unbounded and its ranks are not relied on:
+    /// entries are re-ranked by balance, largest first, and cut to `limit`.
+    pub fn from_node(symbol: &str, response: &serde_json::Value, limit: u32) -> Option<Self> {
+        let list = response.get("richlist")?.as_array()?;
+
+        let mut holders = list
+            .iter()
+            .map(|entry| {
+                Some(HolderEntry {
+                    rank: 0,
+                    address: entry.get("address")?.as_str()?.to_string(),
+                    balance: serde_json::from_value(entry.get("balance")?.clone()).ok()?,
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        holders.sort_by(|a, b| b.balance.cmp(&a.balance));
+        holders.truncate(limit as usize);
+        for (i, holder) in holders.iter_mut().enumerate() {
+            holder.rank = i as u32 + 1;
+        }
+
+        Some(Self {
+            symbol: symbol.to_string(),
+            holders,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct HeightQuery {
+    pub height: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct BlockHashQuery {
+    /// Entry hash, base58 or 64 hex characters
+    #[validate(custom(function = "validate_block_hash"))]
+    pub hash: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+/// Normalizes a 32-byte block hash to the base58 form nodes use, accepting
+/// 64 hex characters (optionally `0x`-prefixed) as well.
+pub fn block_hash_b58(hash: &str) -> Option<String> {
+    let hex_part = hash.trim_start_matches("0x");
+    if hex_part.len() == 64 {
+        if let Ok(bytes) = hex::decode(hex_part) {
+            return Some(bs58::encode(bytes).into_string());
+        }
+    }
+    match bs58::decode(hash).into_vec() {
+        Ok(bytes) if bytes.len() == 32 => Some(hash.to_string()),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct TransactionQuery {
+    #[validate(length(min = 1))]
+    pub tx_hash: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct TransactionPositionQuery {
+    pub height: u64,
+    /// Zero-based position among the transactions at `height`, in entry order
+    pub index: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+/// A transaction addressed by where it sits rather than by hash.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionAtPosition {
+    pub height: u64,
+    pub index: u32,
+    /// Transactions at `height`, so a caller walking blocks knows when to move on
+    pub tx_count: u32,
+    pub transaction: Transaction,
+}
+
+impl TransactionAtPosition {
+    /// Picks the `index`th of a height's transactions, failing with
+    /// `ValidationFailed` when the height holds fewer.
+    pub fn select(
+        height: u64,
+        index: u32,
+        transactions: Vec<Transaction>,
+    ) -> Result<Self, super::error::BlockchainError> {
+        let tx_count = transactions.len() as u32;
+        let transaction = transactions
+            .into_iter()
+            .nth(index as usize)
+            .ok_or_else(|| {
+                super::error::BlockchainError::ValidationFailed(format!(
+                    "index {} is out of range: height {} has {} transactions",
+                    index, height, tx_count
+                ))
+            })?;
+        Ok(Self {
+            height,
+            index,
+            tx_count,
+            transaction,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct WaitForTransactionQuery {
+    #[validate(length(min = 1))]
+    pub tx_hash: String,
+    /// Give up after this many seconds (default 60, at most 120)
+    #[validate(range(min = 1, max = 120))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+/// Poll schedule for `wait_for_transaction`: the first wait is `initial`, each
+/// later one is `factor` times the previous, and none is longer than `max`.
+#[derive(Debug, Clone, Copy)]
+pub struct PollBackoff {
+    pub initial: std::time::Duration,
+    pub factor: f64,
+    pub max: std::time::Duration,
+}
+
+impl Default for PollBackoff {
+    fn default() -> Self {
+        Self {
+            initial: std::time::Duration::from_millis(500),
+            factor: 2.0,
+            max: std::time::Duration::from_secs(5),
+        }
+    }
+}
+
+impl PollBackoff {
+    /// Applies the settings that are given, e.g. from environment variables.
+    /// A factor below 1 would shrink the interval and is ignored.
+    pub fn with_overrides(
+        mut self,
+        initial_ms: Option<u64>,
+        factor: Option<f64>,
+        max_ms: Option<u64>,
+    ) -> Self {
+        if let Some(ms) = initial_ms {
+            self.initial = std::time::Duration::from_millis(ms);
+        }
+        if let Some(factor) = factor.filter(|f| *f >= 1.0) {
+            self.factor = factor;
+        }
+        if let Some(ms) = max_ms {
+            self.max = std::time::Duration::from_millis(ms);
+        }
+        self
+    }
+
+    /// Interval to wait after one of `current`.
+    pub fn next(&self, current: std::time::Duration) -> std::time::Duration {
+        current.mul_f64(self.factor).min(self.max)
+    }
+}
+
+/// Final answer of `wait_for_transaction`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionWait {
+    pub tx_hash: String,
+    pub status: TransactionStatus,
+    pub polls: u32,
+    pub waited_ms: u64,
+    /// True when the timeout passed while the transaction was still pending
+    pub timed_out: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct WaitForBlockQuery {
+    /// Return once the chain tip is above this height
+    pub after_height: u64,
+    /// Give up after this many seconds (default 60, at most 120)
+    #[validate(range(min = 1, max = 120))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+/// Final answer of `wait_for_next_block`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockWait {
+    pub after_height: u64,
+    /// Tip height at the last poll
+    pub height: u64,
+    pub polls: u32,
+    pub waited_ms: u64,
+    /// True when the timeout passed before the tip moved above `after_height`
+    pub timed_out: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct TransactionHistoryQuery {
+    #[validate(custom(function = "validate_address"))]
+    #[serde(deserialize_with = "normalized_address")]
+    pub address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+    /// Follow pages until exhausted, using `limit` as the overall cap
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fetch_all: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct ContractStateQuery {
+    #[validate(length(min = 1))]
+    pub contract_address: String,
+    #[validate(length(min = 1))]
+    pub key: String,
+    /// Convert the raw value; omit to get it exactly as the node returned it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decode_as: Option<StateDecoding>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+/// Representation a contract state value is converted to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StateDecoding {
+    /// Integer from a number or a decimal string, returned as a string
+    I128,
+    /// UTF-8 text
+    String,
+    /// Raw bytes as lowercase hex
+    BytesHex,
+    /// A string holding JSON, parsed
+    Json,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct ContractStatesQuery {
+    #[validate(length(min = 1))]
+    pub contract_address: String,
+    /// Storage keys to read, at most 50
+    #[validate(length(min = 1, max = 50), custom(function = "validate_state_keys"))]
+    pub keys: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+/// Per-key outcome of a multi-key contract state read.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StateLookup {
+    Value(serde_json::Value),
+    Error(String),
+}
+
+/// Storage values of one contract keyed by storage key. A read that fails is
+/// recorded as an error for that key only.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractStates {
+    pub contract_address: String,
+    pub values: std::collections::BTreeMap<String, StateLookup>,
+}
+
+impl ContractStates {
+    pub fn from_results<E: std::fmt::Display>(
+        contract_address: &str,
+        results: impl IntoIterator<Item = (String, Result<serde_json::Value, E>)>,
+    ) -> Self {
+        let values = results
+            .into_iter()
+            .map(|(key, result)| {
+                let lookup = match result {
+                    Ok(value) => StateLookup::Value(value),
+                    Err(e) => StateLookup::Error(e.to_string()),
+                };
+                (key, lookup)
+            })
+            .collect();
+        Self {
+            contract_address: contract_address.to_string(),
+            values,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct ResolveNameQuery {
+    /// Human-readable account name such as "alice.ama"
+    #[validate(length(min = 1, max = 64))]
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct ReverseResolveQuery {
+    /// Account address (base58)
+    #[validate(custom(function = "validate_address"))]
+    #[serde(deserialize_with = "normalized_address")]
+    pub address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct ContractCodeQuery {
+    #[validate(length(min = 1))]
+    pub contract_address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+/// Deployed contract metadata. Nodes may expose the full bytecode, only its
+/// hash, or both; `functions` is present only when the node reports an ABI.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractInfo {
+    #[serde(default)]
+    pub address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub functions: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct ContractKeysQuery {
+    #[validate(length(min = 1))]
+    pub contract_address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractKeys {
+    pub contract_address: String,
+    pub keys: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct TransactionRequest {
+    #[validate(custom(function = "validate_address"))]
+    #[serde(deserialize_with = "normalized_address")]
+    pub signer: String,
+    #[validate(length(min = 1))]
+    pub contract: String,
+    #[validate(length(min = 1))]
+    pub function: String,
+    pub args: Vec<Argument>,
+    #[validate(custom(function = "validate_symbol"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attached_symbol: Option<String>,
+    /// Attached amount in atoms, as an integer string
+    #[validate(custom(function = "validate_atoms"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attached_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<i128>,
+    /// Check the signer holds enough of the spent asset before building (default false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preflight_balance_check: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+impl TransactionRequest {
+    /// Asset symbol and amount (atoms) this transaction spends from the signer, if known:
+    /// the attached payment, or the amount of a `Coin.transfer`.
+    pub fn spend(&self) -> Option<(String, i128)> {
+        if let (Some(symbol), Some(amount)) = (&self.attached_symbol, &self.attached_amount) {
+            return Some((symbol.clone(), amount.parse().ok()?));
+        }
+
+        if self.contract != "Coin" || self.function != "transfer" {
+            return None;
+        }
+        let amount = match self.args.get(1)? {
+            Argument::String(s) | Argument::Utf8 { utf8: s } => s.parse().ok()?,
+            Argument::Number(n) => *n as i128,
+            _ => return None,
+        };
+        let symbol = match self.args.get(2)? {
+            Argument::String(s) | Argument::Utf8 { utf8: s } => s.clone(),
+            _ => return None,
+        };
+        Some((symbol, amount))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+#[validate(schema(function = "validate_transfer"))]
+pub struct TransferRequest {
+    #[validate(custom(function = "validate_address"))]
+    #[serde(deserialize_with = "normalized_address")]
+    pub signer: String,
+    /// Recipient address (base58)
+    #[validate(custom(function = "validate_address"))]
+    #[serde(deserialize_with = "normalized_address")]
+    pub receiver: String,
+    /// Asset to send; may be omitted when the server has a default asset
+    #[validate(custom(function = "validate_symbol"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    /// Amount in smallest unit (atoms); exclusive with `amount_display`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<Amount>,
+    /// Amount in whole units such as "1.5", converted using the asset's decimals
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_display: Option<String>,
+    /// Free-form note attached to the transfer (UTF-8, at most 256 bytes)
+    #[validate(custom(function = "validate_memo"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<i128>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preflight_balance_check: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+impl TransferRequest {
+    /// The asset to send: `symbol`, else the deployment's default asset.
+    /// Fails as a `symbol` field error when neither is set or the default is
+    /// not a valid symbol.
+    pub fn resolve_symbol(&self, default: Option<&str>) -> Result<String, ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        match self.symbol.as_deref().or(default) {
+            Some(symbol) => match validate_symbol(symbol) {
+                Ok(()) => return Ok(symbol.to_string()),
+                Err(e) => errors.add("symbol", e),
+            },
+            None => errors.add("symbol", ValidationError::new("required")),
+        }
+        Err(errors)
+    }
+
+    pub fn into_transaction_request(self, symbol: &str, amount: Amount) -> TransactionRequest {
+        TransactionRequest {
+            signer: self.signer,
+            contract: "Coin".to_string(),
+            function: "transfer".to_string(),
+            args: [
+                Argument::Base58 { b58: self.receiver },
+                Argument::String(amount.to_string()),
+                Argument::String(symbol.to_string()),
+            ]
+            .into_iter()
+            .chain(self.memo.map(|utf8| Argument::Utf8 { utf8 }))
+            .collect(),
+            attached_symbol: None,
+            attached_amount: None,
+            nonce: self.nonce,
+            preflight_balance_check: self.preflight_balance_check,
+            network: self.network,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct SignAndSubmitTransferRequest {
+    /// Recipient address (base58)
+    #[validate(custom(function = "validate_address"))]
+    #[serde(deserialize_with = "normalized_address")]
+    pub receiver: String,
+    #[validate(custom(function = "validate_symbol"))]
+    pub symbol: String,
+    /// Amount in smallest unit (atoms)
+    #[validate(custom(function = "validate_positive_amount"))]
+    pub amount: Amount,
+    /// Free-form note attached to the transfer (UTF-8, at most 256 bytes)
+    #[validate(custom(function = "validate_memo"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct MintRequest {
+    #[validate(custom(function = "validate_address"))]
+    #[serde(deserialize_with = "normalized_address")]
+    pub signer: String,
+    #[validate(custom(function = "validate_symbol"))]
+    pub symbol: String,
+    /// Amount to mint in smallest unit (atoms)
+    #[validate(custom(function = "validate_positive_amount"))]
+    pub amount: Amount,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<i128>,
+}
+
+impl From<MintRequest> for TransactionRequest {
+    fn from(req: MintRequest) -> Self {
+        Self {
+            signer: req.signer,
+            contract: "Coin".to_string(),
+            function: "mint".to_string(),
+            args: vec![
+                Argument::String(req.symbol),
+                Argument::String(req.amount.to_string()),
+            ],
+            attached_symbol: None,
+            attached_amount: None,
+            nonce: req.nonce,
+            preflight_balance_check: Some(false),
+            network: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct DeployContractRequest {
+    #[validate(custom(function = "validate_address"))]
+    #[serde(deserialize_with = "normalized_address")]
+    pub signer: String,
+    /// Compiled WASM contract bytecode (hex)
+    #[validate(custom(function = "validate_code_hex"))]
+    pub code_hex: String,
+    /// Constructor arguments, passed to `deploy` after the code
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<Argument>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<i128>,
+}
+
+impl From<DeployContractRequest> for TransactionRequest {
+    fn from(req: DeployContractRequest) -> Self {
+        Self {
+            signer: req.signer,
+            contract: crate::wasm::tx::DEPLOY_CONTRACT.to_string(),
+            function: crate::wasm::tx::DEPLOY_FUNCTION.to_string(),
+            args: std::iter::once(Argument::Hex { hex: req.code_hex })
+                .chain(req.args)
+                .collect(),
+            attached_symbol: None,
+            attached_amount: None,
+            nonce: req.nonce,
+            preflight_balance_check: Some(false),
+            network: None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
-pub struct SignedTransaction {
-    #[validate(length(min = 1))]
-    pub transaction: String,
-    #[validate(length(min = 1))]
-    pub signature: String,
+pub struct StakeRequest {
+    #[validate(custom(function = "validate_address"))]
+    #[serde(deserialize_with = "normalized_address")]
+    pub signer: String,
+    /// Validator public key (base58)
+    #[validate(custom(function = "validate_address"))]
+    #[serde(deserialize_with = "normalized_address")]
+    pub validator: String,
+    #[validate(custom(function = "validate_symbol"))]
+    pub symbol: String,
+    /// Amount in smallest unit (atoms)
+    #[validate(custom(function = "validate_positive_amount"))]
+    pub amount: Amount,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<i128>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preflight_balance_check: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub network: Option<String>,
 }
 
+impl StakeRequest {
+    /// Delegates by attaching the coins to `Stake.stake(validator)`.
+    pub fn into_stake_request(self) -> TransactionRequest {
+        TransactionRequest {
+            signer: self.signer,
+            contract: crate::wasm::tx::STAKE_CONTRACT.to_string(),
+            function: "stake".to_string(),
+            args: vec![Argument::Base58 {
+                b58: self.validator,
+            }],
+            attached_symbol: Some(self.symbol),
+            attached_amount: Some(self.amount.to_string()),
+            nonce: self.nonce,
+            preflight_balance_check: self.preflight_balance_check,
+            network: self.network,
+        }
+    }
+
+    pub fn into_unstake_request(self) -> TransactionRequest {
+        TransactionRequest {
+            signer: self.signer,
+            contract: crate::wasm::tx::STAKE_CONTRACT.to_string(),
+            function: "unstake".to_string(),
+            args: vec![
+                Argument::Base58 {
+                    b58: self.validator,
+                },
+                Argument::String(self.amount.to_string()),
+                Argument::String(self.symbol),
+            ],
+            attached_symbol: None,
+            attached_amount: None,
+            nonce: self.nonce,
+            preflight_balance_check: Some(false),
+            network: self.network,
+        }
+    }
+}
+
+/// One failed input check, flattened so a client can point at the offending field.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SubmitResponse {
-    pub error: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub tx_hash: Option<String>,
+pub struct FieldError {
+    /// Input field name, with `.`/`[i]` paths into nested inputs; `__all__`
+    /// for checks spanning several fields
+    pub field: String,
+    pub code: String,
+    pub message: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
-pub struct AccountQuery {
-    #[validate(length(min = 1))]
-    pub address: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub network: Option<String>,
+impl FieldError {
+    /// Flattens nested and list errors, sorted by field. A check without its
+    /// own message falls back to its code with spaces.
+    pub fn from_errors(errors: &ValidationErrors) -> Vec<Self> {
+        let mut out = Vec::new();
+        collect_field_errors("", errors, &mut out);
+        out.sort_by(|a, b| a.field.cmp(&b.field));
+        out
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct AccountBalance {
-    pub address: String,
-    pub balances: Vec<Balance>,
+fn collect_field_errors(prefix: &str, errors: &ValidationErrors, out: &mut Vec<FieldError>) {
+    for (field, kind) in errors.errors() {
+        let path = if prefix.is_empty() {
+            field.to_string()
+        } else {
+            format!("{}.{}", prefix, field)
+        };
+        match kind {
+            ValidationErrorsKind::Field(list) => out.extend(list.iter().map(|e| {
+                FieldError {
+                    field: path.clone(),
+                    code: e.code.to_string(),
+                    message: e
+                        .message
+                        .as_ref()
+                        .map_or_else(|| e.code.replace('_', " "), |m| m.to_string()),
+                }
+            })),
+            ValidationErrorsKind::Struct(inner) => collect_field_errors(&path, inner, out),
+            ValidationErrorsKind::List(items) => {
+                for (index, inner) in items {
+                    collect_field_errors(&format!("{}[{}]", path, index), inner, out);
+                }
+            }
+        }
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Balance {
-    pub symbol: String,
-    /// Balance in smallest unit (atoms)
-    pub flat: u64,
-    /// Human-readable balance
-    pub float: f64,
+/// Strips what agents tend to paste around an address: surrounding whitespace
+/// and one pair of quotes or backticks. Every address field is deserialized
+/// through this, so `validate_address` sees the cleaned value.
+pub fn normalize_address(raw: &str) -> String {
+    let trimmed = raw.trim();
+    ['"', '\'', '`']
+        .iter()
+        .find_map(|q| trimmed.strip_prefix(*q)?.strip_suffix(*q))
+        .unwrap_or(trimmed)
+        .trim()
+        .to_string()
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
-pub struct HeightQuery {
-    pub height: u64,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub network: Option<String>,
+fn normalized_address<'de, D>(de: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    String::deserialize(de).map(|a| normalize_address(&a))
+}
+
+fn normalized_opt_address<'de, D>(de: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Option::<String>::deserialize(de).map(|a| a.map(|a| normalize_address(&a)))
+}
+
+fn normalized_addresses<'de, D>(de: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Vec::<String>::deserialize(de).map(|v| v.iter().map(|a| normalize_address(a)).collect())
+}
+
+const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Accounts, signers and validators are all identified by a base58-encoded
+/// 48-byte public key. A rejected value says why, so an agent can tell an
+/// account name (which needs `resolve_name`) from a mangled address.
+fn validate_address(address: &str) -> Result<(), ValidationError> {
+    if crate::wasm::tx::Address::from_b58(address).is_ok() {
+        return Ok(());
+    }
+
+    let looks_like_name = address.contains('.')
+        || (address.len() < 40
+            && address
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_'));
+    let message = if address.is_empty() {
+        "address is empty".to_string()
+    } else if looks_like_name {
+        format!(
+            "{:?} looks like an account name, not an address; resolve it with resolve_name first",
+            address
+        )
+    } else if let Some(c) = address.chars().find(|c| !BASE58_ALPHABET.contains(*c)) {
+        format!("invalid base58: {:?} is not in the base58 alphabet", c)
+    } else {
+        "not a 48-byte public key; addresses are base58 and about 66 characters long".to_string()
+    };
+
+    let mut err = ValidationError::new("invalid_address");
+    err.message = Some(message.into());
+    Err(err)
+}
+
+fn validate_addresses(addresses: &[String]) -> Result<(), ValidationError> {
+    addresses
+        .iter()
+        .try_for_each(|address| validate_address(address))
+}
+
+fn validate_state_keys(keys: &[String]) -> Result<(), ValidationError> {
+    if keys.iter().any(|key| key.is_empty()) {
+        return Err(ValidationError::new("empty_key"));
+    }
+    Ok(())
+}
+
+fn validate_block_hash(hash: &str) -> Result<(), ValidationError> {
+    match block_hash_b58(hash) {
+        Some(_) => Ok(()),
+        None => Err(ValidationError::new("invalid_block_hash")),
+    }
+}
+
+/// Asset symbols are 1 to 32 characters of uppercase ASCII letters, digits,
+/// or underscores, e.g. `AMA` or `USDC_2`. Every request type that names an
+/// asset checks it here so a bad symbol fails before reaching the node.
+fn validate_symbol(symbol: &str) -> Result<(), ValidationError> {
+    let well_formed = (1..=MAX_SYMBOL_LEN).contains(&symbol.len())
+        && symbol
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_');
+    if well_formed {
+        Ok(())
+    } else {
+        let mut e = ValidationError::new("invalid_symbol");
+        e.message = Some("symbol must be 1-32 uppercase letters, digits, or underscores".into());
+        Err(e)
+    }
+}
+
+/// Rejects self-transfers and amounts that are zero or negative, so they fail
+/// as `validation_failed` before any blob is built.
+fn validate_transfer(req: &TransferRequest) -> Result<(), ValidationError> {
+    if req.signer == req.receiver {
+        let mut e = ValidationError::new("self_transfer");
+        e.message = Some("receiver must differ from signer".into());
+        return Err(e);
+    }
+
+    let non_positive = match (&req.amount, &req.amount_display) {
+        (Some(amount), _) => amount.is_zero(),
+        (None, Some(display)) => {
+            let display = display.trim();
+            display.starts_with('-')
+                || (!display.is_empty() && display.chars().all(|c| c == '0' || c == '.'))
+        }
+        (None, None) => false,
+    };
+    if non_positive {
+        let mut e = ValidationError::new("amount_not_positive");
+        e.message = Some("amount must be greater than zero".into());
+        return Err(e);
+    }
+    Ok(())
+}
+
+fn validate_code_hex(code: &str) -> Result<(), ValidationError> {
+    match hex::decode(code.trim_start_matches("0x")) {
+        Ok(bytes) if !bytes.is_empty() => Ok(()),
+        Ok(_) => Err(ValidationError::new("empty_code")),
+        Err(_) => Err(ValidationError::new("invalid_hex")),
+    }
+}
+
+fn validate_memo(memo: &str) -> Result<(), ValidationError> {
+    crate::wasm::tx::check_memo(memo.as_bytes()).map_err(|_| ValidationError::new("memo_too_long"))
+}
+
+fn validate_atoms(raw: &str) -> Result<(), ValidationError> {
+    match super::amount::parse_atoms(raw) {
+        Ok(_) => Ok(()),
+        Err(e) => {
+            let mut err = ValidationError::new("invalid_atoms");
+            if let super::error::BlockchainError::ValidationFailed(msg) = e {
+                err.message = Some(msg.into());
+            }
+            Err(err)
+        }
+    }
+}
+
+fn validate_positive_amount(amount: &Amount) -> Result<(), ValidationError> {
+    if !amount.is_zero() {
+        Ok(())
+    } else {
+        Err(ValidationError::new("amount_not_positive"))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum Argument {
+    String(String),
+    Number(i64),
+    Base58 { b58: String },
+    Hex { hex: String },
+    Utf8 { utf8: String },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
-pub struct TransactionQuery {
-    #[validate(length(min = 1))]
-    pub tx_hash: String,
+pub struct BatchQuery {
+    #[validate(length(min = 1, max = 20))]
+    pub requests: Vec<BatchRequest>,
+    /// Default network for sub-requests that do not set their own
     #[serde(skip_serializing_if = "Option::is_none")]
     pub network: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BatchRequest {
+    /// Name of a read-only tool, e.g. "get_account_balance"
+    pub tool: String,
+    #[serde(default)]
+    pub params: serde_json::Value,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
-pub struct TransactionHistoryQuery {
-    #[validate(length(min = 1))]
-    pub address: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<u32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub offset: Option<u32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort: Option<String>,
+pub struct NetworkInfoQuery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub network: Option<String>,
 }
 
+/// Identity of the chain a node serves. Fields the node does not report are left
+/// out; anything else it returns lands in `extra`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkInfo {
+    #[serde(default, alias = "network_id", skip_serializing_if = "Option::is_none")]
+    pub chain_id: Option<String>,
+    /// Protocol/consensus version the node runs
+    #[serde(
+        default,
+        alias = "consensus_version",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub protocol_version: Option<String>,
+    #[serde(default, alias = "genesis", skip_serializing_if = "Option::is_none")]
+    pub genesis_hash: Option<String>,
+    /// Node software version
+    #[serde(default, alias = "version", skip_serializing_if = "Option::is_none")]
+    pub node_version: Option<String>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
-pub struct ContractStateQuery {
-    #[validate(length(min = 1))]
-    pub contract_address: String,
-    #[validate(length(min = 1))]
-    pub key: String,
+pub struct FeeScheduleQuery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub network: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
-pub struct TransactionRequest {
-    #[validate(length(min = 1))]
-    pub signer: String,
-    #[validate(length(min = 1))]
-    pub contract: String,
-    #[validate(length(min = 1))]
-    pub function: String,
-    pub args: Vec<Argument>,
+/// How the node prices transactions, in atoms of `symbol`. Rates the node does
+/// not report are left out; anything beyond the known rates lands in `extra`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeeSchedule {
+    #[serde(default = "default_fee_symbol")]
+    pub symbol: String,
+    /// Flat charge per transaction
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attached_symbol: Option<String>,
+    pub base_fee: Option<Amount>,
+    /// Charge per byte of the signed envelope
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attached_amount: Option<String>,
+    pub per_byte: Option<Amount>,
+    /// Charge per unit of execution (the receipt's `exec_used`)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub nonce: Option<i64>,
+    pub per_exec_unit: Option<Amount>,
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
-#[serde(untagged)]
-pub enum Argument {
-    String(String),
-    Number(i64),
-    Base58 { b58: String },
-    Hex { hex: String },
-    Utf8 { utf8: String },
+fn default_fee_symbol() -> String {
+    "AMA".to_string()
+}
+
+impl FeeSchedule {
+    /// Fee for a transaction of `bytes` bytes using `exec_units` of execution,
+    /// or `None` if the schedule lacks a rate that applies or the sum overflows.
+    pub fn fee_for(&self, bytes: u64, exec_units: u64) -> Option<Amount> {
+        let scaled = |rate: Option<Amount>, quantity: u64| match (rate, quantity) {
+            (_, 0) => Some(Amount::ZERO),
+            (Some(rate), q) => Amount::new(rate.atoms().checked_mul(q as i128)?).ok(),
+            (None, _) => None,
+        };
+        self.base_fee
+            .unwrap_or_default()
+            .checked_add(scaled(self.per_byte, bytes)?)?
+            .checked_add(scaled(self.per_exec_unit, exec_units)?)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
@@ -142,6 +1621,185 @@ pub struct ChainStats {
     pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct LatestBlockQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct BlockRangeQuery {
+    pub from_height: u64,
+    pub to_height: u64,
+    /// Fail the whole call if any height fails, instead of returning partial results
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strict: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+/// Filters for `search_transactions`. At least one is required. With a
+/// `counterparty` the account's history is scanned; otherwise `from_height` and
+/// `to_height` bound a block-by-block scan limited to the client's max block range.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct SearchTransactionsQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_height: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to_height: Option<u64>,
+    /// Only Coin transfers of this asset
+    #[validate(custom(function = "validate_symbol"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    /// Only Coin transfers of at least this many atoms
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_amount: Option<String>,
+    /// Transactions signed by or transferring to this address (base58)
+    #[validate(custom(function = "validate_address"))]
+    #[serde(
+        default,
+        deserialize_with = "normalized_opt_address",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub counterparty: Option<String>,
+    #[validate(range(min = 1, max = 200))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+impl SearchTransactionsQuery {
+    pub fn has_filter(&self) -> bool {
+        self.from_height.is_some()
+            || self.to_height.is_some()
+            || self.symbol.is_some()
+            || self.min_amount.is_some()
+            || self.counterparty.is_some()
+    }
+
+    pub fn matches(&self, tx: &Transaction, min_amount: Option<i128>) -> bool {
+        let height = tx.metadata.entry_height;
+        if self.from_height.is_some_and(|h| height < h)
+            || self.to_height.is_some_and(|h| height > h)
+        {
+            return false;
+        }
+
+        let action = &tx.tx.action;
+        let is_transfer = action.contract == "Coin" && action.function == "transfer";
+        if self.symbol.is_some() || min_amount.is_some() {
+            if !is_transfer {
+                return false;
+            }
+            let symbol = action.args.get(2).map(String::as_str).unwrap_or("AMA");
+            if self.symbol.as_deref().is_some_and(|s| s != symbol) {
+                return false;
+            }
+            let amount = action.args.get(1).and_then(|a| a.parse::<i128>().ok());
+            if min_amount.is_some_and(|min| amount.map_or(true, |a| a < min)) {
+                return false;
+            }
+        }
+
+        match &self.counterparty {
+            Some(cp) => tx.tx.signer == *cp || (is_transfer && action.args.first() == Some(cp)),
+            None => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionSearchResult {
+    pub transactions: Vec<Transaction>,
+    /// Offset of the next page, when more matches remain
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_offset: Option<u32>,
+    /// Transactions examined before filtering
+    pub scanned: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct TransactionsSinceQuery {
+    #[validate(custom(function = "validate_address"))]
+    #[serde(deserialize_with = "normalized_address")]
+    pub address: String,
+    /// Lowest block height to include; pass back `next_min_height` from the previous call
+    pub min_height: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+/// Account activity at or above a height watermark, oldest first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionsSince {
+    pub transactions: Vec<Transaction>,
+    pub min_height: u64,
+    /// Highest height returned, to use as the next watermark. The watermark is
+    /// inclusive, so transactions at that height come back again next time and
+    /// should be deduplicated by hash.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_min_height: Option<u64>,
+    /// False when the page cap was reached before history older than
+    /// `min_height`, so older matching transactions may be missing
+    pub complete: bool,
+}
+
+/// One page of account history with the paging it was fetched with echoed back.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionHistoryPage {
+    pub transactions: Vec<Transaction>,
+    pub count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    pub offset: u32,
+    /// True when a full page came back, so another page may follow
+    pub has_more: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_offset: Option<u32>,
+}
+
+impl TransactionHistoryPage {
+    /// Without a requested limit the node's page size is unknown, so no
+    /// `has_more` hint is given.
+    pub fn new(transactions: Vec<Transaction>, limit: Option<u32>, offset: Option<u32>) -> Self {
+        let count = transactions.len();
+        let offset = offset.unwrap_or(0);
+        let has_more = limit.is_some_and(|l| l > 0 && count >= l as usize);
+        Self {
+            transactions,
+            count,
+            limit,
+            offset,
+            has_more,
+            next_offset: has_more.then(|| offset + count as u32),
+        }
+    }
+}
+
+/// Blocks fetched for a height range, ordered by height, with any heights
+/// that could not be fetched listed separately.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockRangeResult {
+    pub blocks: Vec<BlockAtHeight>,
+    pub count: usize,
+    pub failed_heights: Vec<FailedHeight>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FailedHeight {
+    pub height: u64,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockAtHeight {
+    pub height: u64,
+    pub entries: Vec<BlockEntry>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BlockEntry {
     pub hash: String,
@@ -181,6 +1839,74 @@ pub struct Transaction {
     pub receipt: TransactionReceipt,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum TransactionStatus {
+    /// Not yet included in a block; may still be in the mempool
+    Pending,
+    Confirmed {
+        height: u64,
+    },
+    Failed {
+        height: u64,
+        error: String,
+    },
+    Unknown,
+}
+
+impl TransactionStatus {
+    /// Classifies a raw `/api/chain/tx/{hash}` response.
+    pub fn from_response(response: &serde_json::Value) -> Self {
+        let error = response
+            .get("result")
+            .and_then(|r| r.get("error"))
+            .and_then(|e| e.as_str());
+        if error == Some("not_found") {
+            return Self::Pending;
+        }
+
+        let height = response
+            .get("metadata")
+            .and_then(|m| m.get("entry_height"))
+            .and_then(|h| h.as_u64());
+        let success = response
+            .get("receipt")
+            .and_then(|r| r.get("success"))
+            .and_then(|s| s.as_bool());
+
+        match (height, success) {
+            (Some(height), Some(true)) => Self::Confirmed { height },
+            (Some(height), Some(false)) => Self::Failed {
+                height,
+                error: error.unwrap_or("unknown").to_string(),
+            },
+            _ => Self::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct PendingTransactionsQuery {
+    /// Only return transactions signed by this account
+    #[validate(custom(function = "validate_address"))]
+    #[serde(
+        default,
+        deserialize_with = "normalized_opt_address",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+/// A mempool transaction: the signed envelope without inclusion metadata or receipt.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingTransaction {
+    pub hash: String,
+    pub signature: String,
+    pub tx: TransactionData,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransactionMetadata {
     pub entry_hash: String,
@@ -212,13 +1938,66 @@ pub struct TransactionAction {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransactionReceipt {
     pub success: bool,
+    #[serde(default)]
     pub result: String,
+    /// Events emitted by the call; absent when it emitted none
+    #[serde(default)]
     pub logs: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub exec_used: String,
+}
+
+/// Execution outcome of an included transaction.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionReceiptReport {
+    pub tx_hash: String,
+    pub height: u64,
+    pub success: bool,
+    /// Failure reason; absent on success
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Return value of the called function
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub result: String,
+    /// Execution cost charged, in atoms
     pub exec_used: String,
+    /// Contract events in emission order; empty when none were emitted
+    pub events: Vec<serde_json::Value>,
+}
+
+impl TransactionReceiptReport {
+    pub fn from_transaction(tx: Transaction) -> Self {
+        let error = (!tx.receipt.success).then(|| match tx.result.error.as_str() {
+            "" | "ok" => tx.receipt.result.clone(),
+            other => other.to_string(),
+        });
+        Self {
+            tx_hash: tx.hash,
+            height: tx.metadata.entry_height,
+            success: tx.receipt.success,
+            error,
+            result: tx.receipt.result,
+            exec_used: tx.receipt.exec_used,
+            events: tx.receipt.logs,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
 pub struct ValidatorsQuery {
+    /// Return stake, score and operator for each validator instead of peer info only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detailed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct ValidatorDetailsQuery {
+    /// Validator public key
+    #[validate(custom(function = "validate_address"))]
+    #[serde(deserialize_with = "normalized_address")]
+    pub validator: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub network: Option<String>,
 }
@@ -235,3 +2014,318 @@ pub struct ValidatorInfo {
     pub rooted_height: u64,
     pub temporal_hash: String,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidatorDetails {
+    #[serde(default)]
+    pub pk: String,
+    /// Staked amount in smallest unit (atoms)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stake: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uptime: Option<f64>,
+    /// Account that operates the validator, when distinct from its key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peer: Option<ValidatorInfo>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::time::Duration;
+
+    fn address(byte: u8) -> String {
+        bs58::encode([byte; 48]).into_string()
+    }
+
+    /// A transfer from `address(1)` to `address(2)` with `extra` fields merged in.
+    fn transfer(extra: serde_json::Value) -> TransferRequest {
+        let mut value = json!({"signer": address(1), "receiver": address(2), "symbol": "AMA"});
+        value
+            .as_object_mut()
+            .unwrap()
+            .extend(extra.as_object().unwrap().clone());
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn validation_message(req: &impl Validate) -> String {
+        req.validate().unwrap_err().to_string()
+    }
+
+    #[test]
+    fn poll_backoff_grows_to_max() {
+        let backoff = PollBackoff::default().with_overrides(Some(100), Some(3.0), Some(1000));
+        let mut interval = backoff.initial;
+        let schedule: Vec<u128> = (0..5)
+            .map(|_| {
+                let current = interval;
+                interval = backoff.next(interval);
+                current.as_millis()
+            })
+            .collect();
+        assert_eq!(schedule, vec![100, 300, 900, 1000, 1000]);
+    }
+
+    #[test]
+    fn poll_backoff_keeps_defaults_for_missing_or_shrinking_overrides() {
+        let backoff = PollBackoff::default().with_overrides(None, Some(0.5), None);
+        assert_eq!(backoff.initial, Duration::from_millis(500));
+        assert_eq!(backoff.factor, 2.0);
+        assert_eq!(backoff.next(Duration::from_secs(4)), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn transfer_accepts_a_positive_amount_to_another_account() {
+        assert!(transfer(json!({"amount": "10"})).validate().is_ok());
+        assert!(transfer(json!({"amount_display": "0.5"}))
+            .validate()
+            .is_ok());
+    }
+
+    #[test]
+    fn transfer_rejects_self_transfer() {
+        let req = transfer(json!({"receiver": address(1), "amount": 10}));
+        assert!(validation_message(&req).contains("receiver must differ from signer"));
+    }
+
+    #[test]
+    fn transfer_rejects_zero_amount() {
+        for extra in [
+            json!({"amount": 0}),
+            json!({"amount": "0"}),
+            json!({"amount_display": "0.00"}),
+            json!({"amount_display": "-1"}),
+        ] {
+            let req = transfer(extra);
+            assert!(validation_message(&req).contains("amount must be greater than zero"));
+        }
+    }
+
+    #[test]
+    fn symbols_are_short_uppercase_identifiers() {
+        for symbol in [
+            "AMA",
+            "USDC_2",
+            "A",
+            "0",
+            "X".repeat(MAX_SYMBOL_LEN).as_str(),
+        ] {
+            assert!(validate_symbol(symbol).is_ok(), "{symbol:?}");
+        }
+        for symbol in [
+            "",
+            "ama",
+            "Ama",
+            "AM A",
+            "AMA-2",
+            "AMA.",
+            "ÄMA",
+            "X".repeat(MAX_SYMBOL_LEN + 1).as_str(),
+        ] {
+            let err = validate_symbol(symbol).unwrap_err();
+            assert_eq!(err.code, "invalid_symbol", "{symbol:?}");
+        }
+    }
+
+    #[test]
+    fn transfer_checks_its_symbol() {
+        assert!(transfer(json!({"symbol": "lowercase", "amount": 1}))
+            .validate()
+            .is_err());
+        let omitted = transfer(json!({"symbol": null, "amount": 1}));
+        assert!(omitted.validate().is_ok());
+        assert_eq!(omitted.resolve_symbol(Some("AMA")).unwrap(), "AMA");
+        assert_eq!(
+            transfer(json!({"amount": 1}))
+                .resolve_symbol(Some("USDC"))
+                .unwrap(),
+            "AMA"
+        );
+
+        let missing = omitted.resolve_symbol(None).unwrap_err();
+        assert_eq!(missing.field_errors()["symbol"][0].code, "required");
+        let bad_default = omitted.resolve_symbol(Some("lowercase")).unwrap_err();
+        assert_eq!(
+            bad_default.field_errors()["symbol"][0].code,
+            "invalid_symbol"
+        );
+    }
+
+    #[derive(Validate)]
+    struct Leg {
+        #[validate(length(min = 1))]
+        name: String,
+    }
+
+    #[derive(Validate)]
+    struct Plan {
+        #[validate(custom(function = "validate_symbol"))]
+        symbol: String,
+        #[validate(nested)]
+        first: Leg,
+        #[validate(nested)]
+        legs: Vec<Leg>,
+    }
+
+    #[test]
+    fn field_errors_flatten_nested_and_list_errors() {
+        let plan = Plan {
+            symbol: "ama".to_string(),
+            first: Leg {
+                name: String::new(),
+            },
+            legs: vec![
+                Leg {
+                    name: "ok".to_string(),
+                },
+                Leg {
+                    name: String::new(),
+                },
+            ],
+        };
+        let errors = FieldError::from_errors(&plan.validate().unwrap_err());
+        let flat: Vec<(&str, &str, &str)> = errors
+            .iter()
+            .map(|e| (e.field.as_str(), e.code.as_str(), e.message.as_str()))
+            .collect();
+        assert_eq!(
+            flat,
+            vec![
+                ("first.name", "length", "length"),
+                ("legs[1].name", "length", "length"),
+                (
+                    "symbol",
+                    "invalid_symbol",
+                    "symbol must be 1-32 uppercase letters, digits, or underscores"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn field_errors_report_schema_checks_under_all() {
+        let req = transfer(json!({"receiver": address(1), "amount": 1}));
+        let errors = FieldError::from_errors(&req.validate().unwrap_err());
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "__all__");
+        assert_eq!(errors[0].code, "self_transfer");
+    }
+
+    fn balances(value: serde_json::Value) -> Result<Vec<(String, i128, Option<f64>)>, String> {
+        serde_json::from_value::<AccountBalance>(json!({"address": address(1), "balances": value}))
+            .map(|account| {
+                account
+                    .balances
+                    .into_iter()
+                    .map(|b| (b.symbol, b.flat.atoms(), b.float))
+                    .collect()
+            })
+            .map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn balances_read_the_list_shape() {
+        let list = json!([
+            {"symbol": "AMA", "flat": 1500000000, "float": 1.5},
+            {"symbol": "USDC", "amount": "42"},
+        ]);
+        assert_eq!(
+            balances(list).unwrap(),
+            vec![
+                ("AMA".to_string(), 1_500_000_000, Some(1.5)),
+                ("USDC".to_string(), 42, None),
+            ]
+        );
+        assert_eq!(balances(json!([])).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn balances_read_the_symbol_keyed_shape() {
+        let by_symbol = json!({
+            "USDC": "42",
+            "AMA": {"flat": 1500000000, "float": 1.5},
+            "GOLD": {"amount": 7},
+        });
+        assert_eq!(
+            balances(by_symbol).unwrap(),
+            vec![
+                ("AMA".to_string(), 1_500_000_000, Some(1.5)),
+                ("GOLD".to_string(), 7, None),
+                ("USDC".to_string(), 42, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn balances_reject_malformed_input() {
+        for malformed in [
+            json!("AMA"),
+            json!(12),
+            json!([{"symbol": "AMA"}]),
+            json!([{"symbol": "AMA", "flat": "1.5"}]),
+            json!({"AMA": "-1"}),
+            json!({"AMA": {"float": 1.5}}),
+        ] {
+            let err = balances(malformed.clone()).unwrap_err();
+            assert!(
+                err.contains("balances must be a list"),
+                "{}: {}",
+                malformed,
+                err
+            );
+        }
+    }
+
+    #[test]
+    fn normalize_address_strips_pasted_quotes() {
+        let addr = address(1);
+        for raw in [
+            addr.clone(),
+            format!("  {addr}\n"),
+            format!("\"{addr}\""),
+            format!("'{addr}'"),
+            format!("`{addr}`"),
+            format!(" ` {addr} ` "),
+        ] {
+            assert_eq!(normalize_address(&raw), addr, "{raw:?}");
+        }
+        // only a matching pair is stripped
+        assert_eq!(
+            normalize_address(&format!("\"{addr}`")),
+            format!("\"{addr}`")
+        );
+    }
+
+    fn address_error(address: &str) -> String {
+        validate_address(address)
+            .unwrap_err()
+            .message
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn validate_address_explains_each_rejection() {
+        assert!(validate_address(&address(1)).is_ok());
+        assert_eq!(address_error(""), "address is empty");
+        assert!(address_error("alice.ama").contains("looks like an account name"));
+        assert!(address_error("alice").contains("resolve_name"));
+        assert_eq!(
+            address_error(&format!("0{}", &address(1)[1..])),
+            "invalid base58: '0' is not in the base58 alphabet"
+        );
+        assert!(address_error(&bs58::encode([5u8; 32]).into_string()).contains("48-byte"));
+    }
+
+    #[test]
+    fn address_fields_are_normalized_before_validation() {
+        let req = transfer(json!({"receiver": format!("`{}`", address(2)), "amount": 1}));
+        assert_eq!(req.receiver, address(2));
+        assert!(req.validate().is_ok());
+    }
+}
@@ -1,38 +1,456 @@
-use amadeus_mcp::{BlockchainClient, BlockchainMcpServer};
-use rmcp::ServiceExt;
-use std::env;
-use tracing::info;
+use amadeus_mcp::{
+    blockchain::{
+        http::{DEFAULT_POOL_IDLE_TIMEOUT, DEFAULT_POOL_MAX_IDLE_PER_HOST},
+        PollBackoff,
+    },
+    wasm::tx::{SecretKey, SigningParams},
+    BlockchainClient, BlockchainMcpServer,
+};
+use rmcp::{transport::sse_server::SseServer, ServiceExt};
+use std::{env, net::SocketAddr, time::Duration};
+use tokio::sync::watch;
+use tracing::{info, warn};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
+/// How long in-flight tool calls may run after a shutdown signal.
+const SHUTDOWN_GRACE: Duration = Duration::from_secs(10);
+
+const DEFAULT_BIND_ADDR: &str = "127.0.0.1:8000";
+
+const DEFAULT_LOG_FILTER: &str = "info,amadeus_mcp=debug";
+
+/// Environment variables read by the stdio/SSE binary, for `--help`.
+const ENV_VARS: &[(&str, &str)] = &[
+    (
+        "BLOCKCHAIN_URL",
+        "mainnet node URL (default https://nodes.amadeus.bot)",
+    ),
+    (
+        "AMADEUS_TESTNET_RPC",
+        "testnet node URL (default https://testnet.amadeus.bot)",
+    ),
+    (
+        "AMADEUS_MAX_BLOCK_RANGE",
+        "max span of get_block_range (default 100)",
+    ),
+    (
+        "AMADEUS_MAX_RESPONSE_BYTES",
+        "largest node reply accepted (default 8388608)",
+    ),
+    ("AMADEUS_MAX_CONCURRENCY", "max node requests in flight"),
+    (
+        "AMADEUS_MIN_REQUEST_INTERVAL_MS",
+        "minimum gap between node requests",
+    ),
+    (
+        "AMADEUS_POOL_MAX_IDLE_PER_HOST",
+        "idle node connections kept open (default 16)",
+    ),
+    (
+        "AMADEUS_POOL_IDLE_TIMEOUT_SECS",
+        "seconds before an idle connection closes (default 90)",
+    ),
+    (
+        "AMADEUS_POLL_INITIAL_MS",
+        "first wait_for_transaction poll interval (default 500)",
+    ),
+    (
+        "AMADEUS_POLL_BACKOFF_FACTOR",
+        "poll interval multiplier, at least 1 (default 2)",
+    ),
+    (
+        "AMADEUS_POLL_MAX_MS",
+        "longest poll interval (default 5000)",
+    ),
+    (
+        "AMADEUS_OFFLINE_BUILD",
+        "\"1\" builds create_transfer blobs without node calls",
+    ),
+    ("BLOCKCHAIN_AUTH_TOKEN", "bearer token sent to the node"),
+    (
+        "AMADEUS_SIGNING_SK",
+        "base58 key that enables sign_and_submit_transfer",
+    ),
+    ("AMADEUS_SIGNING_DST", "BLS signature domain separation tag"),
+    ("AMADEUS_ENABLE_MINT", "\"1\" exposes the create_mint tool"),
+    (
+        "DEFAULT_ASSET",
+        "symbol create_transfer uses when none is given",
+    ),
+    ("MCP_TRANSPORT", "stdio (default) or sse"),
+    (
+        "MCP_BIND_ADDR",
+        "listen address for sse (default 127.0.0.1:8000)",
+    ),
+    ("LOG_FORMAT", "\"json\" for one JSON object per log line"),
+    (
+        "RUST_LOG",
+        "log filter (default info,amadeus_mcp=debug; invalid values fall back to it)",
+    ),
+];
+
+/// Command-line overrides; anything not given falls back to its env var.
+#[derive(Default)]
+struct Args {
+    blockchain_url: Option<String>,
+    transport: Option<String>,
+}
+
+/// Parses the command line, printing and exiting for `--help` and `--version`.
+fn parse_args() -> anyhow::Result<Args> {
+    let mut args = Args::default();
+    let mut argv = env::args().skip(1);
+    while let Some(arg) = argv.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+            None => (arg, None),
+        };
+        match flag.as_str() {
+            "-h" | "--help" => {
+                print!("{}", help_text());
+                std::process::exit(0);
+            }
+            "-V" | "--version" => {
+                println!("amadeus-mcp {}", env!("CARGO_PKG_VERSION"));
+                std::process::exit(0);
+            }
+            "--blockchain-url" => args.blockchain_url = Some(flag_value(&flag, inline, &mut argv)?),
+            "--transport" => args.transport = Some(flag_value(&flag, inline, &mut argv)?),
+            other => anyhow::bail!("unknown argument {:?}, see --help", other),
+        }
+    }
+    Ok(args)
+}
+
+/// Value of `--flag=value` or `--flag value`.
+fn flag_value(
+    flag: &str,
+    inline: Option<String>,
+    argv: &mut impl Iterator<Item = String>,
+) -> anyhow::Result<String> {
+    inline
+        .or_else(|| argv.next())
+        .ok_or_else(|| anyhow::anyhow!("{} requires a value", flag))
+}
+
+fn help_text() -> String {
+    let mut text = format!(
+        concat!(
+            "amadeus-mcp {}\n",
+            "MCP server for the Amadeus blockchain\n\n",
+            "USAGE:\n",
+            "    amadeus-mcp [OPTIONS]\n\n",
+            "OPTIONS:\n",
+            "    --blockchain-url <URL>    mainnet node URL [env: BLOCKCHAIN_URL]\n",
+            "    --transport <stdio|sse>   MCP transport [env: MCP_TRANSPORT]\n",
+            "    -h, --help                print this help\n",
+            "    -V, --version             print the version\n\n",
+            "ENVIRONMENT:\n",
+        ),
+        env!("CARGO_PKG_VERSION")
+    );
+    for (name, about) in ENV_VARS {
+        text.push_str(&format!("    {:<32} {}\n", name, about));
+    }
+    text.push_str("\nTOOLS:\n");
+    for name in BlockchainMcpServer::tool_names() {
+        text.push_str(&format!("    {}\n", name));
+    }
+    text
+}
+
+/// `RUST_LOG` when set and valid, else `DEFAULT_LOG_FILTER`. An invalid value
+/// is reported on stderr, since logging is not set up yet.
+fn log_filter() -> EnvFilter {
+    let spec = env::var(EnvFilter::DEFAULT_ENV).ok();
+    let (filter, warning) = log_filter_from(spec.as_deref());
+    if let Some(warning) = warning {
+        eprintln!("warning: {}", warning);
+    }
+    filter
+}
+
+/// Builds the filter for a `RUST_LOG` value, falling back to
+/// `DEFAULT_LOG_FILTER` when it is unset or invalid. The message explains an
+/// invalid value.
+fn log_filter_from(spec: Option<&str>) -> (EnvFilter, Option<String>) {
+    let Some(spec) = spec else {
+        return (EnvFilter::new(DEFAULT_LOG_FILTER), None);
+    };
+    match EnvFilter::try_new(spec) {
+        Ok(filter) => (filter, None),
+        Err(e) => (
+            EnvFilter::new(DEFAULT_LOG_FILTER),
+            Some(format!(
+                "ignoring invalid {} {:?} ({}), using {:?}",
+                EnvFilter::DEFAULT_ENV,
+                spec,
+                e,
+                DEFAULT_LOG_FILTER
+            )),
+        ),
+    }
+}
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    tracing_subscriber::registry()
-        .with(
-            EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| EnvFilter::new("info,amadeus_mcp=debug")),
-        )
-        .with(tracing_subscriber::fmt::layer().with_target(true))
-        .init();
-
-    let mainnet_url =
-        env::var("BLOCKCHAIN_URL").unwrap_or_else(|_| "https://nodes.amadeus.bot".to_string());
-    let testnet_url =
-        env::var("AMADEUS_TESTNET_RPC").unwrap_or_else(|_| "https://testnet.amadeus.bot".to_string());
+    let args = parse_args()?;
+
+    let registry = tracing_subscriber::registry().with(log_filter());
+    if env::var("LOG_FORMAT").is_ok_and(|f| f.eq_ignore_ascii_case("json")) {
+        // one object per line; event fields are flattened and the enclosing
+        // spans (tool name, request path) are listed under "spans"
+        registry
+            .with(
+                tracing_subscriber::fmt::layer()
+                    .json()
+                    .flatten_event(true)
+                    .with_current_span(false)
+                    .with_span_list(true)
+                    .with_target(true),
+            )
+            .init();
+    } else {
+        registry
+            .with(tracing_subscriber::fmt::layer().with_target(true))
+            .init();
+    }
+
+    let mainnet_url = args
+        .blockchain_url
+        .or_else(|| env::var("BLOCKCHAIN_URL").ok())
+        .unwrap_or_else(|| "https://nodes.amadeus.bot".to_string());
+    let testnet_url = env::var("AMADEUS_TESTNET_RPC")
+        .unwrap_or_else(|_| "https://testnet.amadeus.bot".to_string());
 
     info!(mainnet_url = %mainnet_url, testnet_url = %testnet_url, "initializing blockchain client");
 
-    let client = BlockchainClient::new(mainnet_url.clone())?;
-    let server = BlockchainMcpServer::new(client, mainnet_url, testnet_url);
+    let signing_params = SigningParams::from_dst(env::var("AMADEUS_SIGNING_DST").ok().as_deref());
+    let mut client =
+        BlockchainClient::new(mainnet_url.clone())?.with_signing_params(signing_params.clone());
+    if let Some(max) = env::var("AMADEUS_MAX_BLOCK_RANGE")
+        .ok()
+        .and_then(|v| v.parse().ok())
+    {
+        client = client.with_max_block_range(max);
+    }
+    if let Some(limit) = env::var("AMADEUS_MAX_RESPONSE_BYTES")
+        .ok()
+        .and_then(|v| v.parse().ok())
+    {
+        client = client.with_max_response_bytes(limit);
+    }
+    if let Some(max) = env::var("AMADEUS_MAX_CONCURRENCY")
+        .ok()
+        .and_then(|v| v.parse().ok())
+    {
+        client = client.with_max_concurrency(max);
+    }
+    if let Some(ms) = env::var("AMADEUS_MIN_REQUEST_INTERVAL_MS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+    {
+        client = client.with_min_request_interval(Duration::from_millis(ms));
+    }
+    let pool_max_idle = env::var("AMADEUS_POOL_MAX_IDLE_PER_HOST")
+        .ok()
+        .and_then(|v| v.parse().ok());
+    let pool_idle_timeout = env::var("AMADEUS_POOL_IDLE_TIMEOUT_SECS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .map(Duration::from_secs);
+    if pool_max_idle.is_some() || pool_idle_timeout.is_some() {
+        client = client.with_pool_config(
+            pool_max_idle.unwrap_or(DEFAULT_POOL_MAX_IDLE_PER_HOST),
+            pool_idle_timeout.unwrap_or(DEFAULT_POOL_IDLE_TIMEOUT),
+        )?;
+    }
+    client = client.with_poll_backoff(
+        PollBackoff::default().with_overrides(
+            env::var("AMADEUS_POLL_INITIAL_MS")
+                .ok()
+                .and_then(|v| v.parse().ok()),
+            env::var("AMADEUS_POLL_BACKOFF_FACTOR")
+                .ok()
+                .and_then(|v| v.parse().ok()),
+            env::var("AMADEUS_POLL_MAX_MS")
+                .ok()
+                .and_then(|v| v.parse().ok()),
+        ),
+    );
+    if env::var("AMADEUS_OFFLINE_BUILD").is_ok_and(|v| v == "1" || v == "true") {
+        info!("building transfers locally without node preflight");
+        client = client.with_offline_build(true);
+    }
+    if let Ok(token) = env::var("BLOCKCHAIN_AUTH_TOKEN") {
+        info!("using bearer authentication for blockchain requests");
+        client = client.with_auth_header("Authorization", format!("Bearer {}", token));
+    }
+    let health = client.health_check(&mainnet_url).await;
+    match &health {
+        Ok(status) if status.reachable => {
+            info!(height = ?status.height, latency_ms = status.latency_ms, "blockchain node reachable")
+        }
+        Ok(status) => warn!(
+            latency_ms = status.latency_ms,
+            "blockchain node unreachable"
+        ),
+        Err(e) => warn!(error = %e, "blockchain node health check failed"),
+    }
+
+    let mut server = BlockchainMcpServer::new(client, mainnet_url, testnet_url)
+        .with_signing_params(signing_params);
+    if let Ok(status) = health {
+        server = server.with_health_status(status);
+    }
+    if let Ok(symbol) = env::var("DEFAULT_ASSET") {
+        server = server.with_default_asset(symbol);
+    }
+    if let Ok(key_b58) = env::var("AMADEUS_SIGNING_SK") {
+        let key = SecretKey::from_b58(&key_b58)
+            .map_err(|e| anyhow::anyhow!("invalid AMADEUS_SIGNING_SK: {}", e))?;
+        warn!("server signing key configured; sign_and_submit_transfer is enabled");
+        server = server.with_signing_key(key);
+    }
+    if env::var("AMADEUS_ENABLE_MINT").is_ok_and(|v| v == "1" || v == "true") {
+        info!("create_mint is enabled");
+        server = server.with_mint_enabled();
+    }
+
+    let transport = args.transport.or_else(|| env::var("MCP_TRANSPORT").ok());
+    match transport.as_deref() {
+        Some("stdio") | None => serve_stdio(server).await?,
+        Some("sse") => {
+            let bind = env::var("MCP_BIND_ADDR").unwrap_or_else(|_| DEFAULT_BIND_ADDR.to_string());
+            if env::var("AMADEUS_SIGNING_SK").is_ok() {
+                warn!(bind = %bind, "signing key exposed: any client reaching this address can spend");
+            }
+            serve_sse(server, &bind).await?
+        }
+        Some(other) => anyhow::bail!("unknown transport {:?}, expected stdio or sse", other),
+    }
 
+    info!("server stopped");
+    Ok(())
+}
+
+async fn serve_stdio(server: BlockchainMcpServer) -> anyhow::Result<()> {
+    let mut in_flight = server.in_flight_calls();
     let service = server
         .serve(rmcp::transport::stdio())
         .await
         .map_err(|e| anyhow::anyhow!("failed to initialize server: {}", e))?;
 
-    service
-        .waiting()
+    let cancel = service.cancellation_token();
+    let waiting = service.waiting();
+    tokio::pin!(waiting);
+
+    tokio::select! {
+        result = &mut waiting => {
+            result.map_err(|e| anyhow::anyhow!("server error: {}", e))?;
+        }
+        signal = shutdown_signal() => {
+            drain(signal, &mut in_flight).await;
+            cancel.cancel();
+            waiting
+                .await
+                .map_err(|e| anyhow::anyhow!("server error: {}", e))?;
+        }
+    }
+    Ok(())
+}
+
+/// Serves every SSE session from clones of `server`, so they share one
+/// blockchain client and its rate limits.
+async fn serve_sse(server: BlockchainMcpServer, bind: &str) -> anyhow::Result<()> {
+    let addr: SocketAddr = bind
+        .parse()
+        .map_err(|e| anyhow::anyhow!("invalid MCP_BIND_ADDR {:?}: {}", bind, e))?;
+    let mut in_flight = server.in_flight_calls();
+
+    let cancel = SseServer::serve(addr)
         .await
-        .map_err(|e| anyhow::anyhow!("server error: {}", e))?;
+        .map_err(|e| anyhow::anyhow!("failed to bind {}: {}", addr, e))?
+        .with_service(move || server.clone());
+    info!(%addr, "serving MCP over SSE");
 
+    let signal = shutdown_signal().await;
+    drain(signal, &mut in_flight).await;
+    cancel.cancel();
     Ok(())
 }
+
+/// Gives running tool calls up to `SHUTDOWN_GRACE` to finish.
+async fn drain(signal: &str, in_flight: &mut watch::Receiver<usize>) {
+    info!(
+        signal,
+        grace_secs = SHUTDOWN_GRACE.as_secs(),
+        "shutdown requested, draining in-flight tool calls"
+    );
+    let drained = tokio::time::timeout(SHUTDOWN_GRACE, in_flight.wait_for(|n| *n == 0))
+        .await
+        .is_ok();
+    if !drained {
+        warn!(
+            remaining = *in_flight.borrow(),
+            "grace period elapsed, abandoning in-flight tool calls"
+        );
+    }
+}
+
+/// Resolves on SIGINT, or SIGTERM on unix, naming the signal received.
+async fn shutdown_signal() -> &'static str {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        match signal(SignalKind::terminate()) {
+            Ok(mut term) => tokio::select! {
+                _ = tokio::signal::ctrl_c() => "SIGINT",
+                _ = term.recv() => "SIGTERM",
+            },
+            Err(e) => {
+                warn!(error = %e, "cannot listen for SIGTERM, handling SIGINT only");
+                let _ = tokio::signal::ctrl_c().await;
+                "SIGINT"
+            }
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = tokio::signal::ctrl_c().await;
+        "SIGINT"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn default_filter() -> String {
+        EnvFilter::new(DEFAULT_LOG_FILTER).to_string()
+    }
+
+    #[test]
+    fn log_filter_defaults_when_unset() {
+        let (filter, warning) = log_filter_from(None);
+        assert_eq!(filter.to_string(), default_filter());
+        assert!(warning.is_none());
+    }
+
+    #[test]
+    fn log_filter_uses_valid_spec() {
+        let (filter, warning) = log_filter_from(Some("amadeus_mcp=trace"));
+        assert_eq!(filter.to_string(), "amadeus_mcp=trace");
+        assert!(warning.is_none());
+    }
+
+    #[test]
+    fn log_filter_falls_back_on_invalid_spec() {
+        let (filter, warning) = log_filter_from(Some("amadeus_mcp=loud"));
+        assert_eq!(filter.to_string(), default_filter());
+        let warning = warning.unwrap();
+        assert!(warning.contains("amadeus_mcp=loud"));
+        assert!(warning.contains(DEFAULT_LOG_FILTER));
+    }
+}
@@ -1,26 +1,273 @@
+use crate::blockchain::shared::{check_transaction_signature, decode_state_value};
 use crate::blockchain::{
-    AccountQuery, BlockchainClient, BlockchainError, ChainStatsQuery, ContractStateQuery,
-    HeightQuery, SignedTransaction, TransactionHistoryQuery, TransactionQuery,
-    TransactionRequest, ValidatorsQuery,
+    AccountAssetQuery, AccountQuery, AccountSummaryQuery, AssetQuery, BalanceAtHeightQuery,
+    BalancesQuery, BatchQuery, BlockHashQuery, BlockRangeQuery, BlockchainClient, BlockchainError,
+    ChainStatsQuery, ContractCallRequest, ContractCodeQuery, ContractKeysQuery, ContractStateQuery,
+    ContractStatesQuery, DecodeTransactionRequest, DeployContractRequest, FeeScheduleQuery,
+    HealthStatus, HeightQuery, LatestBlockQuery, ListAssetsQuery, MintRequest, NetworkInfoQuery,
+    PendingTransactionsQuery, RawTransaction, ResolveNameQuery, ReverseResolveQuery,
+    SearchTransactionsQuery, ServerInfo, SignAndSubmitTransferRequest, SignedTransaction,
+    StakeRequest, ToolMetrics, TopHoldersQuery, TransactionHistoryPage, TransactionHistoryQuery,
+    TransactionPositionQuery, TransactionQuery, TransactionRequest, TransactionsSinceQuery,
+    TransferRequest, ValidatorDetailsQuery, ValidatorsQuery, VerifySignatureRequest,
+    WaitForBlockQuery, WaitForTransactionQuery, DEFAULT_TOP_HOLDERS,
 };
+use crate::wasm::tx::{self, SecretKey, SigningParams};
+use futures::{stream, StreamExt};
 use rmcp::{
-    handler::server::tool::{Parameters, ToolRouter},
+    handler::server::tool::{Parameters, ToolCallContext, ToolRouter},
     model::*,
     service::RequestContext,
-    tool, tool_handler, tool_router, ErrorData as McpError, Json, RoleServer, ServerHandler,
+    tool, tool_router, ErrorData as McpError, Json, RoleServer, ServerHandler,
 };
-use std::{future::Future, sync::Arc};
-use tracing::error;
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+use tokio::sync::watch;
+use tracing::{debug, error, Instrument};
 use validator::Validate;
 
+const DEFAULT_HISTORY_CAP: usize = 1000;
+const DEFAULT_SUMMARY_RECENT: u32 = 10;
+const DEFAULT_WAIT_TIMEOUT_SECS: u64 = 60;
+const BATCH_CONCURRENCY: usize = 4;
+
+/// Example arguments appended to tool descriptions in `list_tools`, giving
+/// agents a concrete call to copy. Each must deserialize into the tool's
+/// parameter type.
+const TOOL_EXAMPLES: &[(&str, &str)] = &[
+    (
+        "create_transaction",
+        r#"{"signer":"2ZM9g2DS7WLPb7WTZ62E2u68P2jk7cE48astR6FrxYZ848JYbERNffFwNu1onvs8XX","contract":"Coin","function":"transfer","args":[{"b58":"2jccgTwDGFpLwrEe47imJCx3kqNaZM19gbSr7391Dt26tYf4ChCUejkiKGmTH8tiB1"},"1000000000","AMA"]}"#,
+    ),
+    (
+        "create_transfer",
+        r#"{"signer":"2ZM9g2DS7WLPb7WTZ62E2u68P2jk7cE48astR6FrxYZ848JYbERNffFwNu1onvs8XX","receiver":"2jccgTwDGFpLwrEe47imJCx3kqNaZM19gbSr7391Dt26tYf4ChCUejkiKGmTH8tiB1","symbol":"AMA","amount_display":"1.5","network":"testnet"}"#,
+    ),
+    (
+        "sign_and_submit_transfer",
+        r#"{"receiver":"2jccgTwDGFpLwrEe47imJCx3kqNaZM19gbSr7391Dt26tYf4ChCUejkiKGmTH8tiB1","symbol":"AMA","amount":1000000000}"#,
+    ),
+    (
+        "create_mint",
+        r#"{"signer":"2ZM9g2DS7WLPb7WTZ62E2u68P2jk7cE48astR6FrxYZ848JYbERNffFwNu1onvs8XX","symbol":"MYTOKEN","amount":1000000}"#,
+    ),
+    (
+        "create_contract_deploy",
+        r#"{"signer":"2ZM9g2DS7WLPb7WTZ62E2u68P2jk7cE48astR6FrxYZ848JYbERNffFwNu1onvs8XX","code_hex":"0061736d01000000"}"#,
+    ),
+    (
+        "create_stake",
+        r#"{"signer":"2ZM9g2DS7WLPb7WTZ62E2u68P2jk7cE48astR6FrxYZ848JYbERNffFwNu1onvs8XX","validator":"9FBsfMUSaBoMTZDyrBXCQWQY9HCFZJmkbSZg6dMM5u8tSR3yWNkWZTvHEA3mH8WKfL","symbol":"AMA","amount":5000000000}"#,
+    ),
+    (
+        "create_unstake",
+        r#"{"signer":"2ZM9g2DS7WLPb7WTZ62E2u68P2jk7cE48astR6FrxYZ848JYbERNffFwNu1onvs8XX","validator":"9FBsfMUSaBoMTZDyrBXCQWQY9HCFZJmkbSZg6dMM5u8tSR3yWNkWZTvHEA3mH8WKfL","symbol":"AMA","amount":5000000000}"#,
+    ),
+    (
+        "verify_transaction_blob",
+        r#"{"transaction":"<signed base58 envelope>"}"#,
+    ),
+    (
+        "compute_transaction_hash",
+        r#"{"transaction":"<unsigned blob or signed envelope>"}"#,
+    ),
+    (
+        "decode_transaction",
+        r#"{"transaction":"<blob from create_transaction>"}"#,
+    ),
+    (
+        "submit_transaction",
+        r#"{"transaction":"<blob from create_transaction>","signature":"<base58 BLS signature>","network":"testnet"}"#,
+    ),
+    (
+        "submit_raw_transaction",
+        r#"{"transaction":"<signed base58 envelope>"}"#,
+    ),
+    (
+        "verify_signature",
+        r#"{"public_key":"2ZM9g2DS7WLPb7WTZ62E2u68P2jk7cE48astR6FrxYZ848JYbERNffFwNu1onvs8XX","signing_payload":"<hex signing_payload>","signature":"<base58 BLS signature>"}"#,
+    ),
+    (
+        "get_account_balance",
+        r#"{"address":"2ZM9g2DS7WLPb7WTZ62E2u68P2jk7cE48astR6FrxYZ848JYbERNffFwNu1onvs8XX"}"#,
+    ),
+    (
+        "get_balance_at_height",
+        r#"{"address":"2ZM9g2DS7WLPb7WTZ62E2u68P2jk7cE48astR6FrxYZ848JYbERNffFwNu1onvs8XX","height":1000000}"#,
+    ),
+    (
+        "get_balance_for_asset",
+        r#"{"address":"2ZM9g2DS7WLPb7WTZ62E2u68P2jk7cE48astR6FrxYZ848JYbERNffFwNu1onvs8XX","symbol":"AMA"}"#,
+    ),
+    (
+        "get_balances",
+        r#"{"addresses":["2ZM9g2DS7WLPb7WTZ62E2u68P2jk7cE48astR6FrxYZ848JYbERNffFwNu1onvs8XX"]}"#,
+    ),
+    (
+        "get_account_summary",
+        r#"{"address":"2ZM9g2DS7WLPb7WTZ62E2u68P2jk7cE48astR6FrxYZ848JYbERNffFwNu1onvs8XX","recent":5}"#,
+    ),
+    ("get_asset_info", r#"{"symbol":"AMA"}"#),
+    ("get_supply", r#"{"symbol":"AMA"}"#),
+    ("get_top_holders", r#"{"symbol":"AMA","limit":10}"#),
+    ("get_block_by_height", r#"{"height":1000000}"#),
+    (
+        "get_block_by_hash",
+        r#"{"hash":"2qo2mC7GvBBEPZTmZeYXsc5KhzboKSen6DekBytar1fy"}"#,
+    ),
+    (
+        "get_block_range",
+        r#"{"from_height":1000000,"to_height":1000009}"#,
+    ),
+    (
+        "get_transaction",
+        r#"{"tx_hash":"2qo2mC7GvBBEPZTmZeYXsc5KhzboKSen6DekBytar1fy"}"#,
+    ),
+    (
+        "get_transaction_by_position",
+        r#"{"height":1000000,"index":0}"#,
+    ),
+    (
+        "get_transaction_status",
+        r#"{"tx_hash":"2qo2mC7GvBBEPZTmZeYXsc5KhzboKSen6DekBytar1fy"}"#,
+    ),
+    (
+        "wait_for_transaction",
+        r#"{"tx_hash":"2qo2mC7GvBBEPZTmZeYXsc5KhzboKSen6DekBytar1fy","timeout_secs":30}"#,
+    ),
+    (
+        "wait_for_next_block",
+        r#"{"after_height":1000000,"timeout_secs":30}"#,
+    ),
+    (
+        "get_transaction_receipt",
+        r#"{"tx_hash":"2qo2mC7GvBBEPZTmZeYXsc5KhzboKSen6DekBytar1fy"}"#,
+    ),
+    (
+        "get_pending_transactions",
+        r#"{"address":"2ZM9g2DS7WLPb7WTZ62E2u68P2jk7cE48astR6FrxYZ848JYbERNffFwNu1onvs8XX"}"#,
+    ),
+    (
+        "get_transaction_history",
+        r#"{"address":"2ZM9g2DS7WLPb7WTZ62E2u68P2jk7cE48astR6FrxYZ848JYbERNffFwNu1onvs8XX","limit":20,"offset":0}"#,
+    ),
+    (
+        "get_account_transactions_since",
+        r#"{"address":"2ZM9g2DS7WLPb7WTZ62E2u68P2jk7cE48astR6FrxYZ848JYbERNffFwNu1onvs8XX","min_height":1000000}"#,
+    ),
+    (
+        "search_transactions",
+        r#"{"counterparty":"2jccgTwDGFpLwrEe47imJCx3kqNaZM19gbSr7391Dt26tYf4ChCUejkiKGmTH8tiB1","symbol":"AMA","min_amount":"1000000000","limit":10}"#,
+    ),
+    ("get_validators", r#"{"detailed":true}"#),
+    (
+        "get_validator_details",
+        r#"{"validator":"9FBsfMUSaBoMTZDyrBXCQWQY9HCFZJmkbSZg6dMM5u8tSR3yWNkWZTvHEA3mH8WKfL"}"#,
+    ),
+    (
+        "get_contract_state",
+        r#"{"contract_address":"Coin","key":"<storage key>"}"#,
+    ),
+    (
+        "simulate_contract_call",
+        r#"{"contract":"Coin","function":"balance","args":[{"b58":"2ZM9g2DS7WLPb7WTZ62E2u68P2jk7cE48astR6FrxYZ848JYbERNffFwNu1onvs8XX"},"AMA"],"decode_as":"i128"}"#,
+    ),
+    (
+        "get_contract_states",
+        r#"{"contract_address":"Coin","keys":["<storage key>","<another key>"]}"#,
+    ),
+    ("resolve_name", r#"{"name":"alice.ama"}"#),
+    (
+        "reverse_resolve",
+        r#"{"address":"2ZM9g2DS7WLPb7WTZ62E2u68P2jk7cE48astR6FrxYZ848JYbERNffFwNu1onvs8XX"}"#,
+    ),
+    ("get_contract_code", r#"{"contract_address":"Coin"}"#),
+    (
+        "list_contract_keys",
+        r#"{"contract_address":"Coin","prefix":"balance:","limit":50}"#,
+    ),
+    (
+        "batch_query",
+        r#"{"requests":[{"tool":"get_account_balance","params":{"address":"2ZM9g2DS7WLPb7WTZ62E2u68P2jk7cE48astR6FrxYZ848JYbERNffFwNu1onvs8XX"}},{"tool":"get_chain_stats","params":{}}],"network":"testnet"}"#,
+    ),
+];
+
 #[derive(Clone)]
 pub struct BlockchainMcpServer {
     blockchain: Arc<BlockchainClient>,
     mainnet_url: String,
     testnet_url: String,
+    signing_key: Option<Arc<SecretKey>>,
+    mint_enabled: bool,
+    signing_params: SigningParams,
+    default_asset: Option<String>,
+    in_flight: Arc<watch::Sender<usize>>,
+    last_health: Option<HealthStatus>,
+    metrics: Arc<HashMap<String, ToolCounters>>,
     tool_router: ToolRouter<Self>,
 }
 
+/// Call counters for one tool. Every call goes through `call_tool`, which is
+/// the only place these are updated.
+#[derive(Default)]
+struct ToolCounters {
+    invocations: AtomicU64,
+    successes: AtomicU64,
+    failures: AtomicU64,
+    total_latency_ms: AtomicU64,
+}
+
+impl ToolCounters {
+    fn record(&self, succeeded: bool, elapsed_ms: u64) {
+        self.invocations.fetch_add(1, Ordering::Relaxed);
+        let outcome = if succeeded {
+            &self.successes
+        } else {
+            &self.failures
+        };
+        outcome.fetch_add(1, Ordering::Relaxed);
+        self.total_latency_ms
+            .fetch_add(elapsed_ms, Ordering::Relaxed);
+    }
+
+    fn snapshot(&self, tool: &str) -> ToolMetrics {
+        let invocations = self.invocations.load(Ordering::Relaxed);
+        let total_latency_ms = self.total_latency_ms.load(Ordering::Relaxed);
+        ToolMetrics {
+            tool: tool.to_string(),
+            invocations,
+            successes: self.successes.load(Ordering::Relaxed),
+            failures: self.failures.load(Ordering::Relaxed),
+            total_latency_ms,
+            avg_latency_ms: total_latency_ms.checked_div(invocations).unwrap_or(0),
+        }
+    }
+}
+
+/// Counts a tool call as in flight until dropped, including when the call is
+/// cancelled mid-await.
+struct InFlightGuard(Arc<watch::Sender<usize>>);
+
+impl InFlightGuard {
+    fn new(counter: &Arc<watch::Sender<usize>>) -> Self {
+        counter.send_modify(|n| *n += 1);
+        Self(counter.clone())
+    }
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.0.send_modify(|n| *n -= 1);
+    }
+}
+
 #[tool_router]
 impl BlockchainMcpServer {
     pub fn new(blockchain: BlockchainClient, mainnet_url: String, testnet_url: String) -> Self {
@@ -28,25 +275,107 @@ impl BlockchainMcpServer {
             blockchain: Arc::new(blockchain),
             mainnet_url,
             testnet_url,
-            tool_router: Self::tool_router(),
+            signing_key: None,
+            mint_enabled: false,
+            signing_params: SigningParams::default(),
+            default_asset: None,
+            in_flight: Arc::new(watch::channel(0).0),
+            last_health: None,
+            // keyed by every registered tool up front, so calls to unknown
+            // names cannot grow the map
+            metrics: Arc::new(
+                Self::tool_names()
+                    .into_iter()
+                    .map(|name| (name, ToolCounters::default()))
+                    .collect(),
+            ),
+            tool_router: Self::router_with(false, false),
+        }
+    }
+
+    /// Number of tool calls currently running, for draining on shutdown.
+    pub fn in_flight_calls(&self) -> watch::Receiver<usize> {
+        self.in_flight.subscribe()
+    }
+
+    /// Overrides the domain separation tag used when the server signs.
+    pub fn with_signing_params(mut self, params: SigningParams) -> Self {
+        self.signing_params = params;
+        self
+    }
+
+    /// Asset used by `create_transfer` when the request names no symbol, for
+    /// single-asset deployments.
+    pub fn with_default_asset(mut self, symbol: String) -> Self {
+        self.default_asset = Some(symbol);
+        self
+    }
+
+    /// Records the startup health check so `ping` can report it.
+    pub fn with_health_status(mut self, status: HealthStatus) -> Self {
+        self.last_health = Some(status);
+        self
+    }
+
+    /// Enables `sign_and_submit_transfer`, which signs with this key on the
+    /// server. Only configure this for trusted automation.
+    pub fn with_signing_key(mut self, key: SecretKey) -> Self {
+        self.signing_key = Some(Arc::new(key));
+        self.tool_router = Self::router_with(true, self.mint_enabled);
+        self
+    }
+
+    /// Exposes `create_mint`. Off by default so deployments that never mint
+    /// do not offer the tool to clients.
+    pub fn with_mint_enabled(mut self) -> Self {
+        self.mint_enabled = true;
+        self.tool_router = Self::router_with(self.signing_key.is_some(), true);
+        self
+    }
+
+    /// Names of every tool this server can expose, sorted; for `--help`.
+    pub fn tool_names() -> Vec<String> {
+        let mut names: Vec<String> = Self::tool_router()
+            .list_all()
+            .into_iter()
+            .map(|tool| tool.name.to_string())
+            .collect();
+        names.sort();
+        names
+    }
+
+    fn router_with(signing: bool, mint: bool) -> ToolRouter<Self> {
+        let mut router = Self::tool_router();
+        if !signing {
+            router.remove_route("sign_and_submit_transfer");
         }
+        if !mint {
+            router.remove_route("create_mint");
+        }
+        router
     }
 
     #[tool(
         name = "create_transaction",
-        description = "Creates an unsigned transaction for any contract call. Takes signer public key, contract name, function name, and arguments. Returns transaction blob that only needs signing."
+        description = "Creates an unsigned transaction for any contract call. Takes signer public key, contract name, function name, and arguments. Set preflight_balance_check to true to check the signer holds enough for a transfer or attached payment before building. Optional network parameter: 'mainnet' (default) or 'testnet'. Returns transaction blob that only needs signing."
     )]
     async fn create_transaction(
         &self,
         params: Parameters<TransactionRequest>,
     ) -> Result<Json<serde_json::Value>, McpError> {
         let req = params.0;
-        req.validate().map_err(|e| {
-            McpError::invalid_params(
-                "validation_failed",
-                Some(serde_json::json!({ "errors": e })),
-            )
-        })?;
+        req.validate().map_err(Self::validation_error)?;
+
+        if req.preflight_balance_check.unwrap_or(false) {
+            let url = match req.network.as_deref() {
+                Some("testnet") => &self.testnet_url,
+                _ => &self.mainnet_url,
+            };
+            self.blockchain
+                .check_spendable_balance(&req, url)
+                .await
+                .map_err(|e| Self::blockchain_error("create_transaction", e))?;
+        }
 
         let blob = self
             .blockchain
@@ -64,266 +393,1388 @@ impl BlockchainMcpServer {
     }
 
     #[tool(
-        name = "submit_transaction",
-        description = "Submits a signed transaction to the blockchain network. Requires the transaction blob and signature from the signing process. Optional network parameter: 'mainnet' (default) or 'testnet'."
+        name = "create_transfer",
+        description = "Creates an unsigned Coin transfer transaction. Pass amount in atoms, or amount_display in whole units (e.g. \"1.5\") to convert using the asset's decimals. symbol may be omitted when the server is configured with a default asset. An optional memo (UTF-8, at most 256 bytes) is carried as the last argument of the transfer. Set preflight_balance_check to true to check the signer's balance before building. Optional network parameter: 'mainnet' (default) or 'testnet'. Returns transaction blob that only needs signing."
     )]
-    async fn submit_transaction(
+    async fn create_transfer(
         &self,
-        params: Parameters<SignedTransaction>,
+        params: Parameters<TransferRequest>,
     ) -> Result<Json<serde_json::Value>, McpError> {
-        let tx = params.0;
-        tx.validate().map_err(|e| {
-            McpError::invalid_params(
-                "validation_failed",
-                Some(serde_json::json!({ "errors": e })),
-            )
-        })?;
+        let req = params.0;
+        req.validate().map_err(Self::validation_error)?;
+        let symbol = req
+            .resolve_symbol(self.default_asset.as_deref())
+            .map_err(Self::validation_error)?;
 
-        let url = match tx.network.as_deref() {
+        let url = match req.network.as_deref() {
             Some("testnet") => &self.testnet_url,
             _ => &self.mainnet_url,
         };
 
-        let response = self
+        let blob = self
             .blockchain
-            .submit_signed_transaction(tx, url)
+            .create_transfer_blob(req, &symbol, url)
             .await
-            .map_err(|e| Self::blockchain_error("submit_transaction", e))?;
+            .map_err(|e| Self::blockchain_error("create_transfer", e))?;
 
-        if response.error == "ok" {
+        Ok(Json(serde_json::json!({
+            "blob": blob.blob,
+            "signing_payload": blob.signing_payload,
+            "transaction_hash": blob.transaction_hash,
+            "status": "unsigned",
+            "next_step": "Sign the signing_payload with BLS12-381 and call submit_transaction"
+        })))
+    }
+
+    #[tool(
+        name = "sign_and_submit_transfer",
+        description = "Builds, signs, and submits a Coin transfer from the server's own account in one call, returning the transaction hash. Amount is in atoms; an optional memo (UTF-8, at most 256 bytes) is recorded with the transfer. Only available when the server is configured with a signing key: anyone who can reach this server can spend from that account, so use it only for trusted automation and fund the account accordingly. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn sign_and_submit_transfer(
+        &self,
+        params: Parameters<SignAndSubmitTransferRequest>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let req = params.0;
+        req.validate().map_err(Self::validation_error)?;
+
+        let key = self
+            .signing_key
+            .as_ref()
+            .ok_or_else(|| McpError::invalid_request("signing_key_not_configured", None))?;
+
+        let url = match req.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let receiver = tx::Address::from_b58(&req.receiver).map_err(|e| {
+            McpError::invalid_params(
+                "invalid_receiver",
+                Some(serde_json::json!({ "receiver": req.receiver, "message": e })),
+            )
+        })?;
+        let built = tx::build_with_params(
+            key,
+            "Coin",
+            "transfer",
+            &tx::transfer_args(&receiver, &req.symbol, req.amount, req.memo.as_deref()),
+            None,
+            None,
+            &self.signing_params,
+        )
+        .map_err(|e| {
+            Self::blockchain_error(
+                "sign_and_submit_transfer",
+                BlockchainError::ValidationFailed(e.into()),
+            )
+        })?;
+
+        let result = self
+            .blockchain
+            .submit_packed_transaction(&built.packed, &built.hash, url)
+            .await
+            .map_err(|e| Self::blockchain_error("sign_and_submit_transfer", e))?;
+
+        if result.error == "ok" {
             Ok(Json(serde_json::json!({
                 "status": "success",
-                "message": "Transaction submitted successfully",
-                "tx_hash": response.tx_hash
+                "message": "Transaction signed and submitted successfully",
+                "tx_hash": result.tx_hash,
+                "duplicate": result.duplicate
             })))
         } else {
             Err(McpError::internal_error(
                 "submission_failed",
-                Some(serde_json::json!({ "error": response.error })),
+                Some(serde_json::json!({ "error": result.error })),
             ))
         }
     }
 
     #[tool(
-        name = "get_account_balance",
-        description = "Queries the balance of an account across all supported assets. Optional network parameter: 'mainnet' (default) or 'testnet'."
+        name = "create_mint",
+        description = "Creates an unsigned Coin mint transaction for the given asset symbol and amount (in atoms). Minting is privileged: only the asset's authorized signer can successfully submit the result. Returns transaction blob that only needs signing."
     )]
-    async fn get_account_balance(
+    async fn create_mint(
         &self,
-        params: Parameters<AccountQuery>,
+        params: Parameters<MintRequest>,
     ) -> Result<Json<serde_json::Value>, McpError> {
-        let query = params.0;
-        query.validate().map_err(|e| {
-            McpError::invalid_params(
-                "validation_failed",
-                Some(serde_json::json!({ "errors": e })),
-            )
-        })?;
+        let req = params.0;
+        req.validate().map_err(Self::validation_error)?;
 
-        let url = match query.network.as_deref() {
-            Some("testnet") => &self.testnet_url,
-            _ => &self.mainnet_url,
-        };
+        let blob = self
+            .blockchain
+            .create_transaction_blob(req.into())
+            .await
+            .map_err(|e| Self::blockchain_error("create_mint", e))?;
 
-        let balance = self
+        Ok(Json(serde_json::json!({
+            "blob": blob.blob,
+            "signing_payload": blob.signing_payload,
+            "transaction_hash": blob.transaction_hash,
+            "status": "unsigned",
+            "next_step": "Sign the signing_payload with BLS12-381 and call submit_transaction"
+        })))
+    }
+
+    #[tool(
+        name = "create_contract_deploy",
+        description = "Creates an unsigned transaction deploying WASM contract bytecode (hex) to the signer's account, with optional constructor args in the same formats as create_transaction. Deployment is a call to the built-in Contract.deploy function. Returns transaction blob that only needs signing."
+    )]
+    async fn create_contract_deploy(
+        &self,
+        params: Parameters<DeployContractRequest>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let req = params.0;
+        req.validate().map_err(Self::validation_error)?;
+
+        let blob = self
             .blockchain
-            .get_account_balance(&query.address, url)
+            .create_transaction_blob(req.into())
             .await
-            .map_err(|e| Self::blockchain_error("get_account_balance", e))?;
+            .map_err(|e| Self::blockchain_error("create_contract_deploy", e))?;
 
-        Self::to_json(balance)
+        Ok(Json(serde_json::json!({
+            "blob": blob.blob,
+            "signing_payload": blob.signing_payload,
+            "transaction_hash": blob.transaction_hash,
+            "status": "unsigned",
+            "next_step": "Sign the signing_payload with BLS12-381 and call submit_transaction"
+        })))
     }
 
     #[tool(
-        name = "get_chain_stats",
-        description = "Retrieves current blockchain statistics including height, total transactions, and total accounts. Optional network parameter: 'mainnet' (default) or 'testnet'."
+        name = "create_stake",
+        description = "Creates an unsigned transaction delegating stake (amount in atoms of symbol) to a validator, identified by its base58 public key. Set preflight_balance_check to true to check the signer's balance before building. Optional network parameter: 'mainnet' (default) or 'testnet'. Returns transaction blob that only needs signing."
     )]
-    async fn get_chain_stats(
+    async fn create_stake(
         &self,
-        params: Parameters<ChainStatsQuery>,
+        params: Parameters<StakeRequest>,
     ) -> Result<Json<serde_json::Value>, McpError> {
-        let query = params.0;
-        query.validate().map_err(|e| {
-            McpError::invalid_params(
-                "validation_failed",
-                Some(serde_json::json!({ "errors": e })),
-            )
-        })?;
+        let req = params.0;
+        req.validate().map_err(Self::validation_error)?;
 
-        let url = match query.network.as_deref() {
-            Some("testnet") => &self.testnet_url,
-            _ => &self.mainnet_url,
-        };
+        let req = req.into_stake_request();
+        if req.preflight_balance_check.unwrap_or(false) {
+            let url = match req.network.as_deref() {
+                Some("testnet") => &self.testnet_url,
+                _ => &self.mainnet_url,
+            };
+            self.blockchain
+                .check_spendable_balance(&req, url)
+                .await
+                .map_err(|e| Self::blockchain_error("create_stake", e))?;
+        }
 
-        let stats = self
+        let blob = self
             .blockchain
-            .get_chain_stats(url)
+            .create_transaction_blob(req)
             .await
-            .map_err(|e| Self::blockchain_error("get_chain_stats", e))?;
+            .map_err(|e| Self::blockchain_error("create_stake", e))?;
 
-        Self::to_json(stats)
+        Ok(Json(serde_json::json!({
+            "blob": blob.blob,
+            "signing_payload": blob.signing_payload,
+            "transaction_hash": blob.transaction_hash,
+            "status": "unsigned",
+            "next_step": "Sign the signing_payload with BLS12-381 and call submit_transaction"
+        })))
     }
 
     #[tool(
-        name = "get_block_by_height",
-        description = "Retrieves blockchain entries at a specific height. Returns all entries for that height. Optional network parameter: 'mainnet' (default) or 'testnet'."
+        name = "create_unstake",
+        description = "Creates an unsigned transaction withdrawing delegated stake (amount in atoms of symbol) from a validator, identified by its base58 public key. Returns transaction blob that only needs signing."
     )]
-    async fn get_block_by_height(
+    async fn create_unstake(
         &self,
-        params: Parameters<HeightQuery>,
+        params: Parameters<StakeRequest>,
     ) -> Result<Json<serde_json::Value>, McpError> {
-        let query = params.0;
-        query.validate().map_err(|e| {
-            McpError::invalid_params(
-                "validation_failed",
-                Some(serde_json::json!({ "errors": e })),
-            )
-        })?;
+        let req = params.0;
+        req.validate().map_err(Self::validation_error)?;
 
-        let url = match query.network.as_deref() {
+        let req = req.into_unstake_request();
+
+        let blob = self
+            .blockchain
+            .create_transaction_blob(req)
+            .await
+            .map_err(|e| Self::blockchain_error("create_unstake", e))?;
+
+        Ok(Json(serde_json::json!({
+            "blob": blob.blob,
+            "signing_payload": blob.signing_payload,
+            "transaction_hash": blob.transaction_hash,
+            "status": "unsigned",
+            "next_step": "Sign the signing_payload with BLS12-381 and call submit_transaction"
+        })))
+    }
+
+    #[tool(
+        name = "compute_transaction_hash",
+        description = "Computes the id a transaction will have on chain, offline, from an unsigned blob or a signed base58 envelope. The id is the full SHA-256 of the encoded inner transaction, returned as base58 (hash, the form get_transaction expects) and hex (hash_hex)."
+    )]
+    async fn compute_transaction_hash(
+        &self,
+        params: Parameters<DecodeTransactionRequest>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let req = params.0;
+        req.validate().map_err(Self::validation_error)?;
+
+        let hash = self
+            .blockchain
+            .compute_transaction_hash(&req.transaction)
+            .map_err(|e| Self::blockchain_error("compute_transaction_hash", e))?;
+
+        Self::to_json(hash)
+    }
+
+    #[tool(
+        name = "verify_transaction_blob",
+        description = "Checks a signed base58 transaction envelope offline before submitting: recomputes the hash of the inner transaction, compares it with the stored hash, and verifies the signature against the embedded signer. Returns signer, hash, computed_hash, hash_matches, and signature_valid."
+    )]
+    async fn verify_transaction_blob(
+        &self,
+        params: Parameters<DecodeTransactionRequest>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let req = params.0;
+        req.validate().map_err(Self::validation_error)?;
+
+        let report = self
+            .blockchain
+            .verify_transaction_blob(&req.transaction)
+            .map_err(|e| Self::blockchain_error("verify_transaction_blob", e))?;
+
+        Self::to_json(report)
+    }
+
+    #[tool(
+        name = "decode_transaction",
+        description = "Decodes a base58 transaction blob (unsigned, as returned by create_transaction, or signed) into its signer, nonce, contract, function, and arguments. Arguments are shown as hex and, when valid UTF-8, as strings."
+    )]
+    async fn decode_transaction(
+        &self,
+        params: Parameters<DecodeTransactionRequest>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let req = params.0;
+        req.validate().map_err(Self::validation_error)?;
+
+        let decoded = self
+            .blockchain
+            .decode_transaction(&req.transaction)
+            .map_err(|e| Self::blockchain_error("decode_transaction", e))?;
+
+        Self::to_json(decoded)
+    }
+
+    #[tool(
+        name = "submit_transaction",
+        description = "Submits a signed transaction to the blockchain network. Requires the transaction blob and signature from the signing process. Set check_signature to verify the signature against the transaction's signer and signing payload first, failing with validation_failed on a mismatch (e.g. the wrong bytes signed or the wrong DST). Set dry_run to have the node simulate it instead and report success, fee, and state changes without broadcasting (fails with unsupported on nodes that cannot simulate). Resubmitting a transaction the node already accepted within the last 5 minutes returns the original result with duplicate: true instead of broadcasting it again. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn submit_transaction(
+        &self,
+        params: Parameters<SignedTransaction>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let tx = params.0;
+        tx.validate().map_err(Self::validation_error)?;
+
+        let url = match tx.network.as_deref() {
             Some("testnet") => &self.testnet_url,
             _ => &self.mainnet_url,
         };
 
-        let entries = self
+        if tx.check_signature.unwrap_or(false) {
+            check_transaction_signature(&tx, &self.signing_params)
+                .map_err(|e| Self::blockchain_error("submit_transaction", e))?;
+        }
+
+        if tx.dry_run.unwrap_or(false) {
+            let simulation = self
+                .blockchain
+                .simulate_signed_transaction(&tx, url)
+                .await
+                .map_err(|e| Self::blockchain_error("submit_transaction", e))?;
+            return Self::to_json(simulation);
+        }
+
+        let response = self
             .blockchain
-            .get_block_by_height(query.height, url)
+            .submit_signed_transaction(tx, url)
             .await
-            .map_err(|e| Self::blockchain_error("get_block_by_height", e))?;
+            .map_err(|e| Self::blockchain_error("submit_transaction", e))?;
 
-        Self::to_json(entries)
+        if response.error == "ok" {
+            Ok(Json(serde_json::json!({
+                "status": "success",
+                "message": "Transaction submitted successfully",
+                "tx_hash": response.tx_hash,
+                "duplicate": response.duplicate
+            })))
+        } else {
+            Err(McpError::internal_error(
+                "submission_failed",
+                Some(serde_json::json!({ "error": response.error })),
+            ))
+        }
     }
 
     #[tool(
-        name = "get_transaction",
-        description = "Retrieves a specific transaction by its hash. Returns detailed transaction information. Optional network parameter: 'mainnet' (default) or 'testnet'."
+        name = "submit_raw_transaction",
+        description = "Broadcasts a transaction that was built, signed, and packed entirely off-platform, given as one base58 blob. Use submit_transaction instead when you have the unsigned blob from create_transaction plus a separate signature. Optional network parameter: 'mainnet' (default) or 'testnet'."
     )]
-    async fn get_transaction(
+    async fn submit_raw_transaction(
         &self,
-        params: Parameters<TransactionQuery>,
+        params: Parameters<RawTransaction>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let tx = params.0;
+        tx.validate().map_err(Self::validation_error)?;
+
+        let url = match tx.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let response = self
+            .blockchain
+            .submit_raw_transaction(&tx.transaction, url)
+            .await
+            .map_err(|e| Self::blockchain_error("submit_raw_transaction", e))?;
+
+        if response.error == "ok" {
+            Ok(Json(serde_json::json!({
+                "status": "success",
+                "message": "Transaction submitted successfully",
+                "tx_hash": response.tx_hash,
+                "duplicate": response.duplicate
+            })))
+        } else {
+            Err(McpError::internal_error(
+                "submission_failed",
+                Some(serde_json::json!({ "error": response.error })),
+            ))
+        }
+    }
+
+    #[tool(
+        name = "verify_signature",
+        description = "Verifies a BLS12-381 signature over a transaction signing payload. Takes the base58 signer public key, the hex signing_payload from create_transaction, and the base58 signature. Useful as a sanity check before submit_transaction."
+    )]
+    async fn verify_signature(
+        &self,
+        params: Parameters<VerifySignatureRequest>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let req = params.0;
+        req.validate().map_err(Self::validation_error)?;
+
+        let valid = self
+            .blockchain
+            .verify_signature(&req)
+            .map_err(|e| Self::blockchain_error("verify_signature", e))?;
+
+        Ok(Json(serde_json::json!({
+            "public_key": req.public_key,
+            "valid": valid
+        })))
+    }
+
+    #[tool(
+        name = "get_account_balance",
+        description = "Queries the balance of an account across all supported assets. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn get_account_balance(
+        &self,
+        params: Parameters<AccountQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let balance = self
+            .blockchain
+            .get_account_balance(&query.address, url)
+            .await
+            .map_err(|e| Self::blockchain_error("get_account_balance", e))?;
+
+        Self::to_json(balance)
+    }
+
+    #[tool(
+        name = "get_balance_at_height",
+        description = "Queries an account's balances across all assets as of a past block height, for point-in-time reconciliation. The height must not be above the chain tip. Fails with unsupported on nodes that cannot serve historical state. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn get_balance_at_height(
+        &self,
+        params: Parameters<BalanceAtHeightQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let balance = self
+            .blockchain
+            .get_balance_at_height(&query.address, query.height, url)
+            .await
+            .map_err(|e| Self::blockchain_error("get_balance_at_height", e))?;
+
+        Self::to_json(balance)
+    }
+
+    #[tool(
+        name = "get_balance_for_asset",
+        description = "Returns one account's balance of a single asset in atoms, or zero when it holds none. Cheaper than get_account_balance when only one symbol matters. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn get_balance_for_asset(
+        &self,
+        params: Parameters<AccountAssetQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let balance = self
+            .blockchain
+            .get_balance_for_symbol(&query.address, &query.symbol, url)
+            .await
+            .map_err(|e| Self::blockchain_error("get_balance_for_asset", e))?;
+
+        Self::to_json(balance)
+    }
+
+    #[tool(
+        name = "get_balances",
+        description = "Queries the balances of up to 50 accounts at once, fetched concurrently. Returns a map from address to either {\"balances\": [...]} or {\"error\": ...}; one failing address does not fail the others. Duplicate addresses are looked up once. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn get_balances(
+        &self,
+        params: Parameters<BalancesQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let balances = self
+            .blockchain
+            .get_balances(&query.addresses, url)
+            .await
+            .map_err(|e| Self::blockchain_error("get_balances", e))?;
+
+        Self::to_json(balances)
+    }
+
+    #[tool(
+        name = "get_account_summary",
+        description = "Returns an account's balances, nonce, and most recent transactions (10 by default, set recent for up to 100) in one call. Parts that cannot be fetched are omitted and explained under errors; the call fails only if every part fails. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn get_account_summary(
+        &self,
+        params: Parameters<AccountSummaryQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let summary = self
+            .blockchain
+            .get_account_summary(
+                &query.address,
+                query.recent.unwrap_or(DEFAULT_SUMMARY_RECENT),
+                url,
+            )
+            .await
+            .map_err(|e| Self::blockchain_error("get_account_summary", e))?;
+
+        Self::to_json(summary)
+    }
+
+    #[tool(
+        name = "list_assets",
+        description = "Lists all assets on the chain with their symbol, decimals, and total supply. Use decimals to convert raw integer amounts (atoms) into whole units. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn list_assets(
+        &self,
+        params: Parameters<ListAssetsQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let assets = self
+            .blockchain
+            .list_assets(url)
+            .await
+            .map_err(|e| Self::blockchain_error("list_assets", e))?;
+
+        Ok(Json(serde_json::json!({
+            "assets": assets,
+            "count": assets.len()
+        })))
+    }
+
+    #[tool(
+        name = "get_asset_info",
+        description = "Retrieves the symbol, decimals, and total supply of a single asset. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn get_asset_info(
+        &self,
+        params: Parameters<AssetQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let asset = self
+            .blockchain
+            .get_asset_info(&query.symbol, url)
+            .await
+            .map_err(|e| Self::blockchain_error("get_asset_info", e))?;
+
+        Self::to_json(asset)
+    }
+
+    #[tool(
+        name = "get_supply",
+        description = "Retrieves the total and circulating supply of an asset in atoms, plus burned and locked amounts when the node reports them. Answers \"how much AMA exists\". Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn get_supply(
+        &self,
+        params: Parameters<AssetQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let supply = self
+            .blockchain
+            .get_supply(&query.symbol, url)
+            .await
+            .map_err(|e| Self::blockchain_error("get_supply", e))?;
+
+        Self::to_json(supply)
+    }
+
+    #[tool(
+        name = "get_top_holders",
+        description = "Lists the largest holders of an asset, ranked from 1 by balance in atoms, as {rank, address, balance} entries. limit is 1-100 (default 20). Nodes publish a richlist for AMA only, so other symbols fail with unsupported, as do nodes without a richlist. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn get_top_holders(
+        &self,
+        params: Parameters<TopHoldersQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let holders = self
+            .blockchain
+            .get_top_holders(
+                &query.symbol,
+                query.limit.unwrap_or(DEFAULT_TOP_HOLDERS),
+                url,
+            )
+            .await
+            .map_err(|e| Self::blockchain_error("get_top_holders", e))?;
+
+        Self::to_json(holders)
+    }
+
+    #[tool(
+        name = "ping",
+        description = "Checks that the MCP server is alive without contacting the blockchain. Returns the server version, the configured mainnet and testnet node hosts, and the result of the last node health check (null if none ran)."
+    )]
+    async fn ping(&self) -> Result<Json<serde_json::Value>, McpError> {
+        Self::to_json(ServerInfo::new(
+            &self.mainnet_url,
+            &self.testnet_url,
+            self.last_health.clone(),
+        ))
+    }
+
+    #[tool(
+        name = "get_metrics",
+        description = "Returns per-tool call counts since the server started: invocations, successes, failures, and total and average latency in milliseconds. Only tools that have been called are listed."
+    )]
+    async fn get_metrics(&self) -> Result<Json<serde_json::Value>, McpError> {
+        let mut tools: Vec<ToolMetrics> = self
+            .metrics
+            .iter()
+            .map(|(name, counters)| counters.snapshot(name))
+            .filter(|m| m.invocations > 0)
+            .collect();
+        tools.sort_by(|a, b| a.tool.cmp(&b.tool));
+        Ok(Json(serde_json::json!({ "tools": tools })))
+    }
+
+    #[tool(
+        name = "get_chain_stats",
+        description = "Retrieves current blockchain statistics including height, total transactions, and total accounts. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn get_chain_stats(
+        &self,
+        params: Parameters<ChainStatsQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let stats = self
+            .blockchain
+            .get_chain_stats(url)
+            .await
+            .map_err(|e| Self::blockchain_error("get_chain_stats", e))?;
+
+        Self::to_json(stats)
+    }
+
+    #[tool(
+        name = "get_fee_schedule",
+        description = "Returns how the network prices transactions, in atoms: base_fee per transaction, per_byte of the signed envelope, and per_exec_unit of execution (a receipt's exec_used), plus any other rates the node reports. Lets fees for many hypothetical transactions be computed locally; for one concrete transaction, submit_transaction with dry_run reports the exact fee. Fails with unsupported on nodes without a fee schedule. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn get_fee_schedule(
+        &self,
+        params: Parameters<FeeScheduleQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let schedule = self
+            .blockchain
+            .get_fee_schedule(url)
+            .await
+            .map_err(|e| Self::blockchain_error("get_fee_schedule", e))?;
+
+        Self::to_json(schedule)
+    }
+
+    #[tool(
+        name = "network_info",
+        description = "Identifies the chain a node serves: chain_id, protocol_version (consensus), genesis_hash, and node_version (node software). Check this before signing or submitting against an unfamiliar node to avoid cross-network mistakes such as signing with another network's DST. The result is cached per node. Fails with unsupported on nodes that do not expose it. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn network_info(
+        &self,
+        params: Parameters<NetworkInfoQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let info = self
+            .blockchain
+            .get_network_info(url)
+            .await
+            .map_err(|e| Self::blockchain_error("network_info", e))?;
+
+        Self::to_json(info)
+    }
+
+    #[tool(
+        name = "get_block_by_height",
+        description = "Retrieves blockchain entries at a specific height. Returns all entries for that height. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn get_block_by_height(
+        &self,
+        params: Parameters<HeightQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let entries = self
+            .blockchain
+            .get_block_by_height(query.height, url)
+            .await
+            .map_err(|e| Self::blockchain_error("get_block_by_height", e))?;
+
+        Self::to_json(entries)
+    }
+
+    #[tool(
+        name = "get_block_by_hash",
+        description = "Retrieves blockchain entries by entry hash (base58, or 64 hex characters), in the same shape as get_block_by_height. Useful when only a hash from a transaction record is known. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn get_block_by_hash(
+        &self,
+        params: Parameters<BlockHashQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let entries = self
+            .blockchain
+            .get_block_by_hash(&query.hash, url)
+            .await
+            .map_err(|e| Self::blockchain_error("get_block_by_hash", e))?;
+
+        Self::to_json(entries)
+    }
+
+    #[tool(
+        name = "get_block_range",
+        description = "Retrieves blockchain entries for every height in the inclusive range from_height..=to_height, ordered by height. The range span is capped (100 blocks by default). Heights that fail are listed under failed_heights with their error while the rest are still returned; set strict to fail the whole call instead. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn get_block_range(
+        &self,
+        params: Parameters<BlockRangeQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let blocks = self
+            .blockchain
+            .get_block_range(
+                query.from_height,
+                query.to_height,
+                query.strict.unwrap_or(false),
+                url,
+            )
+            .await
+            .map_err(|e| Self::blockchain_error("get_block_range", e))?;
+
+        Self::to_json(blocks)
+    }
+
+    #[tool(
+        name = "get_latest_block",
+        description = "Retrieves the newest block: the current chain height together with all entries at that height. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn get_latest_block(
+        &self,
+        params: Parameters<LatestBlockQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let block = self
+            .blockchain
+            .get_latest_block(url)
+            .await
+            .map_err(|e| Self::blockchain_error("get_latest_block", e))?;
+
+        Self::to_json(block)
+    }
+
+    #[tool(
+        name = "get_transaction",
+        description = "Retrieves a specific transaction by its hash. Returns detailed transaction information. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn get_transaction(
+        &self,
+        params: Parameters<TransactionQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let transaction = self
+            .blockchain
+            .get_transaction(&query.tx_hash, url)
+            .await
+            .map_err(|e| Self::blockchain_error("get_transaction", e))?;
+
+        Self::to_json(transaction)
+    }
+
+    #[tool(
+        name = "get_transaction_by_position",
+        description = "Retrieves a transaction by its position instead of its hash: the zero-based index among the transactions at a block height, counted across that height's entries in order. Returns the transaction with tx_count, the number of transactions at that height, for walking blocks sequentially. An index past the end fails with validation_failed. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn get_transaction_by_position(
+        &self,
+        params: Parameters<TransactionPositionQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let transaction = self
+            .blockchain
+            .get_transaction_by_position(query.height, query.index, url)
+            .await
+            .map_err(|e| Self::blockchain_error("get_transaction_by_position", e))?;
+
+        Self::to_json(transaction)
+    }
+
+    #[tool(
+        name = "get_transaction_status",
+        description = "Returns a lightweight status for a transaction hash: pending (not yet included, may still be in the mempool), confirmed (with height), failed (with height and error), or unknown. Suited to polling after submit_transaction. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn get_transaction_status(
+        &self,
+        params: Parameters<TransactionQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let status = self
+            .blockchain
+            .get_transaction_status(&query.tx_hash, url)
+            .await
+            .map_err(|e| Self::blockchain_error("get_transaction_status", e))?;
+
+        Self::to_json(status)
+    }
+
+    #[tool(
+        name = "wait_for_transaction",
+        description = "Waits until a transaction is no longer pending and returns its final status (confirmed, failed, or unknown) with the number of polls made. Polls start quickly and back off up to a cap, so fast blocks are seen promptly without flooding the node during slow ones. Gives up after timeout_secs (60 by default, at most 120) and returns the last status with timed_out: true. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn wait_for_transaction(
+        &self,
+        params: Parameters<WaitForTransactionQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+        let timeout = Duration::from_secs(query.timeout_secs.unwrap_or(DEFAULT_WAIT_TIMEOUT_SECS));
+
+        let wait = self
+            .blockchain
+            .wait_for_transaction(&query.tx_hash, timeout, url)
+            .await
+            .map_err(|e| Self::blockchain_error("wait_for_transaction", e))?;
+
+        Self::to_json(wait)
+    }
+
+    #[tool(
+        name = "wait_for_next_block",
+        description = "Waits until the chain tip is above after_height and returns the new height with the number of polls made. Pass the height of the last block seen to react to the next one without polling get_chain_stats yourself. Nodes offer no block subscription, so the tip is polled with the same backoff as wait_for_transaction. Gives up after timeout_secs (60 by default, at most 120) and returns the last height with timed_out: true. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn wait_for_next_block(
+        &self,
+        params: Parameters<WaitForBlockQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+        let timeout = Duration::from_secs(query.timeout_secs.unwrap_or(DEFAULT_WAIT_TIMEOUT_SECS));
+
+        let wait = self
+            .blockchain
+            .wait_for_next_block(query.after_height, timeout, url)
+            .await
+            .map_err(|e| Self::blockchain_error("wait_for_next_block", e))?;
+
+        Self::to_json(wait)
+    }
+
+    #[tool(
+        name = "get_transaction_receipt",
+        description = "Returns the execution result of an included transaction: success, failure reason, return value, execution cost (exec_used), and the contract events it emitted (empty when none). Use after a contract call to read back its outcome; a transaction not yet included fails with transaction_not_found. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn get_transaction_receipt(
+        &self,
+        params: Parameters<TransactionQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let receipt = self
+            .blockchain
+            .get_transaction_receipt(&query.tx_hash, url)
+            .await
+            .map_err(|e| Self::blockchain_error("get_transaction_receipt", e))?;
+
+        Self::to_json(receipt)
+    }
+
+    #[tool(
+        name = "get_pending_transactions",
+        description = "Lists unconfirmed transactions in the node's mempool, optionally only those signed by a given address. Helps tell a still-pending transaction from a dropped one. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn get_pending_transactions(
+        &self,
+        params: Parameters<PendingTransactionsQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let transactions = self
+            .blockchain
+            .get_pending_transactions(query.address.as_deref(), url)
+            .await
+            .map_err(|e| Self::blockchain_error("get_pending_transactions", e))?;
+
+        Ok(Json(serde_json::json!({
+            "transactions": transactions,
+            "count": transactions.len()
+        })))
+    }
+
+    #[tool(
+        name = "get_transaction_history",
+        description = "Retrieves transaction history for a specific account. Supports pagination with limit, offset, and sort parameters; the response carries the transactions with count, limit, offset, has_more, and next_offset. Set fetch_all to follow pages automatically, with limit as the overall cap (1000 by default). Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn get_transaction_history(
+        &self,
+        params: Parameters<TransactionHistoryQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let page = if query.fetch_all.unwrap_or(false) {
+            let cap = query.limit.map_or(DEFAULT_HISTORY_CAP, |l| l as usize);
+            self.blockchain
+                .get_transaction_history_all(&query.address, cap, query.sort.as_deref(), url)
+                .await
+                .map(|txs| TransactionHistoryPage::new(txs, Some(cap as u32), None))
+        } else {
+            self.blockchain
+                .get_transaction_history(
+                    &query.address,
+                    query.limit,
+                    query.offset,
+                    query.sort.as_deref(),
+                    url,
+                )
+                .await
+        }
+        .map_err(|e| Self::blockchain_error("get_transaction_history", e))?;
+
+        Self::to_json(page)
+    }
+
+    #[tool(
+        name = "get_account_transactions_since",
+        description = "Returns an account's transactions at or above min_height, oldest first, for incremental sync. Pass the returned next_min_height as min_height on the next call; the watermark is inclusive, so deduplicate by hash. complete is false if the scan stopped at its page cap before reaching older history. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn get_account_transactions_since(
+        &self,
+        params: Parameters<TransactionsSinceQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let since = self
+            .blockchain
+            .get_transactions_since(&query.address, query.min_height, url)
+            .await
+            .map_err(|e| Self::blockchain_error("get_account_transactions_since", e))?;
+
+        Self::to_json(since)
+    }
+
+    #[tool(
+        name = "search_transactions",
+        description = "Searches transactions by height range, asset symbol, minimum transfer amount (atoms), and counterparty address, with limit/offset pagination. At least one filter is required. Filtering happens in the server: with a counterparty it scans that account's recent history (up to 1000 transactions); otherwise from_height and to_height are required and the range is capped at the max block range, costing one node request per block. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn search_transactions(
+        &self,
+        params: Parameters<SearchTransactionsQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let result = self
+            .blockchain
+            .search_transactions(&query, url)
+            .await
+            .map_err(|e| Self::blockchain_error("search_transactions", e))?;
+
+        Self::to_json(result)
+    }
+
+    #[tool(
+        name = "get_validators",
+        description = "Retrieves the list of current validator nodes (trainers) in the network. Set detailed=true to include stake, score, and operator for each validator. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn get_validators(
+        &self,
+        params: Parameters<ValidatorsQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        if query.detailed.unwrap_or(false) {
+            let validators = self
+                .blockchain
+                .get_validators_detailed(url)
+                .await
+                .map_err(|e| Self::blockchain_error("get_validators", e))?;
+
+            return Ok(Json(serde_json::json!({
+                "validators": validators,
+                "count": validators.len()
+            })));
+        }
+
+        let validators = self
+            .blockchain
+            .get_validators(url)
+            .await
+            .map_err(|e| Self::blockchain_error("get_validators", e))?;
+
+        Ok(Json(serde_json::json!({
+            "validators": validators,
+            "count": validators.len()
+        })))
+    }
+
+    #[tool(
+        name = "get_validator_details",
+        description = "Retrieves stake amount, score, uptime, and operator account for a single validator by public key. Useful when choosing where to delegate stake. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn get_validator_details(
+        &self,
+        params: Parameters<ValidatorDetailsQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let details = self
+            .blockchain
+            .get_validator_details(&query.validator, url)
+            .await
+            .map_err(|e| Self::blockchain_error("get_validator_details", e))?;
+
+        Self::to_json(details)
+    }
+
+    #[tool(
+        name = "get_contract_state",
+        description = "Retrieves a specific value from smart contract storage by contract address and key. Set decode_as to i128, string, bytes_hex, or json to convert the raw value (fails with a validation error if it does not fit); by default the value is returned as the node sent it. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn get_contract_state(
+        &self,
+        params: Parameters<ContractStateQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let mut state = self
+            .blockchain
+            .get_contract_state(&query.contract_address, &query.key, url)
+            .await
+            .map_err(|e| Self::blockchain_error("get_contract_state", e))?;
+        if let Some(decoding) = query.decode_as {
+            state = decode_state_value(&state, decoding)
+                .map_err(|e| Self::blockchain_error("get_contract_state", e))?;
+        }
+
+        Ok(Json(serde_json::json!({
+            "contract_address": query.contract_address,
+            "key": query.key,
+            "value": state
+        })))
+    }
+
+    #[tool(
+        name = "simulate_contract_call",
+        description = "Executes a read-only contract function and returns its result without building or signing a transaction, like eth_call. Args use the same formats as create_transaction; set caller for functions that read it and decode_as (i128, string, bytes_hex, json) to convert the result. A function that reverts returns success: false with the revert reason under error. Unlike get_contract_state, which reads one storage key, this runs contract code. Fails with unsupported on nodes without a view endpoint. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn simulate_contract_call(
+        &self,
+        params: Parameters<ContractCallRequest>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let req = params.0;
+        req.validate().map_err(Self::validation_error)?;
+
+        let url = match req.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let call = self
+            .blockchain
+            .simulate_call(&req, url)
+            .await
+            .map_err(|e| Self::blockchain_error("simulate_contract_call", e))?;
+
+        Self::to_json(call)
+    }
+
+    #[tool(
+        name = "get_contract_states",
+        description = "Reads up to 50 storage keys of one smart contract at once, fetched concurrently. Returns a map from key to either {\"value\": ...} or {\"error\": ...}; one failing key does not fail the others. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn get_contract_states(
+        &self,
+        params: Parameters<ContractStatesQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let url = match query.network.as_deref() {
+            Some("testnet") => &self.testnet_url,
+            _ => &self.mainnet_url,
+        };
+
+        let states = self
+            .blockchain
+            .get_contract_states(&query.contract_address, &query.keys, url)
+            .await
+            .map_err(|e| Self::blockchain_error("get_contract_states", e))?;
+
+        Self::to_json(states)
+    }
+
+    #[tool(
+        name = "resolve_name",
+        description = "Resolves a human-readable account name (e.g. alice.ama) to its base58 address via the naming contract. Use the result as signer or receiver in transfers. Optional network parameter: 'mainnet' (default) or 'testnet'."
+    )]
+    async fn resolve_name(
+        &self,
+        params: Parameters<ResolveNameQuery>,
     ) -> Result<Json<serde_json::Value>, McpError> {
         let query = params.0;
-        query.validate().map_err(|e| {
-            McpError::invalid_params(
-                "validation_failed",
-                Some(serde_json::json!({ "errors": e })),
-            )
-        })?;
+        query.validate().map_err(Self::validation_error)?;
 
         let url = match query.network.as_deref() {
             Some("testnet") => &self.testnet_url,
             _ => &self.mainnet_url,
         };
 
-        let transaction = self
+        let address = self
             .blockchain
-            .get_transaction(&query.tx_hash, url)
+            .resolve_name(&query.name, url)
             .await
-            .map_err(|e| Self::blockchain_error("get_transaction", e))?;
+            .map_err(|e| Self::blockchain_error("resolve_name", e))?;
 
-        Self::to_json(transaction)
+        Ok(Json(serde_json::json!({
+            "name": query.name,
+            "address": address
+        })))
     }
 
     #[tool(
-        name = "get_transaction_history",
-        description = "Retrieves transaction history for a specific account. Supports pagination with limit, offset, and sort parameters. Optional network parameter: 'mainnet' (default) or 'testnet'."
+        name = "reverse_resolve",
+        description = "Looks up the human-readable name registered for a base58 account address. Optional network parameter: 'mainnet' (default) or 'testnet'."
     )]
-    async fn get_transaction_history(
+    async fn reverse_resolve(
         &self,
-        params: Parameters<TransactionHistoryQuery>,
+        params: Parameters<ReverseResolveQuery>,
     ) -> Result<Json<serde_json::Value>, McpError> {
         let query = params.0;
-        query.validate().map_err(|e| {
-            McpError::invalid_params(
-                "validation_failed",
-                Some(serde_json::json!({ "errors": e })),
-            )
-        })?;
+        query.validate().map_err(Self::validation_error)?;
 
         let url = match query.network.as_deref() {
             Some("testnet") => &self.testnet_url,
             _ => &self.mainnet_url,
         };
 
-        let transactions = self
+        let name = self
             .blockchain
-            .get_transaction_history(
-                &query.address,
-                query.limit,
-                query.offset,
-                query.sort.as_deref(),
-                url,
-            )
+            .reverse_resolve(&query.address, url)
             .await
-            .map_err(|e| Self::blockchain_error("get_transaction_history", e))?;
+            .map_err(|e| Self::blockchain_error("reverse_resolve", e))?;
 
-        Self::to_json(transactions)
+        Ok(Json(serde_json::json!({
+            "address": query.address,
+            "name": name
+        })))
     }
 
     #[tool(
-        name = "get_validators",
-        description = "Retrieves the list of current validator nodes (trainers) in the network. Optional network parameter: 'mainnet' (default) or 'testnet'."
+        name = "get_contract_code",
+        description = "Retrieves a deployed contract's code or code hash, owner, and callable functions when the node exposes them. Optional network parameter: 'mainnet' (default) or 'testnet'."
     )]
-    async fn get_validators(
+    async fn get_contract_code(
         &self,
-        params: Parameters<ValidatorsQuery>,
+        params: Parameters<ContractCodeQuery>,
     ) -> Result<Json<serde_json::Value>, McpError> {
         let query = params.0;
-        query.validate().map_err(|e| {
-            McpError::invalid_params(
-                "validation_failed",
-                Some(serde_json::json!({ "errors": e })),
-            )
-        })?;
+        query.validate().map_err(Self::validation_error)?;
 
         let url = match query.network.as_deref() {
             Some("testnet") => &self.testnet_url,
             _ => &self.mainnet_url,
         };
 
-        let validators = self
+        let info = self
             .blockchain
-            .get_validators(url)
+            .get_contract_code(&query.contract_address, url)
             .await
-            .map_err(|e| Self::blockchain_error("get_validators", e))?;
+            .map_err(|e| Self::blockchain_error("get_contract_code", e))?;
 
-        Ok(Json(serde_json::json!({
-            "validators": validators,
-            "count": validators.len()
-        })))
+        Self::to_json(info)
     }
 
     #[tool(
-        name = "get_contract_state",
-        description = "Retrieves a specific value from smart contract storage by contract address and key. Optional network parameter: 'mainnet' (default) or 'testnet'."
+        name = "list_contract_keys",
+        description = "Lists the storage keys of a smart contract, optionally filtered by key prefix. Paginate with limit and the returned next_cursor. Optional network parameter: 'mainnet' (default) or 'testnet'."
     )]
-    async fn get_contract_state(
+    async fn list_contract_keys(
         &self,
-        params: Parameters<ContractStateQuery>,
+        params: Parameters<ContractKeysQuery>,
     ) -> Result<Json<serde_json::Value>, McpError> {
         let query = params.0;
-        query.validate().map_err(|e| {
-            McpError::invalid_params(
-                "validation_failed",
-                Some(serde_json::json!({ "errors": e })),
-            )
-        })?;
+        query.validate().map_err(Self::validation_error)?;
 
         let url = match query.network.as_deref() {
             Some("testnet") => &self.testnet_url,
             _ => &self.mainnet_url,
         };
 
-        let state = self
+        let keys = self
             .blockchain
-            .get_contract_state(&query.contract_address, &query.key, url)
+            .list_contract_keys(&query, url)
             .await
-            .map_err(|e| Self::blockchain_error("get_contract_state", e))?;
+            .map_err(|e| Self::blockchain_error("list_contract_keys", e))?;
+
+        Self::to_json(keys)
+    }
+
+    #[tool(
+        name = "batch_query",
+        description = "Runs several read-only queries concurrently in one call. Each request names a tool (get_account_balance, get_balance_at_height, get_balance_for_asset, get_balances, get_account_summary, list_assets, get_asset_info, get_supply, get_top_holders, get_chain_stats, get_fee_schedule, network_info, get_block_by_height, get_block_by_hash, get_block_range, get_latest_block, get_transaction, get_transaction_by_position, get_transaction_status, get_transaction_receipt, get_pending_transactions, get_transaction_history, get_account_transactions_since, get_validators, get_validator_details, get_contract_state, get_contract_states, simulate_contract_call, get_contract_code, list_contract_keys) and its params. Results are returned in request order, each either {\"result\": ...} or {\"error\": ...}. At most 20 requests per batch. Optional network parameter applies to requests that do not set their own."
+    )]
+    async fn batch_query(
+        &self,
+        params: Parameters<BatchQuery>,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        let query = params.0;
+        query.validate().map_err(Self::validation_error)?;
+
+        let network = query.network;
+        let results: Vec<serde_json::Value> = stream::iter(query.requests)
+            .map(|req| {
+                let tool = req.tool;
+                let mut params = req.params;
+                if let (Some(network), Some(obj)) = (&network, params.as_object_mut()) {
+                    obj.entry("network")
+                        .or_insert_with(|| serde_json::Value::String(network.clone()));
+                }
+                async move {
+                    match self.dispatch_read(&tool, params).await {
+                        Ok(Json(value)) => serde_json::json!({ "result": value }),
+                        Err(e) => serde_json::json!({ "error": e }),
+                    }
+                }
+            })
+            .buffered(BATCH_CONCURRENCY)
+            .collect()
+            .await;
 
         Ok(Json(serde_json::json!({
-            "contract_address": query.contract_address,
-            "key": query.key,
-            "value": state
+            "results": results,
+            "count": results.len()
         })))
     }
 
@@ -537,17 +1988,230 @@ impl BlockchainMcpServer {
             },
             "mcp_tools_available": [
                 "create_transaction - Create unsigned transaction",
+                "create_transfer - Create unsigned transfer, amounts in atoms or whole units",
+                "create_mint - Create unsigned mint transaction (authorized signers only; only when enabled)",
+                "create_contract_deploy - Create unsigned contract deployment transaction",
+                "sign_and_submit_transfer - Sign and submit a transfer with the server key (only when configured)",
+                "create_stake - Create unsigned stake delegation to a validator",
+                "create_unstake - Create unsigned stake withdrawal from a validator",
+                "decode_transaction - Decode a transaction blob for inspection",
+                "compute_transaction_hash - Predict a transaction's id offline",
+                "verify_transaction_blob - Check a signed blob's hash and signature offline",
                 "submit_transaction - Submit signed transaction",
+                "submit_raw_transaction - Broadcast a fully signed blob built off-platform",
+                "verify_signature - Verify a transaction signature before submitting",
                 "get_account_balance - Query account balances",
+                "get_balance_at_height - Query account balances as of a past block",
+                "get_balance_for_asset - Query one account's balance of a single asset",
+                "get_balances - Query balances of up to 50 accounts at once",
+                "get_account_summary - Get balances, nonce, and recent transactions together",
+                "list_assets - List assets with decimals and supply",
+                "get_asset_info - Get decimals and supply of one asset",
+                "get_supply - Get total, circulating, burned, and locked supply of one asset",
+                "get_top_holders - List the largest holders of an asset",
+                "ping - Check the server is alive and which nodes it uses",
+                "get_metrics - Per-tool call counts, failures, and latency since startup",
                 "get_chain_stats - Get blockchain statistics",
+                "get_fee_schedule - Get base, per-byte, and per-execution fee rates",
+                "network_info - Get chain id, protocol version, genesis hash, and node version",
+                "get_latest_block - Get the newest block with its entries",
+                "get_block_by_hash - Get entries by entry hash",
+                "get_block_range - Get entries for a bounded range of heights",
                 "get_transaction - Get transaction by hash",
+                "get_transaction_by_position - Get transaction by block height and index",
+                "get_transaction_status - Poll pending/confirmed/failed status of a transaction",
+                "wait_for_transaction - Wait for a transaction to leave pending, with backoff",
+                "wait_for_next_block - Wait for the chain tip to pass a height, with backoff",
+                "get_transaction_receipt - Read a transaction's execution result and emitted events",
+                "get_pending_transactions - List unconfirmed mempool transactions",
                 "get_transaction_history - Get account history",
+                "get_account_transactions_since - Get account activity at or above a block height",
+                "search_transactions - Filter transactions by height, asset, amount, or counterparty",
                 "get_validators - List validators",
+                "get_validator_details - Get validator stake, score, and operator",
+                "get_contract_states - Read many storage keys of one contract at once",
+                "simulate_contract_call - Run a read-only contract function and get its result",
+                "get_contract_code - Get contract code hash, owner, and functions",
+                "resolve_name - Resolve an account name to its address",
+                "reverse_resolve - Look up the name registered for an address",
+                "list_contract_keys - List contract storage keys",
+                "batch_query - Run several read-only queries in one call",
                 "claim_testnet_ama - Claim testnet tokens"
             ]
         })))
     }
 
+    async fn dispatch_read(
+        &self,
+        tool: &str,
+        params: serde_json::Value,
+    ) -> Result<Json<serde_json::Value>, McpError> {
+        fn parse<T: serde::de::DeserializeOwned>(
+            params: serde_json::Value,
+        ) -> Result<Parameters<T>, McpError> {
+            let params = if params.is_null() {
+                serde_json::json!({})
+            } else {
+                params
+            };
+            serde_json::from_value(params).map(Parameters).map_err(|e| {
+                McpError::invalid_params(
+                    "invalid_params",
+                    Some(serde_json::json!({ "error": e.to_string() })),
+                )
+            })
+        }
+
+        match tool {
+            "get_account_balance" => self.get_account_balance(parse(params)?).await,
+            "get_balance_at_height" => self.get_balance_at_height(parse(params)?).await,
+            "get_balance_for_asset" => self.get_balance_for_asset(parse(params)?).await,
+            "get_balances" => self.get_balances(parse(params)?).await,
+            "get_account_summary" => self.get_account_summary(parse(params)?).await,
+            "list_assets" => self.list_assets(parse(params)?).await,
+            "get_asset_info" => self.get_asset_info(parse(params)?).await,
+            "get_supply" => self.get_supply(parse(params)?).await,
+            "get_top_holders" => self.get_top_holders(parse(params)?).await,
+            "get_chain_stats" => self.get_chain_stats(parse(params)?).await,
+            "get_fee_schedule" => self.get_fee_schedule(parse(params)?).await,
+            "network_info" => self.network_info(parse(params)?).await,
+            "get_block_by_height" => self.get_block_by_height(parse(params)?).await,
+            "get_block_by_hash" => self.get_block_by_hash(parse(params)?).await,
+            "get_block_range" => self.get_block_range(parse(params)?).await,
+            "get_latest_block" => self.get_latest_block(parse(params)?).await,
+            "get_transaction" => self.get_transaction(parse(params)?).await,
+            "get_transaction_by_position" => self.get_transaction_by_position(parse(params)?).await,
+            "get_transaction_status" => self.get_transaction_status(parse(params)?).await,
+            "get_transaction_receipt" => self.get_transaction_receipt(parse(params)?).await,
+            "get_pending_transactions" => self.get_pending_transactions(parse(params)?).await,
+            "get_transaction_history" => self.get_transaction_history(parse(params)?).await,
+            "get_account_transactions_since" => {
+                self.get_account_transactions_since(parse(params)?).await
+            }
+            "get_validators" => self.get_validators(parse(params)?).await,
+            "get_validator_details" => self.get_validator_details(parse(params)?).await,
+            "get_contract_state" => self.get_contract_state(parse(params)?).await,
+            "get_contract_states" => self.get_contract_states(parse(params)?).await,
+            "simulate_contract_call" => self.simulate_contract_call(parse(params)?).await,
+            "get_contract_code" => self.get_contract_code(parse(params)?).await,
+            "list_contract_keys" => self.list_contract_keys(parse(params)?).await,
+            _ => Err(McpError::invalid_params(
+                "unsupported_batch_tool",
+                Some(serde_json::json!({ "tool": tool })),
+            )),
+        }
+    }
+
+    fn prompts() -> Vec<Prompt> {
+        PROMPTS
+            .iter()
+            .map(|p| {
+                Prompt::new(
+                    p.name,
+                    Some(p.description),
+                    Some(
+                        p.arguments
+                            .iter()
+                            .map(|arg| PromptArgument {
+                                name: arg.name.to_string(),
+                                description: Some(arg.description.to_string()),
+                                required: Some(arg.default.is_none()),
+                            })
+                            .collect(),
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    fn render_prompt(name: &str, arguments: Option<&JsonObject>) -> Option<GetPromptResult> {
+        let prompt = PROMPTS.iter().find(|p| p.name == name)?;
+
+        let values: Vec<(String, String)> = prompt
+            .arguments
+            .iter()
+            .map(|arg| {
+                let value = arguments
+                    .and_then(|a| a.get(arg.name))
+                    .map(|v| match v {
+                        serde_json::Value::String(s) => s.clone(),
+                        other => other.to_string(),
+                    })
+                    .or_else(|| arg.default.map(str::to_string))
+                    .unwrap_or_else(|| format!("<{}>", arg.name));
+                (format!("{{{}}}", arg.name), value)
+            })
+            .collect();
+        let render = |template: &str| {
+            values
+                .iter()
+                .fold(template.to_string(), |text, (placeholder, value)| {
+                    text.replace(placeholder, value)
+                })
+        };
+
+        let messages = std::iter::once(prompt.template)
+            .chain(prompt.steps.iter().copied())
+            .map(|template| PromptMessage::new_text(PromptMessageRole::User, render(template)))
+            .collect();
+
+        Some(GetPromptResult {
+            description: Some(prompt.description.to_string()),
+            messages,
+        })
+    }
+
+    fn resources() -> Vec<Resource> {
+        RESOURCES
+            .iter()
+            .map(|(uri, name, description)| {
+                let mut resource = RawResource::new(*uri, name.to_string());
+                resource.description = Some(description.to_string());
+                resource.mime_type = Some("application/json".into());
+                resource.no_annotation()
+            })
+            .collect()
+    }
+
+    fn resource_templates() -> Vec<ResourceTemplate> {
+        RESOURCE_TEMPLATES
+            .iter()
+            .map(|(uri_template, name, description)| {
+                RawResourceTemplate {
+                    uri_template: uri_template.to_string(),
+                    name: name.to_string(),
+                    description: Some(description.to_string()),
+                    mime_type: Some("application/json".into()),
+                }
+                .no_annotation()
+            })
+            .collect()
+    }
+
+    fn resource_contents<T: serde::Serialize>(
+        uri: &str,
+        value: T,
+    ) -> Result<ReadResourceResult, McpError> {
+        let text = serde_json::to_string_pretty(&value).map_err(|e| {
+            McpError::internal_error(
+                "serialization_error",
+                Some(serde_json::json!({ "error": e.to_string() })),
+            )
+        })?;
+
+        Ok(ReadResourceResult {
+            contents: vec![ResourceContents::text(text, uri)],
+        })
+    }
+
+    /// Reports every failed check as `{field, code, message}` under `errors`.
+    fn validation_error(errors: validator::ValidationErrors) -> McpError {
+        McpError::invalid_params(
+            "validation_failed",
+            Some(serde_json::json!({ "errors": FieldError::from_errors(&errors) })),
+        )
+    }
+
     fn blockchain_error(tool: &str, error: BlockchainError) -> McpError {
         error!(%error, tool, "blockchain operation failed");
         match error {
@@ -555,6 +2219,22 @@ impl BlockchainMcpServer {
                 "account_not_found",
                 Some(serde_json::json!({ "address": address })),
             ),
+            BlockchainError::NotAContract { address } => McpError::resource_not_found(
+                "not_a_contract",
+                Some(serde_json::json!({ "address": address })),
+            ),
+            BlockchainError::BlockNotFound { hash } => McpError::resource_not_found(
+                "block_not_found",
+                Some(serde_json::json!({ "hash": hash })),
+            ),
+            BlockchainError::TransactionNotFound { hash } => McpError::resource_not_found(
+                "transaction_not_found",
+                Some(serde_json::json!({ "hash": hash })),
+            ),
+            BlockchainError::AssetNotFound { symbol } => McpError::resource_not_found(
+                "asset_not_found",
+                Some(serde_json::json!({ "symbol": symbol })),
+            ),
             BlockchainError::InsufficientBalance {
                 required,
                 available,
@@ -562,6 +2242,43 @@ impl BlockchainMcpServer {
                 "insufficient_balance",
                 Some(serde_json::json!({ "required": required, "available": available })),
             ),
+            BlockchainError::HttpStatus {
+                status,
+                path,
+                body_snippet,
+            } => McpError::internal_error(
+                "http_status_error",
+                Some(serde_json::json!({ "status": status, "path": path, "body": body_snippet })),
+            ),
+            BlockchainError::NodeError { status, message } if (400..500).contains(&status) => {
+                McpError::invalid_request(
+                    "node_error",
+                    Some(serde_json::json!({ "status": status, "message": message })),
+                )
+            }
+            BlockchainError::NodeError { status, message } => McpError::internal_error(
+                "node_error",
+                Some(serde_json::json!({ "status": status, "message": message })),
+            ),
+            BlockchainError::CircuitOpen { retry_after_secs } => McpError::internal_error(
+                "node_unavailable",
+                Some(serde_json::json!({ "retry_after_secs": retry_after_secs })),
+            ),
+            BlockchainError::UnexpectedContentType {
+                content_type,
+                body_snippet,
+            } => McpError::internal_error(
+                "unexpected_content_type",
+                Some(serde_json::json!({ "content_type": content_type, "body": body_snippet })),
+            ),
+            BlockchainError::ResponseTooLarge { limit } => McpError::internal_error(
+                "response_too_large",
+                Some(serde_json::json!({ "limit_bytes": limit })),
+            ),
+            BlockchainError::Unsupported { feature } => McpError::invalid_request(
+                "unsupported",
+                Some(serde_json::json!({ "feature": feature })),
+            ),
             BlockchainError::ValidationFailed(msg) => McpError::invalid_params(
                 "validation_failed",
                 Some(serde_json::json!({ "message": msg })),
@@ -573,6 +2290,19 @@ impl BlockchainMcpServer {
         }
     }
 
+    /// Runs a tool call until it finishes or `cancelled` resolves. Cancelling
+    /// drops the call future, and with it any pending node requests and poll
+    /// loops, so nothing keeps running for a client that has moved on.
+    async fn until_cancelled<T>(
+        call: impl Future<Output = Result<T, McpError>>,
+        cancelled: impl Future<Output = ()>,
+    ) -> Result<T, McpError> {
+        tokio::select! {
+            result = call => result,
+            _ = cancelled => Err(McpError::internal_error("request_cancelled", None)),
+        }
+    }
+
     fn to_json<T: serde::Serialize>(value: T) -> Result<Json<serde_json::Value>, McpError> {
         Ok(Json(serde_json::to_value(value).map_err(|e| {
             McpError::internal_error(
@@ -583,7 +2313,133 @@ impl BlockchainMcpServer {
     }
 }
 
-#[tool_handler]
+/// (uri, name, description)
+const RESOURCES: &[(&str, &str, &str)] = &[
+    (
+        "amadeus://chain/stats",
+        "Chain statistics",
+        "Current mainnet height, emission, burn, and throughput statistics",
+    ),
+    (
+        "amadeus://validators",
+        "Validators",
+        "Current mainnet validator nodes (trainers)",
+    ),
+];
+
+/// (uri_template, name, description)
+const RESOURCE_TEMPLATES: &[(&str, &str, &str)] = &[
+    (
+        "amadeus://account/{address}/balance",
+        "Account balance",
+        "All asset balances of a mainnet account",
+    ),
+    (
+        "amadeus://tx/{hash}",
+        "Transaction",
+        "A mainnet transaction by hash",
+    ),
+];
+
+struct PromptTemplate {
+    name: &'static str,
+    description: &'static str,
+    arguments: &'static [PromptArg],
+    template: &'static str,
+    /// Follow-up messages, one per step, rendered after the template
+    steps: &'static [&'static str],
+}
+
+struct PromptArg {
+    name: &'static str,
+    description: &'static str,
+    /// Substituted when the argument is optional and not provided
+    default: Option<&'static str>,
+}
+
+const PROMPTS: &[PromptTemplate] = &[
+    PromptTemplate {
+        name: "transfer_assets",
+        description: "Guides through building, signing, and submitting an asset transfer",
+        arguments: &[
+            PromptArg { name: "signer", description: "Sender public key (base58)", default: None },
+            PromptArg { name: "receiver", description: "Recipient address (base58)", default: None },
+            PromptArg { name: "amount", description: "Amount in atomic units (1 AMA = 1000000000)", default: None },
+            PromptArg { name: "symbol", description: "Asset symbol, e.g. AMA", default: Some("AMA") },
+            PromptArg { name: "network", description: "'mainnet' (default) or 'testnet'", default: Some("mainnet") },
+        ],
+        template: "Transfer {amount} atomic units of {symbol} from {signer} to {receiver} on {network}.\n\
+            1. Call get_account_balance with address={signer} and network={network} to confirm the sender holds enough {symbol}.\n\
+            2. Call create_transaction with signer={signer}, contract=\"Coin\", function=\"transfer\", args=[{\"b58\": \"{receiver}\"}, \"{amount}\", \"{symbol}\"].\n\
+            3. Sign the returned signing_payload with the sender's BLS12-381 key (the key never leaves the user's machine).\n\
+            4. Call submit_transaction with the returned blob as transaction, the base58 signature, and network={network}.\n\
+            5. Call get_transaction with the returned tx_hash to confirm the transfer was included.",
+        steps: &[],
+    },
+    PromptTemplate {
+        name: "guided_transfer",
+        description: "Walks step by step through a checked transfer: balance, fee, build, sign, submit, confirm",
+        arguments: &[
+            PromptArg { name: "source", description: "Sender public key (base58)", default: None },
+            PromptArg { name: "destination", description: "Recipient address (base58)", default: None },
+            PromptArg { name: "symbol", description: "Asset symbol, e.g. AMA", default: None },
+            PromptArg { name: "amount", description: "Amount in atomic units (1 AMA = 1000000000)", default: None },
+            PromptArg { name: "network", description: "'mainnet' (default) or 'testnet'", default: Some("mainnet") },
+        ],
+        template: "Transfer {amount} atomic units of {symbol} from {source} to {destination} on {network}. \
+            Follow the steps in the next messages in order, one tool call per step, and stop to report \
+            to the user if any step fails or its check does not hold. Do not skip the signature step: \
+            the source key stays with the user and is never sent to any tool.",
+        steps: &[
+            "Step 1 - check the source balance. Call get_balance_for_asset with \
+                {\"address\": \"{source}\", \"symbol\": \"{symbol}\", \"network\": \"{network}\"}. \
+                Continue only if balance is at least {amount}; otherwise tell the user how much is missing.",
+            "Step 2 - estimate the fee. Call get_fee_schedule with {\"network\": \"{network}\"}. \
+                The fee is roughly base_fee plus per_byte times the signed envelope size (about 300 bytes for a transfer). \
+                If {symbol} is AMA, check that the balance from step 1 covers {amount} plus that fee; \
+                otherwise check the source also holds enough AMA for the fee.",
+            "Step 3 - build the transfer. Call create_transfer with \
+                {\"signer\": \"{source}\", \"receiver\": \"{destination}\", \"symbol\": \"{symbol}\", \"amount\": \"{amount}\", \"network\": \"{network}\"}. \
+                Keep the returned blob, signing_payload, and transaction_hash.",
+            "Step 4 - get the signature. Show the user the transaction_hash and the signing_payload from step 3 \
+                and ask them to sign the signing_payload with the BLS12-381 key of {source}. \
+                Wait for the base58 signature; never ask for or accept the private key.",
+            "Step 5 - rehearse and submit. Call submit_transaction with \
+                {\"transaction\": \"<blob from step 3>\", \"signature\": \"<signature from step 4>\", \"check_signature\": true, \"dry_run\": true, \"network\": \"{network}\"}. \
+                If success is true, call submit_transaction again with the same arguments and dry_run set to false. \
+                If the signature check fails, go back to step 4.",
+            "Step 6 - confirm. Call wait_for_transaction with \
+                {\"tx_hash\": \"<tx_hash from step 5>\", \"network\": \"{network}\"} \
+                and report the final status, fee, and tx_hash to the user.",
+        ],
+    },
+    PromptTemplate {
+        name: "check_balance",
+        description: "Looks up and summarizes all asset balances of an account",
+        arguments: &[
+            PromptArg { name: "address", description: "Account address (base58)", default: None },
+            PromptArg { name: "network", description: "'mainnet' (default) or 'testnet'", default: Some("mainnet") },
+        ],
+        template: "Call get_account_balance with address={address} and network={network}. \
+            Summarize each asset's symbol with its human-readable balance (the float field), \
+            and mention the atomic amount (the flat field) when precision matters.",
+        steps: &[],
+    },
+    PromptTemplate {
+        name: "inspect_transaction",
+        description: "Fetches a transaction and explains what it did and whether it succeeded",
+        arguments: &[
+            PromptArg { name: "tx_hash", description: "Transaction hash (base58)", default: None },
+            PromptArg { name: "network", description: "'mainnet' (default) or 'testnet'", default: Some("mainnet") },
+        ],
+        template: "Call get_transaction with tx_hash={tx_hash} and network={network}. \
+            Explain who signed it (tx.signer), which contract and function it called (tx.action), \
+            its arguments, the entry height it was included at (metadata.entry_height), \
+            and whether it succeeded (receipt.success and result.error).",
+        steps: &[],
+    },
+];
+
 impl ServerHandler for BlockchainMcpServer {
     fn get_info(&self) -> ServerInfo {
         ServerInfo {
@@ -606,13 +2462,67 @@ impl ServerHandler for BlockchainMcpServer {
         }
     }
 
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, McpError> {
+        let tools = self
+            .tool_router
+            .list_all()
+            .into_iter()
+            .map(|mut tool| {
+                if let Some((_, example)) = TOOL_EXAMPLES.iter().find(|(n, _)| *n == tool.name) {
+                    let description = tool.description.as_deref().unwrap_or_default();
+                    tool.description = Some(format!("{} Example: {}", description, example).into());
+                }
+                tool
+            })
+            .collect();
+        Ok(ListToolsResult::with_all_items(tools))
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, McpError> {
+        // arguments are left out of the span: they can carry signatures
+        let span = tracing::info_span!("tool", tool = %request.name);
+        let counters = self.metrics.get(request.name.as_ref());
+        let _in_flight = InFlightGuard::new(&self.in_flight);
+        let started = Instant::now();
+        let cancelled = context.ct.clone();
+        let tcc = ToolCallContext::new(self, request, context);
+        let result = Self::until_cancelled(
+            self.tool_router.call(tcc).instrument(span.clone()),
+            cancelled.cancelled(),
+        )
+        .await;
+
+        let elapsed_ms = started.elapsed().as_millis() as u64;
+        if let Some(counters) = counters {
+            counters.record(
+                matches!(&result, Ok(r) if r.is_error != Some(true)),
+                elapsed_ms,
+            );
+        }
+        let _guard = span.enter();
+        match &result {
+            Ok(r) if r.is_error != Some(true) => debug!(elapsed_ms, "tool call succeeded"),
+            Ok(_) => debug!(elapsed_ms, "tool call returned an error result"),
+            Err(e) => error!(elapsed_ms, error = %e.message, "tool call failed"),
+        }
+        result
+    }
+
     async fn list_prompts(
         &self,
         _request: Option<PaginatedRequestParam>,
         _context: RequestContext<RoleServer>,
     ) -> Result<ListPromptsResult, McpError> {
         Ok(ListPromptsResult {
-            prompts: vec![],
+            prompts: Self::prompts(),
             next_cursor: None,
         })
     }
@@ -624,6 +2534,10 @@ impl ServerHandler for BlockchainMcpServer {
     ) -> Result<GetPromptResult, McpError> {
         let prompt_name = request.name.as_str();
 
+        if let Some(result) = Self::render_prompt(prompt_name, request.arguments.as_ref()) {
+            return Ok(result);
+        }
+
         Err(McpError::invalid_params(
             "unknown_prompt",
             Some(serde_json::json!({ "name": prompt_name })),
@@ -636,7 +2550,7 @@ impl ServerHandler for BlockchainMcpServer {
         _context: RequestContext<RoleServer>,
     ) -> Result<ListResourcesResult, McpError> {
         Ok(ListResourcesResult {
-            resources: vec![],
+            resources: Self::resources(),
             next_cursor: None,
         })
     }
@@ -647,7 +2561,7 @@ impl ServerHandler for BlockchainMcpServer {
         _context: RequestContext<RoleServer>,
     ) -> Result<ListResourceTemplatesResult, McpError> {
         Ok(ListResourceTemplatesResult {
-            resource_templates: vec![],
+            resource_templates: Self::resource_templates(),
             next_cursor: None,
         })
     }
@@ -658,9 +2572,722 @@ impl ServerHandler for BlockchainMcpServer {
         _context: RequestContext<RoleServer>,
     ) -> Result<ReadResourceResult, McpError> {
         let uri = request.uri.as_str();
+
+        match uri {
+            "amadeus://chain/stats" => {
+                let stats = self
+                    .blockchain
+                    .get_chain_stats(&self.mainnet_url)
+                    .await
+                    .map_err(|e| Self::blockchain_error("read_resource", e))?;
+                return Self::resource_contents(uri, stats);
+            }
+            "amadeus://validators" => {
+                let validators = self
+                    .blockchain
+                    .get_validators(&self.mainnet_url)
+                    .await
+                    .map_err(|e| Self::blockchain_error("read_resource", e))?;
+                return Self::resource_contents(
+                    uri,
+                    serde_json::json!({ "validators": validators, "count": validators.len() }),
+                );
+            }
+            _ => {}
+        }
+
+        if let Some(address) = uri
+            .strip_prefix("amadeus://account/")
+            .and_then(|rest| rest.strip_suffix("/balance"))
+        {
+            let query = AccountQuery {
+                address: address.to_string(),
+                network: None,
+            };
+            query.validate().map_err(|e| {
+                McpError::invalid_params(
+                    "invalid_uri",
+                    Some(serde_json::json!({ "message": e.to_string() })),
+                )
+            })?;
+
+            let balance = self
+                .blockchain
+                .get_account_balance(&query.address, &self.mainnet_url)
+                .await
+                .map_err(|e| Self::blockchain_error("read_resource", e))?;
+            return Self::resource_contents(uri, balance);
+        }
+
+        if let Some(tx_hash) = uri.strip_prefix("amadeus://tx/") {
+            let query = TransactionQuery {
+                tx_hash: tx_hash.to_string(),
+                network: None,
+            };
+            query.validate().map_err(|e| {
+                McpError::invalid_params(
+                    "invalid_uri",
+                    Some(serde_json::json!({ "message": e.to_string() })),
+                )
+            })?;
+
+            let transaction = self
+                .blockchain
+                .get_transaction(&query.tx_hash, &self.mainnet_url)
+                .await
+                .map_err(|e| Self::blockchain_error("read_resource", e))?;
+            return Self::resource_contents(uri, transaction);
+        }
+
         Err(McpError::invalid_params(
             "invalid_uri",
             Some(serde_json::json!({ "message": format!("Unknown resource URI: {}", uri) })),
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blockchain::http::MockExecutor;
+    use serde_json::json;
+
+    const MAINNET: &str = "http://mainnet.test";
+
+    fn server(client: BlockchainClient) -> BlockchainMcpServer {
+        BlockchainMcpServer::new(
+            client,
+            MAINNET.to_string(),
+            "http://testnet.test".to_string(),
+        )
+    }
+
+    fn client(mock: MockExecutor) -> BlockchainClient {
+        BlockchainClient::with_executor(Arc::new(mock))
+    }
+
+    fn address(byte: u8) -> String {
+        bs58::encode([byte; 48]).into_string()
+    }
+
+    fn parses<T: serde::de::DeserializeOwned>(example: &str) -> Result<(), String> {
+        serde_json::from_str::<T>(example)
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+
+    fn parse_example(tool: &str, example: &str) -> Result<(), String> {
+        match tool {
+            "create_transaction" => parses::<TransactionRequest>(example),
+            "create_transfer" => parses::<TransferRequest>(example),
+            "sign_and_submit_transfer" => parses::<SignAndSubmitTransferRequest>(example),
+            "create_mint" => parses::<MintRequest>(example),
+            "create_contract_deploy" => parses::<DeployContractRequest>(example),
+            "create_stake" | "create_unstake" => parses::<StakeRequest>(example),
+            "verify_transaction_blob" | "compute_transaction_hash" | "decode_transaction" => {
+                parses::<DecodeTransactionRequest>(example)
+            }
+            "submit_transaction" => parses::<SignedTransaction>(example),
+            "submit_raw_transaction" => parses::<RawTransaction>(example),
+            "verify_signature" => parses::<VerifySignatureRequest>(example),
+            "get_account_balance" => parses::<AccountQuery>(example),
+            "get_asset_info" | "get_supply" => parses::<AssetQuery>(example),
+            "get_top_holders" => parses::<TopHoldersQuery>(example),
+            "get_block_by_height" => parses::<HeightQuery>(example),
+            "get_validators" => parses::<ValidatorsQuery>(example),
+            "resolve_name" => parses::<ResolveNameQuery>(example),
+            "get_contract_code" => parses::<ContractCodeQuery>(example),
+            "wait_for_transaction" => parses::<WaitForTransactionQuery>(example),
+            "wait_for_next_block" => parses::<WaitForBlockQuery>(example),
+            "get_balance_at_height" => parses::<BalanceAtHeightQuery>(example),
+            "get_balance_for_asset" => parses::<AccountAssetQuery>(example),
+            "get_balances" => parses::<BalancesQuery>(example),
+            "get_account_summary" => parses::<AccountSummaryQuery>(example),
+            "get_block_by_hash" => parses::<BlockHashQuery>(example),
+            "get_block_range" => parses::<BlockRangeQuery>(example),
+            "get_transaction" | "get_transaction_status" | "get_transaction_receipt" => {
+                parses::<TransactionQuery>(example)
+            }
+            "get_transaction_by_position" => parses::<TransactionPositionQuery>(example),
+            "get_pending_transactions" => parses::<PendingTransactionsQuery>(example),
+            "get_transaction_history" => parses::<TransactionHistoryQuery>(example),
+            "get_account_transactions_since" => parses::<TransactionsSinceQuery>(example),
+            "search_transactions" => parses::<SearchTransactionsQuery>(example),
+            "get_validator_details" => parses::<ValidatorDetailsQuery>(example),
+            "get_contract_state" => parses::<ContractStateQuery>(example),
+            "simulate_contract_call" => parses::<ContractCallRequest>(example),
+            "get_contract_states" => parses::<ContractStatesQuery>(example),
+            "reverse_resolve" => parses::<ReverseResolveQuery>(example),
+            "list_contract_keys" => parses::<ContractKeysQuery>(example),
+            "batch_query" => parses::<BatchQuery>(example),
+            other => Err(format!("no parameter type registered for {}", other)),
+        }
+    }
+
+    #[test]
+    fn tool_examples_deserialize_into_their_parameter_types() {
+        let tools = BlockchainMcpServer::tool_names();
+        for (tool, example) in TOOL_EXAMPLES {
+            assert!(
+                tools.iter().any(|name| name == tool),
+                "example for unknown tool {}",
+                tool
+            );
+            if let Err(e) = parse_example(tool, example) {
+                panic!("example for {} does not parse: {}", tool, e);
+            }
+        }
+    }
+
+    #[test]
+    fn every_tool_with_required_params_has_an_example() {
+        for tool in BlockchainMcpServer::tool_router().list_all() {
+            let requires_params = tool
+                .input_schema
+                .get("required")
+                .and_then(|r| r.as_array())
+                .is_some_and(|r| !r.is_empty());
+            if requires_params {
+                assert!(
+                    TOOL_EXAMPLES.iter().any(|(name, _)| *name == tool.name),
+                    "tool {} has required params but no example",
+                    tool.name
+                );
+            }
+        }
+    }
+
+    fn routed(server: &BlockchainMcpServer, tool: &str) -> bool {
+        server.tool_router.list_all().iter().any(|t| t.name == tool)
+    }
+
+    #[test]
+    fn create_mint_is_routed_only_when_enabled() {
+        let plain = server(client(MockExecutor::new()));
+        assert!(!routed(&plain, "create_mint"));
+        assert!(!routed(&plain, "sign_and_submit_transfer"));
+
+        let key = SecretKey::from_b58(&bs58::encode([7u8; 64]).into_string()).unwrap();
+        let minting = server(client(MockExecutor::new()))
+            .with_mint_enabled()
+            .with_signing_key(key);
+        assert!(routed(&minting, "create_mint"));
+        assert!(routed(&minting, "sign_and_submit_transfer"));
+    }
+
+    struct ErrorCase {
+        tool: &'static str,
+        params: serde_json::Value,
+        client: BlockchainClient,
+        code: ErrorCode,
+        message: &'static str,
+    }
+
+    fn error_cases() -> Vec<ErrorCase> {
+        let block_hash = bs58::encode([4u8; 32]).into_string();
+        let stats = json!({"error": "ok", "stats": {"height": 10}});
+        vec![
+            ErrorCase {
+                tool: "get_account_balance",
+                params: json!({"address": address(1)}),
+                client: client(MockExecutor::new().with_response(
+                    &format!("/api/wallet/balance_all/{}", address(1)),
+                    200,
+                    json!({"error": "not_found"}),
+                )),
+                code: ErrorCode::RESOURCE_NOT_FOUND,
+                message: "account_not_found",
+            },
+            ErrorCase {
+                tool: "get_contract_code",
+                params: json!({"contract_address": "Nope"}),
+                client: client(MockExecutor::new()),
+                code: ErrorCode::RESOURCE_NOT_FOUND,
+                message: "not_a_contract",
+            },
+            ErrorCase {
+                tool: "get_block_by_hash",
+                params: json!({"hash": block_hash}),
+                client: client(MockExecutor::new().with_response(
+                    &format!("/api/chain/hash/{}", block_hash),
+                    200,
+                    json!({"error": "not_found"}),
+                )),
+                code: ErrorCode::RESOURCE_NOT_FOUND,
+                message: "block_not_found",
+            },
+            ErrorCase {
+                tool: "get_transaction",
+                params: json!({"tx_hash": "abc"}),
+                client: client(MockExecutor::new().with_response(
+                    "/api/chain/tx/abc",
+                    200,
+                    json!({"result": {"error": "not_found"}}),
+                )),
+                code: ErrorCode::RESOURCE_NOT_FOUND,
+                message: "transaction_not_found",
+            },
+            ErrorCase {
+                tool: "get_asset_info",
+                params: json!({"symbol": "GOLD"}),
+                client: client(MockExecutor::new().with_response(
+                    "/api/coin/list",
+                    200,
+                    json!({"error": "ok", "assets": [{"symbol": "AMA", "decimals": 9}]}),
+                )),
+                code: ErrorCode::RESOURCE_NOT_FOUND,
+                message: "asset_not_found",
+            },
+            ErrorCase {
+                tool: "get_transaction",
+                params: json!({"tx_hash": "abc"}),
+                client: client(MockExecutor::new().with_response(
+                    "/api/chain/tx/abc",
+                    400,
+                    json!({"error": "invalid hash"}),
+                )),
+                code: ErrorCode::INVALID_REQUEST,
+                message: "node_error",
+            },
+            ErrorCase {
+                tool: "list_contract_keys",
+                params: json!({"contract_address": "Coin"}),
+                client: client(MockExecutor::new()),
+                code: ErrorCode::INVALID_REQUEST,
+                message: "unsupported",
+            },
+            ErrorCase {
+                tool: "get_chain_stats",
+                params: json!({}),
+                client: client(MockExecutor::new().with_raw_response(
+                    "/api/chain/stats",
+                    502,
+                    Some("text/plain"),
+                    "bad gateway",
+                )),
+                code: ErrorCode::INTERNAL_ERROR,
+                message: "http_status_error",
+            },
+            ErrorCase {
+                tool: "get_chain_stats",
+                params: json!({}),
+                client: client(MockExecutor::new().with_response(
+                    "/api/chain/stats",
+                    503,
+                    json!({"error": "overloaded"}),
+                )),
+                code: ErrorCode::INTERNAL_ERROR,
+                message: "node_error",
+            },
+            ErrorCase {
+                tool: "get_chain_stats",
+                params: json!({}),
+                client: client(MockExecutor::new().with_response(
+                    "/api/chain/stats",
+                    503,
+                    json!({"error": "overloaded"}),
+                ))
+                .with_circuit_breaker(
+                    1,
+                    Duration::from_secs(60),
+                    Duration::from_secs(60),
+                ),
+                code: ErrorCode::INTERNAL_ERROR,
+                message: "node_unavailable",
+            },
+            ErrorCase {
+                tool: "get_chain_stats",
+                params: json!({}),
+                client: client(MockExecutor::new().with_raw_response(
+                    "/api/chain/stats",
+                    200,
+                    Some("text/html"),
+                    "<html>maintenance</html>",
+                )),
+                code: ErrorCode::INTERNAL_ERROR,
+                message: "unexpected_content_type",
+            },
+            ErrorCase {
+                tool: "get_chain_stats",
+                params: json!({}),
+                client: client(MockExecutor::new().with_response(
+                    "/api/chain/stats",
+                    200,
+                    stats.clone(),
+                ))
+                .with_max_response_bytes(8),
+                code: ErrorCode::INTERNAL_ERROR,
+                message: "response_too_large",
+            },
+            ErrorCase {
+                tool: "get_balance_at_height",
+                params: json!({"address": address(1), "height": 11}),
+                client: client(MockExecutor::new().with_response("/api/chain/stats", 200, stats)),
+                code: ErrorCode::INVALID_PARAMS,
+                message: "validation_failed",
+            },
+        ]
+    }
+
+    // non-2xx replies are retried with backoff, so time is paused and
+    // auto-advanced instead of waiting out the delays
+    #[tokio::test(start_paused = true)]
+    async fn read_tools_map_blockchain_errors() {
+        for case in error_cases() {
+            let label = format!("{} -> {}", case.tool, case.message);
+            let err = server(case.client)
+                .dispatch_read(case.tool, case.params)
+                .await
+                .err()
+                .unwrap_or_else(|| panic!("{} succeeded", label));
+            assert_eq!(err.code, case.code, "{}", label);
+            assert_eq!(err.message, case.message, "{}", label);
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn preflight_maps_insufficient_balance() {
+        let server = server(client(MockExecutor::new().with_response(
+            &format!("/api/wallet/balance_all/{}", address(1)),
+            200,
+            json!({"error": "ok", "balances": {"AMA": "5"}}),
+        )));
+        let request: TransactionRequest = serde_json::from_value(json!({
+            "signer": address(1),
+            "contract": "Coin",
+            "function": "transfer",
+            "args": [{"b58": address(2)}, "100", "AMA"],
+            "preflight_balance_check": true,
+        }))
+        .unwrap();
+
+        let err = server
+            .create_transaction(Parameters(request))
+            .await
+            .err()
+            .expect("preflight should fail");
+        assert_eq!(err.code, ErrorCode::INVALID_REQUEST);
+        assert_eq!(err.message, "insufficient_balance");
+        assert_eq!(
+            err.data,
+            Some(json!({"required": "100 AMA", "available": "5 AMA"}))
+        );
+    }
+
+    fn decoded_action(response: &serde_json::Value) -> tx::TxAction {
+        let blob = bs58::decode(response["blob"].as_str().unwrap())
+            .into_vec()
+            .unwrap();
+        tx::decode(&blob).unwrap().tx.action
+    }
+
+    fn stake_request() -> StakeRequest {
+        serde_json::from_value(json!({
+            "signer": address(1),
+            "validator": address(3),
+            "symbol": "AMA",
+            "amount": "400",
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn create_transfer_falls_back_to_the_default_asset() {
+        let transfer = || -> TransferRequest {
+            serde_json::from_value(json!({
+                "signer": address(1),
+                "receiver": address(2),
+                "amount": "5",
+            }))
+            .unwrap()
+        };
+        let offline = || client(MockExecutor::new()).with_offline_build(true);
+
+        let err = server(offline())
+            .create_transfer(Parameters(transfer()))
+            .await
+            .err()
+            .expect("no symbol and no default asset");
+        assert_eq!(err.message, "validation_failed");
+
+        let Json(response) = server(offline())
+            .with_default_asset("USDC".to_string())
+            .create_transfer(Parameters(transfer()))
+            .await
+            .unwrap();
+        assert_eq!(decoded_action(&response).args[2], b"USDC");
+    }
+
+    #[tokio::test]
+    async fn create_contract_deploy_passes_the_code_first() {
+        let server = server(client(MockExecutor::new()));
+        let request: DeployContractRequest = serde_json::from_value(json!({
+            "signer": address(1),
+            "code_hex": "0x0061736d01000000",
+            "args": ["init"],
+        }))
+        .unwrap();
+        let Json(response) = server
+            .create_contract_deploy(Parameters(request))
+            .await
+            .unwrap();
+
+        let action = decoded_action(&response);
+        assert_eq!(action.op, "call");
+        assert_eq!(action.contract, "Contract");
+        assert_eq!(action.function, "deploy");
+        assert_eq!(
+            action.args,
+            vec![b"\0asm\x01\0\0\0".to_vec(), b"init".to_vec()]
+        );
+    }
+
+    #[tokio::test]
+    async fn create_stake_attaches_the_amount() {
+        let server = server(client(MockExecutor::new()));
+        let Json(response) = server
+            .create_stake(Parameters(stake_request()))
+            .await
+            .unwrap();
+
+        let action = decoded_action(&response);
+        assert_eq!(action.contract, tx::STAKE_CONTRACT);
+        assert_eq!(action.function, "stake");
+        assert_eq!(action.args, vec![vec![3u8; 48]]);
+        assert_eq!(action.attached_symbol.as_deref(), Some(&b"AMA"[..]));
+        assert_eq!(action.attached_amount.as_deref(), Some(&b"400"[..]));
+    }
+
+    #[tokio::test]
+    async fn create_unstake_names_amount_and_symbol_as_args() {
+        let server = server(client(MockExecutor::new()));
+        let Json(response) = server
+            .create_unstake(Parameters(stake_request()))
+            .await
+            .unwrap();
+
+        let action = decoded_action(&response);
+        assert_eq!(action.contract, tx::STAKE_CONTRACT);
+        assert_eq!(action.function, "unstake");
+        assert_eq!(
+            action.args,
+            vec![vec![3u8; 48], b"400".to_vec(), b"AMA".to_vec()]
+        );
+        assert_eq!(action.attached_symbol, None);
+        assert_eq!(action.attached_amount, None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn read_tools_return_node_data_on_success() {
+        let server = server(client(
+            MockExecutor::new()
+                .with_fixtures(&json!({
+                    "/api/chain/stats": {"error": "ok", "stats": {"height": 42}},
+                }))
+                .unwrap(),
+        ));
+
+        let Json(stats) = server
+            .dispatch_read("get_chain_stats", json!(null))
+            .await
+            .unwrap();
+        assert_eq!(stats["height"], 42);
+    }
+
+    fn fixture_server(fixtures: serde_json::Value) -> BlockchainMcpServer {
+        server(client(
+            MockExecutor::new().with_fixtures(&fixtures).unwrap(),
+        ))
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn create_transfer_converts_display_amounts_with_node_decimals() {
+        let assets = json!({
+            "/api/coin/list": {"error": "ok", "assets": [{"symbol": "AMA", "decimals": 9}]},
+        });
+        let transfer = |symbol: &str| -> TransferRequest {
+            serde_json::from_value(json!({
+                "signer": address(1),
+                "receiver": address(2),
+                "symbol": symbol,
+                "amount_display": "1.5",
+            }))
+            .unwrap()
+        };
+
+        let Json(response) = fixture_server(assets.clone())
+            .create_transfer(Parameters(transfer("AMA")))
+            .await
+            .unwrap();
+        assert_eq!(decoded_action(&response).args[1], b"1500000000");
+
+        let err = fixture_server(assets)
+            .create_transfer(Parameters(transfer("USDC")))
+            .await
+            .err()
+            .expect("unknown asset");
+        assert_eq!(err.code, ErrorCode::RESOURCE_NOT_FOUND);
+        assert_eq!(err.message, "asset_not_found");
+        assert_eq!(err.data, Some(json!({"symbol": "USDC"})));
+    }
+
+    fn signed_transaction() -> SignedTransaction {
+        let signer = tx::public_key_from_secret(
+            &SecretKey::from_b58(&bs58::encode([7u8; 64]).into_string()).unwrap(),
+        )
+        .unwrap();
+        let unsigned =
+            tx::build_unsigned_with_nonce(&signer, "Coin", "transfer", &[], None, None, 1).unwrap();
+        serde_json::from_value(json!({
+            "transaction": bs58::encode(&unsigned.tx_blob).into_string(),
+            "signature": bs58::encode([1u8; 96]).into_string(),
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn submit_transaction_reports_the_node_verdict() {
+        let Json(accepted) = fixture_server(json!({
+            "/api/tx/submit": {"error": "ok", "hash": "node-hash"},
+        }))
+        .submit_transaction(Parameters(signed_transaction()))
+        .await
+        .unwrap();
+        assert_eq!(accepted["status"], "success");
+        assert_eq!(accepted["tx_hash"], "node-hash");
+        assert_eq!(accepted["duplicate"], false);
+
+        let err = fixture_server(json!({
+            "/api/tx/submit": {"error": "invalid_signature"},
+        }))
+        .submit_transaction(Parameters(signed_transaction()))
+        .await
+        .err()
+        .expect("node rejected the transaction");
+        assert_eq!(err.code, ErrorCode::INTERNAL_ERROR);
+        assert_eq!(err.message, "submission_failed");
+        assert_eq!(err.data, Some(json!({"error": "invalid_signature"})));
+
+        let err = fixture_server(json!({
+            "/api/tx/submit": {"status": 400, "body": {"error": "malformed envelope"}},
+        }))
+        .submit_transaction(Parameters(signed_transaction()))
+        .await
+        .err()
+        .expect("node refused the request");
+        assert_eq!(err.code, ErrorCode::INVALID_REQUEST);
+        assert_eq!(err.message, "node_error");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn wait_for_transaction_returns_the_final_status() {
+        let wait = |server: BlockchainMcpServer| async move {
+            let query: WaitForTransactionQuery =
+                serde_json::from_value(json!({"tx_hash": "abc", "timeout_secs": 5})).unwrap();
+            server.wait_for_transaction(Parameters(query)).await
+        };
+
+        let Json(confirmed) = wait(fixture_server(json!({
+            "/api/chain/tx/abc": {"metadata": {"entry_height": 7}, "receipt": {"success": true}},
+        })))
+        .await
+        .unwrap();
+        assert_eq!(
+            confirmed["status"],
+            json!({"status": "confirmed", "height": 7})
+        );
+        assert_eq!(confirmed["polls"], 1);
+        assert_eq!(confirmed["timed_out"], false);
+
+        let Json(pending) = wait(fixture_server(json!({
+            "/api/chain/tx/abc": {"result": {"error": "not_found"}},
+        })))
+        .await
+        .unwrap();
+        assert_eq!(pending["status"], json!({"status": "pending"}));
+        assert_eq!(pending["timed_out"], true);
+
+        let err = wait(fixture_server(json!({
+            "/api/chain/tx/abc": {"status": 400, "body": {"error": "bad hash"}},
+        })))
+        .await
+        .err()
+        .expect("node refused the hash");
+        assert_eq!(err.code, ErrorCode::INVALID_REQUEST);
+        assert_eq!(err.message, "node_error");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn wait_for_next_block_returns_the_new_tip() {
+        let wait = |server: BlockchainMcpServer| async move {
+            let query: WaitForBlockQuery =
+                serde_json::from_value(json!({"after_height": 41, "timeout_secs": 5})).unwrap();
+            server.wait_for_next_block(Parameters(query)).await
+        };
+
+        let Json(advanced) = wait(fixture_server(json!({
+            "/api/chain/stats": {"error": "ok", "stats": {"height": 42}},
+        })))
+        .await
+        .unwrap();
+        assert_eq!(advanced["height"], 42);
+        assert_eq!(advanced["timed_out"], false);
+
+        let err = wait(fixture_server(json!({
+            "/api/chain/stats": {"status": 503, "body": {"error": "syncing"}},
+        })))
+        .await
+        .err()
+        .expect("node unavailable");
+        assert_eq!(err.code, ErrorCode::INTERNAL_ERROR);
+        assert_eq!(err.message, "node_error");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cancelling_a_wait_drops_the_handler() {
+        use std::sync::atomic::AtomicBool;
+        use tokio_util::sync::CancellationToken;
+
+        struct SetOnDrop(Arc<AtomicBool>);
+        impl Drop for SetOnDrop {
+            fn drop(&mut self) {
+                self.0.store(true, Ordering::SeqCst);
+            }
+        }
+
+        let server = server(client(MockExecutor::new().with_response(
+            "/api/chain/tx/abc",
+            200,
+            json!({"result": {"error": "not_found"}}),
+        )));
+        let dropped = Arc::new(AtomicBool::new(false));
+        let guard = SetOnDrop(dropped.clone());
+        let call = async move {
+            let _guard = guard;
+            let query: WaitForTransactionQuery =
+                serde_json::from_value(json!({"tx_hash": "abc", "timeout_secs": 120})).unwrap();
+            server.wait_for_transaction(Parameters(query)).await
+        };
+
+        let ct = CancellationToken::new();
+        let token = ct.clone();
+        let handle = tokio::spawn(async move {
+            BlockchainMcpServer::until_cancelled(call, token.cancelled()).await
+        });
+
+        // still polling the pending transaction well into its timeout
+        tokio::time::sleep(Duration::from_secs(30)).await;
+        assert!(!handle.is_finished());
+        assert!(!dropped.load(Ordering::SeqCst));
+
+        ct.cancel();
+        let err = handle
+            .await
+            .unwrap()
+            .err()
+            .expect("call should be cancelled");
+        assert_eq!(err.code, ErrorCode::INTERNAL_ERROR);
+        assert_eq!(err.message, "request_cancelled");
+        assert!(dropped.load(Ordering::SeqCst));
+    }
+}
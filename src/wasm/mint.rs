@@ -1,11 +1,177 @@
 use super::tx;
+use crate::blockchain::{amount::Amount, BlockchainError};
+use crate::BlockchainClient;
+use serde::Serialize;
 use serde_json::{json, Value};
-use worker::Env;
+use worker::{Date, Env};
 
-const FAUCET_AMOUNT: i128 = 100_000_000_000;
-const FAUCET_SYMBOL: &str = "AMA";
+const DEFAULT_FAUCET_AMOUNT: u64 = 100_000_000_000;
+const DEFAULT_FAUCET_SYMBOL: &str = "AMA";
+const DEFAULT_ADDRESS_COOLDOWN_SECS: u64 = 86400;
+
+/// Amount (atoms) and assets the faucet hands out. `FAUCET_ALLOWED_SYMBOLS` is a
+/// comma-separated allowlist that must include `FAUCET_SYMBOL`, and defaults to
+/// just that.
+///
+/// Claims can additionally be gated, and are not when neither is set:
+/// `FAUCET_ALLOWED_ADDRESSES` limits which addresses may claim, and
+/// `FAUCET_VERIFY_URL` must approve each claim (e.g. a captcha check).
+struct FaucetConfig {
+    amount: Amount,
+    symbol: String,
+    allowed_symbols: Vec<String>,
+    allowed_addresses: Option<Vec<String>>,
+    verify_url: Option<String>,
+}
+
+impl FaucetConfig {
+    fn from_env(env: &Env) -> Result<Self, Value> {
+        let amount = match env.var("FAUCET_AMOUNT") {
+            Ok(v) => v
+                .to_string()
+                .trim()
+                .parse::<Amount>()
+                .ok()
+                .filter(|a| !a.is_zero())
+                .ok_or_else(|| err("FAUCET_AMOUNT must be a positive integer within range"))?,
+            Err(_) => Amount::from(DEFAULT_FAUCET_AMOUNT),
+        };
+        let symbol = env
+            .var("FAUCET_SYMBOL")
+            .map(|v| v.to_string())
+            .unwrap_or_else(|_| DEFAULT_FAUCET_SYMBOL.to_string());
+        let allowed_symbols = env
+            .var("FAUCET_ALLOWED_SYMBOLS")
+            .map(|v| {
+                v.to_string()
+                    .split(',')
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect()
+            })
+            .unwrap_or_else(|_| vec![symbol.clone()]);
+        // claims that name no asset get FAUCET_SYMBOL, so it must be allowed too
+        if !allowed_symbols.contains(&symbol) {
+            return Err(err(&format!(
+                "FAUCET_ALLOWED_SYMBOLS must include FAUCET_SYMBOL ({})",
+                symbol
+            )));
+        }
+        let allowed_addresses = env.var("FAUCET_ALLOWED_ADDRESSES").ok().map(|v| {
+            v.to_string()
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect()
+        });
+        let verify_url = env
+            .var("FAUCET_VERIFY_URL")
+            .map(|v| v.to_string())
+            .ok()
+            .filter(|v| !v.is_empty());
+
+        Ok(Self {
+            amount,
+            symbol,
+            allowed_symbols,
+            allowed_addresses,
+            verify_url,
+        })
+    }
+
+    fn resolve_symbol(&self, requested: Option<&str>) -> Result<String, Value> {
+        match requested {
+            None => Ok(self.symbol.clone()),
+            Some(s) if self.allowed_symbols.iter().any(|a| a == s) => Ok(s.to_string()),
+            Some(s) => Err(err(&format!(
+                "asset {} is not available from the faucet (allowed: {})",
+                s,
+                self.allowed_symbols.join(", ")
+            ))),
+        }
+    }
+
+    /// Runs the operator's gates before anything is built or signed. The
+    /// verification hook receives `{"address", "token"}` as a JSON POST and
+    /// must answer 2xx for the claim to proceed.
+    async fn verify_claim(&self, address: &str, token: Option<&str>) -> Result<(), Value> {
+        if let Some(allowed) = &self.allowed_addresses {
+            if !allowed.iter().any(|a| a == address) {
+                return Err(rejected("address_not_allowed", None));
+            }
+        }
+
+        let Some(verify_url) = &self.verify_url else {
+            return Ok(());
+        };
+        let mut headers = worker::Headers::new();
+        headers
+            .set("Content-Type", "application/json")
+            .map_err(|e| err(&e.to_string()))?;
+        let mut init = worker::RequestInit::new();
+        init.with_method(worker::Method::Post)
+            .with_headers(headers)
+            .with_body(Some(
+                json!({ "address": address, "token": token })
+                    .to_string()
+                    .into(),
+            ));
+        let request =
+            worker::Request::new_with_init(verify_url, &init).map_err(|e| err(&e.to_string()))?;
+        let response = worker::Fetch::Request(request)
+            .send()
+            .await
+            .map_err(|e| err(&format!("faucet verification unavailable: {}", e)))?;
+
+        let status = response.status_code();
+        if !(200..300).contains(&status) {
+            return Err(rejected("verification_failed", Some(status)));
+        }
+        Ok(())
+    }
+}
+
+pub async fn transfer(
+    env: &Env,
+    address: &str,
+    symbol: Option<&str>,
+    verification_token: Option<&str>,
+) -> Result<SubmitOutcome, Value> {
+    let config = FaucetConfig::from_env(env)?;
+    let symbol = config.resolve_symbol(symbol)?;
+    // a malformed address is rejected before any storage or network access
+    let receiver = tx::Address::from_b58(address).map_err(err)?;
+
+    // a cooling-down address is turned away before the verification hook or
+    // the node see the claim
+    let kv = env.kv("FAUCET_KV").map_err(|e| err(&e.to_string()))?;
+    let cooldown = env
+        .var("FAUCET_ADDRESS_COOLDOWN_SECS")
+        .ok()
+        .and_then(|v| v.to_string().parse::<u64>().ok())
+        .unwrap_or(DEFAULT_ADDRESS_COOLDOWN_SECS);
+    let rate_key = format!("faucet:{}", address);
+    let now = Date::now().as_millis() / 1000;
+
+    let last_claim = kv
+        .get(&rate_key)
+        .text()
+        .await
+        .map_err(|e| err(&e.to_string()))?
+        .and_then(|v| v.parse::<u64>().ok());
+    if let Some(claimed_at) = last_claim {
+        let elapsed = now.saturating_sub(claimed_at);
+        if elapsed < cooldown {
+            return Err(json!({
+                "code": -32000,
+                "message": "rate limited",
+                "retry_after": cooldown - elapsed
+            }));
+        }
+    }
+
+    config.verify_claim(address, verification_token).await?;
 
-pub async fn transfer(env: &Env, address: &str) -> Result<String, Value> {
     let rpc = env
         .var("AMADEUS_TESTNET_RPC")
         .map(|v| v.to_string())
@@ -15,36 +181,105 @@ pub async fn transfer(env: &Env, address: &str) -> Result<String, Value> {
         .map(|v| v.to_string())
         .map_err(|_| err("AMADEUS_TESTNET_SK not configured"))?;
 
-    let sk = bs58::decode(&key_b58)
-        .into_vec()
-        .map_err(|_| err("invalid mint key encoding"))?;
-    let receiver = bs58::decode(address)
-        .into_vec()
-        .map_err(|_| err("invalid address encoding"))?;
-
-    if receiver.len() < 44 || receiver.len() > 48 {
-        return Err(err("address must be 44-48 bytes"));
-    }
+    let sk = tx::SecretKey::from_b58(&key_b58).map_err(|_| err("invalid mint key encoding"))?;
+    let faucet_pk = tx::public_key_from_secret(&sk).map_err(err)?;
+    let faucet_address = tx::Address::from_bytes(&faucet_pk).map_err(err)?;
+    let client = BlockchainClient::new(rpc.clone()).map_err(|e| err(&e.to_string()))?;
+    ensure_funded(
+        &client,
+        &rpc,
+        faucet_address.as_b58(),
+        &symbol,
+        config.amount,
+    )
+    .await?;
 
-    let built = tx::build_transfer_tx(&sk, &receiver, FAUCET_SYMBOL, FAUCET_AMOUNT).map_err(err)?;
-    let tx_b58 = bs58::encode(&built.packed).into_string();
-    let tx_hash = bs58::encode(&built.hash).into_string();
+    let params = tx::SigningParams::from_dst(
+        env.var("AMADEUS_SIGNING_DST")
+            .ok()
+            .map(|v| v.to_string())
+            .as_deref(),
+    );
+    let built = tx::build_with_params(
+        &sk,
+        "Coin",
+        "transfer",
+        &tx::transfer_args(&receiver, &symbol, config.amount, None),
+        None,
+        None,
+        &params,
+    )
+    .map_err(err)?;
+    let local_hash = bs58::encode(&built.hash).into_string();
 
-    let url = format!("{}/api/tx/submit/{}", rpc.trim_end_matches('/'), tx_b58);
-    let mut resp = worker::Fetch::Url(worker::Url::parse(&url).map_err(|e| err(&e.to_string()))?)
-        .send()
+    let response = client
+        .submit_packed_transaction(&built.packed, &built.hash, &rpc)
         .await
         .map_err(|e| err(&e.to_string()))?;
+    let outcome = SubmitOutcome {
+        accepted: response.error == "ok",
+        tx_hash: response.tx_hash.unwrap_or(local_hash),
+        node_status: response.error,
+        height: response.height,
+    };
+
+    if outcome.accepted {
+        kv.put(&rate_key, now.to_string())
+            .map_err(|e| err(&e.to_string()))?
+            .expiration_ttl(cooldown.max(60))
+            .execute()
+            .await
+            .map_err(|e| err(&e.to_string()))?;
+    }
+
+    Ok(outcome)
+}
 
-    let body = resp.text().await.map_err(|e| err(&e.to_string()))?;
-    Ok(format!(
-        "status={} tx_hash={} body={}",
-        resp.status_code(),
-        tx_hash,
-        body
-    ))
+/// What the node said about a faucet submission.
+#[derive(Debug, Serialize)]
+pub struct SubmitOutcome {
+    pub tx_hash: String,
+    pub accepted: bool,
+    pub node_status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<u64>,
+}
+
+async fn ensure_funded(
+    client: &BlockchainClient,
+    rpc: &str,
+    faucet_address: &str,
+    symbol: &str,
+    amount: Amount,
+) -> Result<(), Value> {
+    let flat = match client
+        .get_balance_for_symbol(faucet_address, symbol, rpc)
+        .await
+    {
+        Ok(balance) => balance.balance,
+        // an account the chain has never seen holds nothing
+        Err(BlockchainError::AccountNotFound { .. }) => Amount::ZERO,
+        Err(e) => return Err(err(&format!("faucet balance unavailable: {}", e))),
+    };
+    if flat < amount {
+        return Err(err(&format!(
+            "faucet address {} is not funded (balance {} {})",
+            faucet_address, flat, symbol
+        )));
+    }
+    Ok(())
 }
 
 fn err(msg: &str) -> Value {
     json!({ "code": -32603, "message": msg })
 }
+
+/// A claim refused by the operator's allowlist or verification hook.
+fn rejected(reason: &str, status: Option<u16>) -> Value {
+    let mut error =
+        json!({ "code": -32000, "message": "faucet claim not verified", "reason": reason });
+    if let Some(status) = status {
+        error["verify_status"] = json!(status);
+    }
+    error
+}
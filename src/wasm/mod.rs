@@ -14,13 +14,36 @@ use worker::*;
 
 #[event(fetch)]
 pub async fn main(mut req: Request, env: Env, _ctx: Context) -> Result<Response> {
-    let blockchain_url = env
-        .var("BLOCKCHAIN_URL")
-        .map(|v| v.to_string())
-        .unwrap_or_else(|_| "https://nodes.amadeus.bot".to_string());
+    let blockchain_url = rpc_url(&env, None);
 
-    let client = BlockchainClient::new(blockchain_url.clone())
+    let mut client = BlockchainClient::new(blockchain_url.clone())
         .map_err(|e| format!("failed to create client: {}", e))?;
+    if let Some(max) = env
+        .var("AMADEUS_MAX_BLOCK_RANGE")
+        .ok()
+        .and_then(|v| v.to_string().parse().ok())
+    {
+        client = client.with_max_block_range(max);
+    }
+    if let Some(limit) = env
+        .var("AMADEUS_MAX_RESPONSE_BYTES")
+        .ok()
+        .and_then(|v| v.to_string().parse().ok())
+    {
+        client = client.with_max_response_bytes(limit);
+    }
+    let env_num = |name: &str| env.var(name).ok().map(|v| v.to_string());
+    client = client.with_poll_backoff(PollBackoff::default().with_overrides(
+        env_num("AMADEUS_POLL_INITIAL_MS").and_then(|v| v.parse().ok()),
+        env_num("AMADEUS_POLL_BACKOFF_FACTOR").and_then(|v| v.parse().ok()),
+        env_num("AMADEUS_POLL_MAX_MS").and_then(|v| v.parse().ok()),
+    ));
+    client = client.with_signing_params(crate::wasm::tx::SigningParams::from_dst(
+        env.var("AMADEUS_SIGNING_DST").ok().map(|v| v.to_string()).as_deref(),
+    ));
+    if let Ok(token) = env.secret("BLOCKCHAIN_AUTH_TOKEN") {
+        client = client.with_auth_header("Authorization", format!("Bearer {}", token.to_string()));
+    }
 
     let url = req.url()?;
     let path = url.path();
@@ -342,156 +365,632 @@ async fn handle_tool_call(
     headers: HashMap<String, String>, params: &Value,
 ) -> std::result::Result<Value, Value> {
     let tool = params["name"].as_str().unwrap_or("");
-    let args = &params["arguments"];
+    // tools without required arguments may be called with none at all
+    let no_args = json!({});
+    let args = match &params["arguments"] {
+        Value::Null => &no_args,
+        args => args,
+    };
     match tool {
         "create_transaction" => {
             let req: TransactionRequest =
                 serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&req).map_err(validation_err)?;
+            if req.preflight_balance_check.unwrap_or(false) {
+                let url = rpc_url(env, req.network.as_deref());
+                client.check_spendable_balance(&req, &url).await.map_err(|e| err(&e.to_string()))?;
+            }
+            client.create_transaction_blob(req).await
+                .map(|b| ok(&json!({ "blob": b.blob, "signing_payload": b.signing_payload, "transaction_hash": b.transaction_hash, "status": "unsigned" })))
+                .map_err(|e| err(&e.to_string()))
+        }
+        "create_transfer" => {
+            let req: TransferRequest =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            let default_asset = env.var("DEFAULT_ASSET").ok().map(|v| v.to_string());
+            validator::Validate::validate(&req).map_err(validation_err)?;
+            let symbol = req.resolve_symbol(default_asset.as_deref()).map_err(validation_err)?;
+            let url = rpc_url(env, req.network.as_deref());
+            client.create_transfer_blob(req, &symbol, &url).await
+                .map(|b| ok(&json!({ "blob": b.blob, "signing_payload": b.signing_payload, "transaction_hash": b.transaction_hash, "status": "unsigned" })))
+                .map_err(|e| err(&e.to_string()))
+        }
+        "create_mint" => {
+            let req: MintRequest =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&req).map_err(validation_err)?;
+            client.create_transaction_blob(req.into()).await
+                .map(|b| ok(&json!({ "blob": b.blob, "signing_payload": b.signing_payload, "transaction_hash": b.transaction_hash, "status": "unsigned" })))
+                .map_err(|e| err(&e.to_string()))
+        }
+        "create_contract_deploy" => {
+            let req: DeployContractRequest =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&req).map_err(validation_err)?;
+            client.create_transaction_blob(req.into()).await
+                .map(|b| ok(&json!({ "blob": b.blob, "signing_payload": b.signing_payload, "transaction_hash": b.transaction_hash, "status": "unsigned" })))
+                .map_err(|e| err(&e.to_string()))
+        }
+        "create_stake" | "create_unstake" => {
+            let req: StakeRequest =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&req).map_err(validation_err)?;
+            let req = if tool == "create_stake" { req.into_stake_request() } else { req.into_unstake_request() };
+            if req.preflight_balance_check.unwrap_or(false) {
+                let url = rpc_url(env, req.network.as_deref());
+                client.check_spendable_balance(&req, &url).await.map_err(|e| err(&e.to_string()))?;
+            }
             client.create_transaction_blob(req).await
                 .map(|b| ok(&json!({ "blob": b.blob, "signing_payload": b.signing_payload, "transaction_hash": b.transaction_hash, "status": "unsigned" })))
                 .map_err(|e| err(&e.to_string()))
         }
+        "decode_transaction" => {
+            let req: DecodeTransactionRequest =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&req).map_err(validation_err)?;
+            client
+                .decode_transaction(&req.transaction)
+                .map(|d| ok(&d))
+                .map_err(|e| err(&e.to_string()))
+        }
+        "compute_transaction_hash" => {
+            let req: DecodeTransactionRequest =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&req).map_err(validation_err)?;
+            client
+                .compute_transaction_hash(&req.transaction)
+                .map(|h| ok(&h))
+                .map_err(|e| err(&e.to_string()))
+        }
+        "verify_transaction_blob" => {
+            let req: DecodeTransactionRequest =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&req).map_err(validation_err)?;
+            client
+                .verify_transaction_blob(&req.transaction)
+                .map(|r| ok(&r))
+                .map_err(|e| err(&e.to_string()))
+        }
         "submit_transaction" => {
             let tx: SignedTransaction =
                 serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
-            let url = match tx.network.as_deref() {
-                Some("testnet") => env.var("AMADEUS_TESTNET_RPC").map(|v| v.to_string()).unwrap_or_else(|_| "https://testnet.amadeus.bot".to_string()),
-                _ => rpc.to_string(),
-            };
+            validator::Validate::validate(&tx).map_err(validation_err)?;
+            let url = rpc_url(env, tx.network.as_deref());
+            if tx.check_signature.unwrap_or(false) {
+                crate::blockchain::shared::check_transaction_signature(&tx, client.signing_params())
+                    .map_err(|e| err(&e.to_string()))?;
+            }
+            if tx.dry_run.unwrap_or(false) {
+                return client
+                    .simulate_signed_transaction(&tx, &url)
+                    .await
+                    .map(|r| ok(&r))
+                    .map_err(|e| err(&e.to_string()));
+            }
             client
                 .submit_signed_transaction(tx, &url)
                 .await
                 .map(|r| ok(&r))
                 .map_err(|e| err(&e.to_string()))
         }
+        "submit_raw_transaction" => {
+            let tx: RawTransaction =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&tx).map_err(validation_err)?;
+            let url = rpc_url(env, tx.network.as_deref());
+            client
+                .submit_raw_transaction(&tx.transaction, &url)
+                .await
+                .map(|r| ok(&r))
+                .map_err(|e| err(&e.to_string()))
+        }
+        "verify_signature" => {
+            let req: VerifySignatureRequest =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&req).map_err(validation_err)?;
+            client
+                .verify_signature(&req)
+                .map(|valid| ok(&json!({ "public_key": req.public_key, "valid": valid })))
+                .map_err(|e| err(&e.to_string()))
+        }
         "get_account_balance" => {
-            let addr = args["address"]
-                .as_str()
-                .ok_or_else(|| err("missing address"))?;
-            let url = match args["network"].as_str() {
-                Some("testnet") => env.var("AMADEUS_TESTNET_RPC").map(|v| v.to_string()).unwrap_or_else(|_| "https://testnet.amadeus.bot".to_string()),
-                _ => rpc.to_string(),
-            };
+            let query: AccountQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
+            client
+                .get_account_balance(&query.address, &url)
+                .await
+                .map(|b| ok(&b))
+                .map_err(|e| err(&e.to_string()))
+        }
+        "get_balance_at_height" => {
+            let query: BalanceAtHeightQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
+            client
+                .get_balance_at_height(&query.address, query.height, &url)
+                .await
+                .map(|b| ok(&b))
+                .map_err(|e| err(&e.to_string()))
+        }
+        "get_balance_for_asset" => {
+            let query: AccountAssetQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
             client
-                .get_account_balance(addr, &url)
+                .get_balance_for_symbol(&query.address, &query.symbol, &url)
                 .await
                 .map(|b| ok(&b))
                 .map_err(|e| err(&e.to_string()))
         }
+        "get_balances" => {
+            let query: BalancesQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
+            client
+                .get_balances(&query.addresses, &url)
+                .await
+                .map(|b| ok(&b))
+                .map_err(|e| err(&e.to_string()))
+        }
+        "get_account_summary" => {
+            let query: AccountSummaryQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
+            client
+                .get_account_summary(&query.address, query.recent.unwrap_or(10), &url)
+                .await
+                .map(|s| ok(&s))
+                .map_err(|e| err(&e.to_string()))
+        }
+        "list_assets" => {
+            let query: ListAssetsQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
+            client
+                .list_assets(&url)
+                .await
+                .map(|a| ok(&json!({ "assets": a, "count": a.len() })))
+                .map_err(|e| err(&e.to_string()))
+        }
+        "get_asset_info" => {
+            let query: AssetQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
+            client
+                .get_asset_info(&query.symbol, &url)
+                .await
+                .map(|a| ok(&a))
+                .map_err(|e| err(&e.to_string()))
+        }
+        "get_supply" => {
+            let query: AssetQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
+            client
+                .get_supply(&query.symbol, &url)
+                .await
+                .map(|a| ok(&a))
+                .map_err(|e| err(&e.to_string()))
+        }
+        "get_top_holders" => {
+            let query: TopHoldersQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
+            client
+                .get_top_holders(&query.symbol, query.limit.unwrap_or(DEFAULT_TOP_HOLDERS), &url)
+                .await
+                .map(|h| ok(&h))
+                .map_err(|e| err(&e.to_string()))
+        }
+        "ping" => {
+            let testnet = rpc_url(env, Some("testnet"));
+            Ok(ok(&ServerInfo::new(rpc, &testnet, None)))
+        }
         "get_chain_stats" => {
-            let url = match args["network"].as_str() {
-                Some("testnet") => env.var("AMADEUS_TESTNET_RPC").map(|v| v.to_string()).unwrap_or_else(|_| "https://testnet.amadeus.bot".to_string()),
-                _ => rpc.to_string(),
-            };
+            let query: ChainStatsQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
             client
                 .get_chain_stats(&url)
                 .await
                 .map(|s| ok(&s))
                 .map_err(|e| err(&e.to_string()))
         }
+        "get_fee_schedule" => {
+            let query: FeeScheduleQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
+            client
+                .get_fee_schedule(&url)
+                .await
+                .map(|s| ok(&s))
+                .map_err(|e| err(&e.to_string()))
+        }
+        "network_info" => {
+            let query: NetworkInfoQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
+            client
+                .get_network_info(&url)
+                .await
+                .map(|i| ok(&i))
+                .map_err(|e| err(&e.to_string()))
+        }
         "get_block_by_height" => {
-            let height = args["height"]
-                .as_u64()
-                .ok_or_else(|| err("missing height"))?;
-            let url = match args["network"].as_str() {
-                Some("testnet") => env.var("AMADEUS_TESTNET_RPC").map(|v| v.to_string()).unwrap_or_else(|_| "https://testnet.amadeus.bot".to_string()),
-                _ => rpc.to_string(),
-            };
+            let query: HeightQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
+            client
+                .get_block_by_height(query.height, &url)
+                .await
+                .map(|e| ok(&e))
+                .map_err(|e| err(&e.to_string()))
+        }
+        "get_block_by_hash" => {
+            let query: BlockHashQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
             client
-                .get_block_by_height(height, &url)
+                .get_block_by_hash(&query.hash, &url)
                 .await
                 .map(|e| ok(&e))
                 .map_err(|e| err(&e.to_string()))
         }
+        "get_block_range" => {
+            let query: BlockRangeQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
+            let strict = query.strict.unwrap_or(false);
+            client
+                .get_block_range(query.from_height, query.to_height, strict, &url)
+                .await
+                .map(|b| ok(&b))
+                .map_err(|e| err(&e.to_string()))
+        }
+        "get_latest_block" => {
+            let query: LatestBlockQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
+            client
+                .get_latest_block(&url)
+                .await
+                .map(|b| ok(&b))
+                .map_err(|e| err(&e.to_string()))
+        }
         "get_transaction" => {
-            let hash = args["tx_hash"]
-                .as_str()
-                .ok_or_else(|| err("missing tx_hash"))?;
-            let url = match args["network"].as_str() {
-                Some("testnet") => env.var("AMADEUS_TESTNET_RPC").map(|v| v.to_string()).unwrap_or_else(|_| "https://testnet.amadeus.bot".to_string()),
-                _ => rpc.to_string(),
-            };
+            let query: TransactionQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
+            client
+                .get_transaction(&query.tx_hash, &url)
+                .await
+                .map(|t| ok(&t))
+                .map_err(|e| err(&e.to_string()))
+        }
+        "get_transaction_by_position" => {
+            let query: TransactionPositionQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
             client
-                .get_transaction(hash, &url)
+                .get_transaction_by_position(query.height, query.index, &url)
                 .await
                 .map(|t| ok(&t))
                 .map_err(|e| err(&e.to_string()))
         }
+        "get_transaction_status" => {
+            let query: TransactionQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
+            client
+                .get_transaction_status(&query.tx_hash, &url)
+                .await
+                .map(|s| ok(&s))
+                .map_err(|e| err(&e.to_string()))
+        }
+        "wait_for_transaction" => {
+            let query: WaitForTransactionQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
+            let timeout = std::time::Duration::from_secs(query.timeout_secs.unwrap_or(60));
+            client
+                .wait_for_transaction(&query.tx_hash, timeout, &url)
+                .await
+                .map(|w| ok(&w))
+                .map_err(|e| err(&e.to_string()))
+        }
+        "wait_for_next_block" => {
+            let query: WaitForBlockQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
+            let timeout = std::time::Duration::from_secs(query.timeout_secs.unwrap_or(60));
+            client
+                .wait_for_next_block(query.after_height, timeout, &url)
+                .await
+                .map(|w| ok(&w))
+                .map_err(|e| err(&e.to_string()))
+        }
+        "get_transaction_receipt" => {
+            let query: TransactionQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
+            client
+                .get_transaction_receipt(&query.tx_hash, &url)
+                .await
+                .map(|r| ok(&r))
+                .map_err(|e| err(&e.to_string()))
+        }
+        "get_pending_transactions" => {
+            let query: PendingTransactionsQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
+            client
+                .get_pending_transactions(query.address.as_deref(), &url)
+                .await
+                .map(|t| ok(&json!({ "transactions": t, "count": t.len() })))
+                .map_err(|e| err(&e.to_string()))
+        }
         "get_transaction_history" => {
-            let addr = args["address"]
-                .as_str()
-                .ok_or_else(|| err("missing address"))?;
-            let limit = args["limit"].as_u64().map(|v| v as u32);
-            let offset = args["offset"].as_u64().map(|v| v as u32);
-            let sort = args["sort"].as_str();
-            let url = match args["network"].as_str() {
-                Some("testnet") => env.var("AMADEUS_TESTNET_RPC").map(|v| v.to_string()).unwrap_or_else(|_| "https://testnet.amadeus.bot".to_string()),
-                _ => rpc.to_string(),
+            let query: TransactionHistoryQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let addr = &query.address;
+            let limit = query.limit;
+            let offset = query.offset;
+            let sort = query.sort.as_deref();
+            let url = rpc_url(env, query.network.as_deref());
+            let result = if query.fetch_all.unwrap_or(false) {
+                let cap = limit.map_or(1000, |l| l as usize);
+                client.get_transaction_history_all(addr, cap, sort, &url).await
+                    .map(|txs| TransactionHistoryPage::new(txs, Some(cap as u32), None))
+            } else {
+                client.get_transaction_history(addr, limit, offset, sort, &url).await
             };
+            result.map(|t| ok(&t)).map_err(|e| err(&e.to_string()))
+        }
+        "get_account_transactions_since" => {
+            let query: TransactionsSinceQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
             client
-                .get_transaction_history(addr, limit, offset, sort, &url)
+                .get_transactions_since(&query.address, query.min_height, &url)
                 .await
                 .map(|t| ok(&t))
                 .map_err(|e| err(&e.to_string()))
         }
+        "search_transactions" => {
+            let query: SearchTransactionsQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
+            client
+                .search_transactions(&query, &url)
+                .await
+                .map(|r| ok(&r))
+                .map_err(|e| err(&e.to_string()))
+        }
         "get_validators" => {
-            let url = match args["network"].as_str() {
-                Some("testnet") => env.var("AMADEUS_TESTNET_RPC").map(|v| v.to_string()).unwrap_or_else(|_| "https://testnet.amadeus.bot".to_string()),
-                _ => rpc.to_string(),
-            };
+            let query: ValidatorsQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
+            if query.detailed.unwrap_or(false) {
+                return client
+                    .get_validators_detailed(&url)
+                    .await
+                    .map(|v| ok(&json!({ "validators": v, "count": v.len() })))
+                    .map_err(|e| err(&e.to_string()));
+            }
             client
                 .get_validators(&url)
                 .await
                 .map(|v| ok(&json!({ "validators": v, "count": v.len() })))
                 .map_err(|e| err(&e.to_string()))
         }
+        "get_validator_details" => {
+            let query: ValidatorDetailsQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
+            client
+                .get_validator_details(&query.validator, &url)
+                .await
+                .map(|d| ok(&d))
+                .map_err(|e| err(&e.to_string()))
+        }
         "get_contract_state" => {
-            let addr = args["contract_address"]
-                .as_str()
-                .ok_or_else(|| err("missing contract_address"))?;
-            let key = args["key"].as_str().ok_or_else(|| err("missing key"))?;
-            let url = match args["network"].as_str() {
-                Some("testnet") => env.var("AMADEUS_TESTNET_RPC").map(|v| v.to_string()).unwrap_or_else(|_| "https://testnet.amadeus.bot".to_string()),
-                _ => rpc.to_string(),
-            };
+            let query: ContractStateQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let (addr, key) = (&query.contract_address, &query.key);
+            let url = rpc_url(env, query.network.as_deref());
             client
                 .get_contract_state(addr, key, &url)
                 .await
+                .and_then(|s| match query.decode_as {
+                    Some(decoding) => crate::blockchain::shared::decode_state_value(&s, decoding),
+                    None => Ok(s),
+                })
                 .map(|s| ok(&json!({ "contract_address": addr, "key": key, "value": s })))
                 .map_err(|e| err(&e.to_string()))
         }
+        "simulate_contract_call" => {
+            let req: ContractCallRequest =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&req).map_err(validation_err)?;
+            let url = rpc_url(env, req.network.as_deref());
+            client
+                .simulate_call(&req, &url)
+                .await
+                .map(|c| ok(&c))
+                .map_err(|e| err(&e.to_string()))
+        }
+        "get_contract_states" => {
+            let query: ContractStatesQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
+            client
+                .get_contract_states(&query.contract_address, &query.keys, &url)
+                .await
+                .map(|s| ok(&s))
+                .map_err(|e| err(&e.to_string()))
+        }
+        "batch_query" => batch_query(client, env, rpc, args).await,
+        "resolve_name" => {
+            let query: ResolveNameQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
+            client
+                .resolve_name(&query.name, &url)
+                .await
+                .map(|a| ok(&json!({ "name": query.name, "address": a })))
+                .map_err(|e| err(&e.to_string()))
+        }
+        "reverse_resolve" => {
+            let query: ReverseResolveQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
+            client
+                .reverse_resolve(&query.address, &url)
+                .await
+                .map(|n| ok(&json!({ "address": query.address, "name": n })))
+                .map_err(|e| err(&e.to_string()))
+        }
+        "get_contract_code" => {
+            let query: ContractCodeQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
+            client
+                .get_contract_code(&query.contract_address, &url)
+                .await
+                .map(|c| ok(&c))
+                .map_err(|e| err(&e.to_string()))
+        }
+        "list_contract_keys" => {
+            let query: ContractKeysQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            let url = rpc_url(env, query.network.as_deref());
+            client
+                .list_contract_keys(&query, &url)
+                .await
+                .map(|k| ok(&k))
+                .map_err(|e| err(&e.to_string()))
+        }
         "claim_testnet_ama" => claim_testnet_ama(env, client_ip, headers, args).await,
-        "get_entry_tip" => fetch_json(&format!("{rpc}/api/chain/tip")).await,
+        "get_entry_tip" => fetch_json(client, rpc, "/api/chain/tip").await,
         "get_entry_by_hash" => {
-            let h = args["hash"].as_str().ok_or_else(|| err("missing hash"))?;
-            fetch_json(&format!("{rpc}/api/chain/hash/{h}")).await
+            let query: BlockHashQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            // validated above, so the hash converts
+            let hash = block_hash_b58(&query.hash).unwrap_or(query.hash);
+            fetch_json(client, rpc, &format!("/api/chain/hash/{}", hash)).await
         }
         "get_block_with_txs" => {
             let h = args["height"].as_u64().ok_or_else(|| err("missing height"))?;
-            fetch_json(&format!("{rpc}/api/chain/height_with_txs/{h}")).await
+            fetch_json(client, rpc, &format!("/api/chain/height_with_txs/{h}")).await
         }
         "get_txs_in_entry" => {
             let h = args["entry_hash"].as_str().ok_or_else(|| err("missing entry_hash"))?;
-            fetch_json(&format!("{rpc}/api/chain/txs_in_entry/{h}")).await
+            fetch_json(client, rpc, &format!("/api/chain/txs_in_entry/{h}")).await
         }
         "get_epoch_score" => {
-            let url = match args["address"].as_str() {
-                Some(pk) => format!("{rpc}/api/epoch/score/{pk}"),
-                None => format!("{rpc}/api/epoch/score"),
+            let path = match args["address"].as_str().map(normalize_address) {
+                Some(pk) => format!("/api/epoch/score/{pk}"),
+                None => "/api/epoch/score".to_string(),
             };
-            fetch_json(&url).await
+            fetch_json(client, rpc, &path).await
         }
         "get_emission_address" => {
-            let pk = args["address"].as_str().ok_or_else(|| err("missing address"))?;
-            fetch_json(&format!("{rpc}/api/epoch/get_emission_address/{pk}")).await
+            let query: AccountQuery =
+                serde_json::from_value(args.clone()).map_err(|e| err(&e.to_string()))?;
+            validator::Validate::validate(&query).map_err(validation_err)?;
+            fetch_json(client, rpc, &format!("/api/epoch/get_emission_address/{}", query.address)).await
         }
-        "get_richlist" => fetch_json(&format!("{rpc}/api/contract/richlist")).await,
-        "get_nodes" => fetch_json(&format!("{rpc}/api/peer/nodes")).await,
-        "get_removed_validators" => fetch_json(&format!("{rpc}/api/peer/removed_trainers")).await,
+        "get_richlist" => fetch_json(client, rpc, "/api/contract/richlist").await,
+        "get_nodes" => fetch_json(client, rpc, "/api/peer/nodes").await,
+        "get_removed_validators" => fetch_json(client, rpc, "/api/peer/removed_trainers").await,
         _ => Err(err("unknown tool")),
     }
 }
 
+const BATCH_MAX_REQUESTS: usize = 20;
+const BATCH_CONCURRENCY: usize = 4;
+const BATCH_TOOLS: &[&str] = &[
+    "get_account_balance", "get_balance_at_height", "get_balance_for_asset", "get_balances", "get_account_summary", "list_assets", "get_asset_info", "get_supply", "get_top_holders", "get_chain_stats", "get_fee_schedule", "network_info",
+    "get_block_by_height", "get_block_by_hash", "get_block_range", "get_latest_block", "get_transaction", "get_transaction_by_position",
+    "get_transaction_status", "get_transaction_receipt", "get_pending_transactions", "get_transaction_history", "get_account_transactions_since",
+    "get_validators", "get_validator_details", "get_contract_state", "get_contract_states", "simulate_contract_call", "get_contract_code",
+    "list_contract_keys",
+];
+
+async fn batch_query(
+    client: &BlockchainClient, env: &Env, rpc: &str, args: &Value,
+) -> std::result::Result<Value, Value> {
+    use futures::{stream, StreamExt};
+
+    let requests = args["requests"]
+        .as_array()
+        .ok_or_else(|| err("missing requests"))?;
+    if requests.is_empty() || requests.len() > BATCH_MAX_REQUESTS {
+        return Err(err(&format!("requests must contain 1 to {} entries", BATCH_MAX_REQUESTS)));
+    }
+
+    let results: Vec<Value> = stream::iter(requests)
+        .map(|req| async move {
+            let tool = req["tool"].as_str().unwrap_or("");
+            if !BATCH_TOOLS.contains(&tool) {
+                return json!({ "error": err(&format!("unsupported batch tool: {}", tool)) });
+            }
+            let mut params = req["params"].clone();
+            if params.is_null() {
+                params = json!({});
+            }
+            if let (Some(network), Some(obj)) = (args["network"].as_str(), params.as_object_mut()) {
+                obj.entry("network").or_insert_with(|| json!(network));
+            }
+            let call = json!({ "name": tool, "arguments": params });
+            match Box::pin(handle_tool_call(client, env, rpc, None, HashMap::new(), &call)).await {
+                Ok(result) => json!({ "result": result }),
+                Err(e) => json!({ "error": e }),
+            }
+        })
+        .buffered(BATCH_CONCURRENCY)
+        .collect()
+        .await;
+
+    Ok(ok(&json!({ "results": results, "count": results.len() })))
+}
+
 fn tools_list() -> Value {
     json!({ "tools": [
         tool("create_transaction", "Creates unsigned transaction for any contract call",
@@ -502,25 +1001,135 @@ fn tools_list() -> Value {
                 "args": { "type": "array" },
                 "attached_symbol": str_prop(),
                 "attached_amount": str_prop(),
-                "nonce": { "type": "number" }
+                "nonce": { "type": "number" },
+                "preflight_balance_check": { "type": "boolean" },
+                "network": str_prop()
             }),
             vec!["signer", "contract", "function", "args"]),
+        tool("create_transfer", "Creates unsigned transfer; amount in atoms or amount_display in whole units",
+            json!({
+                "signer": str_prop(),
+                "receiver": str_prop(),
+                "symbol": str_prop(),
+                "amount": str_prop(),
+                "amount_display": str_prop(),
+                "memo": str_prop(),
+                "nonce": { "type": "number" },
+                "preflight_balance_check": { "type": "boolean" },
+                "network": str_prop()
+            }),
+            vec!["signer", "receiver"]),
+        tool("create_mint", "Creates unsigned mint transaction (only authorized signers can submit it)",
+            json!({ "signer": str_prop(), "symbol": str_prop(), "amount": { "type": "number" }, "nonce": { "type": "number" } }),
+            vec!["signer", "symbol", "amount"]),
+        tool("create_contract_deploy", "Creates unsigned transaction deploying WASM contract bytecode (hex) with optional constructor args",
+            json!({ "signer": str_prop(), "code_hex": str_prop(), "args": { "type": "array" }, "nonce": { "type": "number" } }),
+            vec!["signer", "code_hex"]),
+        tool("create_stake", "Creates unsigned transaction delegating stake to a validator",
+            json!({ "signer": str_prop(), "validator": str_prop(), "symbol": str_prop(), "amount": { "type": "number" } }),
+            vec!["signer", "validator", "symbol", "amount"]),
+        tool("create_unstake", "Creates unsigned transaction withdrawing stake from a validator",
+            json!({ "signer": str_prop(), "validator": str_prop(), "symbol": str_prop(), "amount": { "type": "number" } }),
+            vec!["signer", "validator", "symbol", "amount"]),
+        tool("decode_transaction", "Decodes a transaction blob into its signer, nonce, contract, function, and arguments",
+            json!({ "transaction": str_prop() }), vec!["transaction"]),
+        tool("compute_transaction_hash", "Computes a transaction's on-chain id offline from an unsigned blob or signed envelope",
+            json!({ "transaction": str_prop() }), vec!["transaction"]),
+        tool("verify_transaction_blob", "Checks a signed transaction's stored hash and signature offline before submitting",
+            json!({ "transaction": str_prop() }), vec!["transaction"]),
         tool("submit_transaction", "Submits a signed transaction to the blockchain network",
-            json!({ "transaction": str_prop(), "signature": str_prop(), "network": str_prop() }), vec!["transaction", "signature"]),
+            json!({ "transaction": str_prop(), "signature": str_prop(), "check_signature": { "type": "boolean" }, "dry_run": { "type": "boolean" }, "network": str_prop() }), vec!["transaction", "signature"]),
+        tool("submit_raw_transaction", "Broadcasts a fully signed, packed base58 transaction built off-platform",
+            json!({ "transaction": str_prop(), "network": str_prop() }), vec!["transaction"]),
+        tool("verify_signature", "Verifies a BLS12-381 signature over a transaction signing payload",
+            json!({ "public_key": str_prop(), "signing_payload": str_prop(), "signature": str_prop() }),
+            vec!["public_key", "signing_payload", "signature"]),
         tool("get_account_balance", "Queries the balance of an account across all supported assets",
             json!({ "address": str_prop() }), vec!["address"]),
+        tool("get_balance_at_height", "Queries an account's balances as of a past block height; unsupported on nodes without historical state",
+            json!({ "address": str_prop(), "height": { "type": "number" } }), vec!["address", "height"]),
+        tool("get_balance_for_asset", "Returns one account's balance of a single asset in atoms, or zero when it holds none",
+            json!({ "address": str_prop(), "symbol": str_prop() }), vec!["address", "symbol"]),
+        tool("get_balances", "Queries the balances of up to 50 accounts at once; a failing address is reported without failing the rest",
+            json!({ "addresses": { "type": "array", "items": str_prop(), "maxItems": 50 } }), vec!["addresses"]),
+        tool("get_account_summary", "Returns balances, nonce, and the most recent transactions of an account in one call",
+            json!({ "address": str_prop(), "recent": { "type": "number" } }), vec!["address"]),
+        tool("list_assets", "Lists all assets with their decimals and total supply", json!({}), vec![]),
+        tool("get_asset_info", "Retrieves decimals and total supply of a single asset",
+            json!({ "symbol": str_prop() }), vec!["symbol"]),
+        tool("get_supply", "Retrieves total, circulating, burned, and locked supply of an asset in atoms",
+            json!({ "symbol": str_prop() }), vec!["symbol"]),
+        tool("get_top_holders", "Lists the largest AMA holders, ranked by balance in atoms (limit 1-100, default 20); other symbols are unsupported",
+            json!({ "symbol": str_prop(), "limit": { "type": "number" } }), vec!["symbol"]),
+        tool("ping", "Checks the server is alive and reports its version and configured node hosts without contacting the blockchain",
+            json!({}), vec![]),
         tool("get_chain_stats", "Retrieves current blockchain statistics", json!({}), vec![]),
+        tool("get_fee_schedule", "Returns base, per-byte, and per-execution-unit fee rates in atoms", json!({}), vec![]),
+        tool("network_info", "Returns the node's chain id, protocol version, genesis hash, and node software version", json!({}), vec![]),
         tool("get_block_by_height", "Retrieves blockchain entries at a specific height",
             json!({ "height": { "type": "number" } }), vec!["height"]),
+        tool("get_block_by_hash", "Retrieves blockchain entries by entry hash (base58 or hex)",
+            json!({ "hash": str_prop() }), vec!["hash"]),
+        tool("get_block_range", "Retrieves entries for each height in an inclusive, bounded range",
+            json!({ "from_height": { "type": "number" }, "to_height": { "type": "number" }, "strict": { "type": "boolean" } }), vec!["from_height", "to_height"]),
+        tool("get_latest_block", "Retrieves the newest block height with all its entries", json!({}), vec![]),
         tool("get_transaction", "Retrieves a specific transaction by its hash",
             json!({ "tx_hash": str_prop() }), vec!["tx_hash"]),
+        tool("get_transaction_by_position", "Retrieves the index-th transaction (zero-based) at a block height, with the height's transaction count",
+            json!({ "height": { "type": "number" }, "index": { "type": "number" } }), vec!["height", "index"]),
+        tool("wait_for_transaction", "Waits with backoff until a transaction is confirmed or failed, or the timeout passes",
+            json!({ "tx_hash": str_prop(), "timeout_secs": { "type": "number" }, "network": str_prop() }), vec!["tx_hash"]),
+        tool("wait_for_next_block", "Waits with backoff until the chain tip is above after_height, or the timeout passes",
+            json!({ "after_height": { "type": "number" }, "timeout_secs": { "type": "number" }, "network": str_prop() }), vec!["after_height"]),
+        tool("get_transaction_status", "Returns pending, confirmed, failed, or unknown status for a transaction hash",
+            json!({ "tx_hash": str_prop() }), vec!["tx_hash"]),
+        tool("get_transaction_receipt", "Returns an included transaction's execution result, cost, and emitted events",
+            json!({ "tx_hash": str_prop() }), vec!["tx_hash"]),
+        tool("get_pending_transactions", "Lists unconfirmed mempool transactions, optionally for one signer",
+            json!({ "address": str_prop() }), vec![]),
         tool("get_transaction_history", "Retrieves transaction history for a specific account",
-            json!({ "address": str_prop(), "limit": { "type": "number" }, "offset": { "type": "number" }, "sort": str_prop() }), vec!["address"]),
-        tool("get_validators", "Retrieves the list of current validator nodes", json!({}), vec![]),
+            json!({ "address": str_prop(), "limit": { "type": "number" }, "offset": { "type": "number" }, "sort": str_prop(), "fetch_all": { "type": "boolean" } }), vec!["address"]),
+        tool("get_account_transactions_since", "Returns an account's transactions at or above a block height, oldest first, with the next watermark",
+            json!({ "address": str_prop(), "min_height": { "type": "number" } }), vec!["address", "min_height"]),
+        tool("search_transactions", "Searches transactions by height range, symbol, min amount, and counterparty (bounded scan)",
+            json!({
+                "from_height": { "type": "number" }, "to_height": { "type": "number" },
+                "symbol": str_prop(), "min_amount": str_prop(), "counterparty": str_prop(),
+                "limit": { "type": "number" }, "offset": { "type": "number" }
+            }),
+            vec![]),
+        tool("get_validators", "Retrieves the list of current validator nodes",
+            json!({ "detailed": { "type": "boolean" } }), vec![]),
+        tool("get_validator_details", "Retrieves stake, score, uptime, and operator for a validator",
+            json!({ "validator": str_prop() }), vec!["validator"]),
         tool("get_contract_state", "Retrieves a specific value from smart contract storage",
-            json!({ "contract_address": str_prop(), "key": str_prop() }), vec!["contract_address", "key"]),
-        tool("claim_testnet_ama", "Claims testnet AMA tokens to the specified address (once per 24 hours per IP)",
+            json!({ "contract_address": str_prop(), "key": str_prop(), "decode_as": { "type": "string", "enum": ["i128", "string", "bytes_hex", "json"] } }), vec!["contract_address", "key"]),
+        tool("simulate_contract_call", "Executes a read-only contract function and returns its result or revert reason without signing",
+            json!({
+                "contract": str_prop(),
+                "function": str_prop(),
+                "args": { "type": "array" },
+                "caller": str_prop(),
+                "decode_as": { "type": "string", "enum": ["i128", "string", "bytes_hex", "json"] },
+                "network": str_prop()
+            }),
+            vec!["contract", "function"]),
+        tool("get_contract_states", "Reads up to 50 storage keys of one contract at once; a failing key is reported without failing the rest",
+            json!({ "contract_address": str_prop(), "keys": { "type": "array", "items": str_prop(), "maxItems": 50 } }), vec!["contract_address", "keys"]),
+        tool("resolve_name", "Resolves a human-readable account name to its address",
+            json!({ "name": str_prop() }), vec!["name"]),
+        tool("reverse_resolve", "Looks up the name registered for an account address",
             json!({ "address": str_prop() }), vec!["address"]),
+        tool("get_contract_code", "Retrieves a deployed contract's code hash, owner, and callable functions",
+            json!({ "contract_address": str_prop() }), vec!["contract_address"]),
+        tool("list_contract_keys", "Lists smart contract storage keys with optional prefix filter and cursor pagination",
+            json!({ "contract_address": str_prop(), "prefix": str_prop(), "cursor": str_prop(), "limit": { "type": "number" } }),
+            vec!["contract_address"]),
+        tool("batch_query", "Runs several read-only queries concurrently; results are returned in request order",
+            json!({ "requests": { "type": "array", "items": { "type": "object", "properties": { "tool": str_prop(), "params": { "type": "object" } }, "required": ["tool"] } }, "network": str_prop() }),
+            vec!["requests"]),
+        tool("claim_testnet_ama", "Claims testnet AMA tokens to the specified address (once per 24 hours per IP)",
+            json!({ "address": str_prop(), "symbol": str_prop(), "verification_token": str_prop() }), vec!["address"]),
         tool("get_entry_tip", "Get the latest blockchain entry", json!({}), vec![]),
         tool("get_entry_by_hash", "Get entry by hash", json!({ "hash": str_prop() }), vec!["hash"]),
         tool("get_block_with_txs", "Get block at height with full transactions", json!({ "height": { "type": "number" } }), vec!["height"]),
@@ -543,16 +1152,27 @@ fn str_prop() -> Value {
 fn err(msg: &str) -> Value {
     json!({ "code": -32603, "message": msg })
 }
+fn validation_err(errors: validator::ValidationErrors) -> Value {
+    json!({ "code": -32602, "message": "validation_failed", "data": { "errors": FieldError::from_errors(&errors) } })
+}
 fn ok<T: serde::Serialize>(data: &T) -> Value {
     json!({ "content": [{ "type": "text", "text": serde_json::to_string_pretty(data).unwrap() }] })
 }
 
-async fn fetch_json(url: &str) -> std::result::Result<Value, Value> {
-    let mut resp = worker::Fetch::Url(worker::Url::parse(url).map_err(|e| err(&e.to_string()))?)
-        .send().await.map_err(|e| err(&e.to_string()))?;
-    let json: Value = serde_json::from_str(&resp.text().await.map_err(|e| err(&e.to_string()))?)
-        .map_err(|e| err(&e.to_string()))?;
-    Ok(ok(&json))
+/// Node URL for a request's `network`: `AMADEUS_TESTNET_RPC` for "testnet",
+/// else `BLOCKCHAIN_URL`.
+fn rpc_url(env: &Env, network: Option<&str>) -> String {
+    let (var, default) = match network {
+        Some("testnet") => ("AMADEUS_TESTNET_RPC", "https://testnet.amadeus.bot"),
+        _ => ("BLOCKCHAIN_URL", "https://nodes.amadeus.bot"),
+    };
+    env.var(var).map(|v| v.to_string()).unwrap_or_else(|_| default.to_string())
+}
+
+/// Goes through the client so passthrough tools get the same auth header,
+/// User-Agent, size cap and content-type checks as every other tool.
+async fn fetch_json(client: &BlockchainClient, rpc: &str, path: &str) -> std::result::Result<Value, Value> {
+    client.get_raw(path, rpc).await.map(|json| ok(&json)).map_err(|e| err(&e.to_string()))
 }
 
 const CLAIM_COOLDOWN_SECS: f64 = 86400.0;
@@ -573,6 +1193,8 @@ async fn claim_testnet_ama(
     let address = args["address"]
         .as_str()
         .ok_or_else(|| err("missing address"))?;
+    let address = normalize_address(address);
+    let address = address.as_str();
     let now = (Date::now().as_millis() / 1000) as f64;
 
     let db = env.d1("MCP_DATABASE").map_err(|e| err(&e.to_string()))?;
@@ -606,7 +1228,21 @@ async fn claim_testnet_ama(
         }
     }
 
-    let tx_hash = mint::transfer(env, address).await?;
+    let submit = mint::transfer(
+        env,
+        address,
+        args["symbol"].as_str(),
+        args["verification_token"].as_str(),
+    )
+    .await?;
+    if !submit.accepted {
+        return Ok(ok(&json!({
+            "status": "rejected",
+            "message": format!("node rejected the faucet transaction: {}", submit.node_status),
+            "tx_hash": submit.tx_hash,
+            "submit": submit
+        })));
+    }
 
     if existing.is_some() {
         db.prepare("UPDATE faucet_claims SET claimed_at = ?1, address = ?2 WHERE ip = ?3")
@@ -624,7 +1260,7 @@ async fn claim_testnet_ama(
             .map_err(|e| err(&e.to_string()))?;
     }
 
-    Ok(ok(&json!({ "status": "success", "tx_hash": tx_hash })))
+    Ok(ok(&json!({ "status": "success", "tx_hash": submit.tx_hash, "submit": submit })))
 }
 
 }
@@ -1,5 +1,7 @@
+use crate::blockchain::amount::Amount;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
+use std::sync::atomic::{AtomicI64, Ordering};
 
 mod args_serde {
     use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -16,7 +18,15 @@ mod args_serde {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// The only `op` nodes accept. Deploying a contract is itself a call, to
+/// `DEPLOY_CONTRACT`'s `deploy` function.
+pub const CALL_OP: &str = "call";
+
+/// Built-in contract that deploys WASM bytecode to the signer's account.
+pub const DEPLOY_CONTRACT: &str = "Contract";
+pub const DEPLOY_FUNCTION: &str = "deploy";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TxAction {
     #[serde(with = "args_serde")]
     pub args: Vec<Vec<u8>>,
@@ -29,7 +39,7 @@ pub struct TxAction {
     pub attached_amount: Option<Vec<u8>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Tx {
     pub action: TxAction,
     pub nonce: i128,
@@ -37,12 +47,82 @@ pub struct Tx {
     pub signer: Vec<u8>,
 }
 
+pub const SIG_DST: &[u8] = b"AMADEUS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_TX_";
+
+/// Chain-specific signing settings. The default domain separation tag is
+/// `SIG_DST`; a network or protocol version that uses another tag overrides it.
+#[derive(Debug, Clone)]
+pub struct SigningParams {
+    pub dst: Vec<u8>,
+}
+
+impl Default for SigningParams {
+    fn default() -> Self {
+        Self {
+            dst: SIG_DST.to_vec(),
+        }
+    }
+}
+
+impl SigningParams {
+    /// Uses `dst` when set (e.g. from an `AMADEUS_SIGNING_DST` variable), else the default.
+    pub fn from_dst(dst: Option<&str>) -> Self {
+        match dst {
+            Some(d) if !d.is_empty() => Self {
+                dst: d.as_bytes().to_vec(),
+            },
+            _ => Self::default(),
+        }
+    }
+}
+
+/// What a signer signs for one transaction: its `hash_tx` hash under a domain
+/// separation tag. The create tools return the hash as hex `signing_payload`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SigningPayload {
+    pub hash: [u8; 32],
+    pub dst: Vec<u8>,
+}
+
+impl SigningPayload {
+    pub fn new(hash: [u8; 32], params: &SigningParams) -> Self {
+        Self {
+            hash,
+            dst: params.dst.clone(),
+        }
+    }
+
+    /// Payload of an unsigned blob, hashed as given.
+    pub fn from_blob(blob: &[u8], params: &SigningParams) -> Result<Self, &'static str> {
+        Ok(Self::new(compute_transaction_hash(blob)?, params))
+    }
+
+    /// Parses a `signing_payload` as returned by the create tools, with or without `0x`.
+    pub fn from_hex(payload: &str, params: &SigningParams) -> Result<Self, &'static str> {
+        let bytes = hex::decode(payload.trim_start_matches("0x"))
+            .map_err(|_| "invalid signing payload hex")?;
+        let hash = bytes
+            .try_into()
+            .map_err(|_| "signing payload must be 32 bytes")?;
+        Ok(Self::new(hash, params))
+    }
+
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.hash)
+    }
+
+    /// Whether `signature` is `signer`'s BLS signature over this payload.
+    pub fn verify(&self, signer: &[u8], signature: &[u8]) -> Result<bool, &'static str> {
+        verify(signer, &self.hash, signature, &self.dst)
+    }
+}
+
 pub struct UnsignedTx {
     pub tx_blob: Vec<u8>,
     pub signing_hash: [u8; 32],
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 struct TxU {
     #[serde(with = "serde_bytes")]
     hash: Vec<u8>,
@@ -56,11 +136,70 @@ pub struct FinalizedTx {
     pub hash: [u8; 32],
 }
 
-pub fn finalize_transaction(tx_blob_b58: &str, signature_b58: &str) -> Result<FinalizedTx, &'static str> {
-    let tx_encoded = bs58::decode(tx_blob_b58).into_vec().map_err(|_| "invalid blob base58")?;
-    let signature = bs58::decode(signature_b58).into_vec().map_err(|_| "invalid signature base58")?;
+pub struct DecodedTx {
+    pub tx: Tx,
+    pub hash: Option<Vec<u8>>,
+    pub signature: Option<Vec<u8>>,
+}
+
+/// Decodes either an unsigned `Tx` blob or a signed `TxU` envelope.
+pub fn decode(blob: &[u8]) -> Result<DecodedTx, &'static str> {
+    if let Ok(tx) = vecpak::from_slice::<Tx>(blob) {
+        return Ok(DecodedTx {
+            tx,
+            hash: None,
+            signature: None,
+        });
+    }
+    let txu: TxU = vecpak::from_slice(blob).map_err(|_| "failed to decode tx")?;
+    Ok(DecodedTx {
+        tx: txu.tx,
+        hash: Some(txu.hash),
+        signature: Some(txu.signature),
+    })
+}
+
+/// Transaction id convention: the full 32-byte SHA-256 of the vecpak-encoded
+/// `Tx`, never of the signed `TxU` envelope and never truncated. It is also
+/// the message the signer signs. Every id is computed here, over the exact
+/// bytes that are signed or submitted.
+pub fn hash_tx_bytes(tx_encoded: &[u8]) -> [u8; 32] {
+    Sha256::digest(tx_encoded).into()
+}
+
+/// `hash_tx_bytes` of `tx`'s encoding, for transactions built in memory.
+pub fn hash_tx(tx: &Tx) -> Result<[u8; 32], &'static str> {
+    let tx_encoded = vecpak::to_vec(tx).map_err(|_| "failed to encode tx")?;
+    Ok(hash_tx_bytes(&tx_encoded))
+}
+
+/// Id of an unsigned `Tx` blob or a signed `TxU` envelope. An unsigned blob
+/// is hashed as given; the `Tx` inside an envelope has no bytes of its own,
+/// so it is re-encoded.
+pub fn compute_transaction_hash(blob: &[u8]) -> Result<[u8; 32], &'static str> {
+    let decoded = decode(blob)?;
+    if decoded.signature.is_none() {
+        return Ok(hash_tx_bytes(blob));
+    }
+    hash_tx(&decoded.tx)
+}
+
+pub fn finalize_transaction(
+    tx_blob_b58: &str,
+    signature_b58: &str,
+) -> Result<FinalizedTx, &'static str> {
+    let tx_encoded = bs58::decode(tx_blob_b58)
+        .into_vec()
+        .map_err(|_| "invalid blob base58")?;
+    let signature = bs58::decode(signature_b58)
+        .into_vec()
+        .map_err(|_| "invalid signature base58")?;
     let tx: Tx = vecpak::from_slice(&tx_encoded).map_err(|_| "failed to decode tx")?;
-    let hash: [u8; 32] = Sha256::digest(&tx_encoded).into();
+    // the envelope carries the re-encoded tx, so it must match the signed bytes
+    if vecpak::to_vec(&tx).map_err(|_| "failed to encode tx")? != tx_encoded {
+        return Err("transaction blob is not canonically encoded");
+    }
+    let hash = hash_tx_bytes(&tx_encoded);
 
     let txu = TxU {
         hash: hash.to_vec(),
@@ -71,6 +210,30 @@ pub fn finalize_transaction(tx_blob_b58: &str, signature_b58: &str) -> Result<Fi
     Ok(FinalizedTx { packed, hash })
 }
 
+/// Last nonce handed out by `default_nonce`. Nanosecond timestamps fit in an
+/// i64 until 2262.
+static LAST_NONCE: AtomicI64 = AtomicI64::new(0);
+
+/// Current time in nanoseconds, used as the nonce when the caller does not supply one.
+/// Strictly increasing per process: builds within the same clock tick (a whole
+/// millisecond under WASM) get `last + 1` instead of a repeated nonce.
+pub fn default_nonce() -> i128 {
+    #[cfg(target_arch = "wasm32")]
+    let now = js_sys::Date::now() as i64 * 1_000_000;
+    #[cfg(not(target_arch = "wasm32"))]
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as i64;
+
+    let previous = LAST_NONCE
+        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |last| {
+            Some(now.max(last + 1))
+        })
+        .unwrap_or_else(|last| last);
+    now.max(previous + 1) as i128
+}
+
 pub fn build_unsigned(
     signer_pk: &[u8],
     contract: &str,
@@ -78,17 +241,30 @@ pub fn build_unsigned(
     args: &[Vec<u8>],
     attached_symbol: Option<&[u8]>,
     attached_amount: Option<&[u8]>,
-    nonce: Option<i64>,
+    nonce: Option<i128>,
 ) -> Result<UnsignedTx, &'static str> {
-    let nonce_val = nonce.map(|n| n as i128).unwrap_or_else(|| {
-        #[cfg(target_arch = "wasm32")]
-        { js_sys::Date::now() as i128 * 1_000_000 }
-        #[cfg(not(target_arch = "wasm32"))]
-        { std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_nanos() as i128 }
-    });
+    build_unsigned_with_nonce(
+        signer_pk,
+        contract,
+        function,
+        args,
+        attached_symbol,
+        attached_amount,
+        nonce.unwrap_or_else(default_nonce),
+    )
+}
 
+pub fn build_unsigned_with_nonce(
+    signer_pk: &[u8],
+    contract: &str,
+    function: &str,
+    args: &[Vec<u8>],
+    attached_symbol: Option<&[u8]>,
+    attached_amount: Option<&[u8]>,
+    nonce_val: i128,
+) -> Result<UnsignedTx, &'static str> {
     let action = TxAction {
-        op: "call".to_string(),
+        op: CALL_OP.to_string(),
         contract: contract.to_string(),
         function: function.to_string(),
         args: args.to_vec(),
@@ -103,7 +279,7 @@ pub fn build_unsigned(
     };
 
     let tx_encoded = vecpak::to_vec(&tx).map_err(|_| "failed to encode tx")?;
-    let hash: [u8; 32] = Sha256::digest(&tx_encoded).into();
+    let hash = hash_tx_bytes(&tx_encoded);
 
     Ok(UnsignedTx {
         tx_blob: tx_encoded,
@@ -111,49 +287,608 @@ pub fn build_unsigned(
     })
 }
 
-#[cfg(target_arch = "wasm32")]
+pub fn verify(
+    pk_bytes: &[u8],
+    message: &[u8],
+    signature: &[u8],
+    dst: &[u8],
+) -> Result<bool, &'static str> {
+    let pk = blst::min_pk::PublicKey::from_bytes(pk_bytes).map_err(|_| "invalid public key")?;
+    let sig = blst::min_pk::Signature::from_bytes(signature).map_err(|_| "invalid signature")?;
+    Ok(sig.verify(true, message, dst, &[], &pk, true) == blst::BLST_ERROR::BLST_SUCCESS)
+}
+
+pub struct SignedTxCheck {
+    pub signer: Vec<u8>,
+    pub stored_hash: Vec<u8>,
+    pub computed_hash: [u8; 32],
+    pub signature_valid: bool,
+}
+
+impl SignedTxCheck {
+    pub fn hash_matches(&self) -> bool {
+        self.stored_hash == self.computed_hash
+    }
+}
+
+/// Checks a signed `TxU` envelope offline: recomputes `Sha256(vecpak(tx))` and
+/// verifies the signature over it against the embedded signer. A malformed
+/// signature counts as invalid rather than failing the check.
+pub fn check_signed(blob: &[u8], dst: &[u8]) -> Result<SignedTxCheck, &'static str> {
+    let txu: TxU = vecpak::from_slice(blob).map_err(|_| "not a signed transaction envelope")?;
+    let computed_hash = hash_tx(&txu.tx)?;
+    let signature_valid =
+        verify(&txu.tx.signer, &computed_hash, &txu.signature, dst).unwrap_or(false);
+
+    Ok(SignedTxCheck {
+        signer: txu.tx.signer,
+        stored_hash: txu.hash,
+        computed_hash,
+        signature_valid,
+    })
+}
+
+/// Signing key material. The bytes are only reachable inside this module and
+/// `Debug`/`Display` never print them, so the key cannot leak through logs,
+/// error messages or tracing fields.
+pub struct SecretKey(Vec<u8>);
+
+impl SecretKey {
+    pub fn from_b58(encoded: &str) -> Result<Self, &'static str> {
+        bs58::decode(encoded)
+            .into_vec()
+            .map(Self)
+            .map_err(|_| "invalid secret key encoding")
+    }
+}
+
+impl std::fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SecretKey(redacted)")
+    }
+}
+
+impl std::fmt::Display for SecretKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SecretKey(redacted)")
+    }
+}
+
+fn secret_scalar(sk: &SecretKey) -> Result<bls12_381::Scalar, &'static str> {
+    let bytes_64: [u8; 64] =
+        sk.0.as_slice()
+            .try_into()
+            .map_err(|_| "secret key must be 64 bytes")?;
+    Ok(bls12_381::Scalar::from_bytes_wide(&bytes_64))
+}
+
+pub fn public_key_from_secret(sk: &SecretKey) -> Result<Vec<u8>, &'static str> {
+    use group::Curve;
+
+    let sk_scalar = secret_scalar(sk)?;
+    Ok((bls12_381::G1Projective::generator() * sk_scalar)
+        .to_affine()
+        .to_compressed()
+        .to_vec())
+}
+
+/// Account addresses are the 48-byte compressed G1 public key.
+pub fn address_from_public_key(pk: &[u8]) -> Result<[u8; 48], &'static str> {
+    pk.try_into().map_err(|_| "public key must be 48 bytes")
+}
+
+/// An account: the 48-byte public key transactions carry, and the base58 form
+/// requests and responses use. Every base58 address is decoded and
+/// length-checked here, so the two can never disagree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Address {
+    bytes: [u8; 48],
+    b58: String,
+}
+
+impl Address {
+    pub fn from_b58(encoded: &str) -> Result<Self, &'static str> {
+        let bytes = bs58::decode(encoded)
+            .into_vec()
+            .map_err(|_| "invalid address base58")?;
+        Self::from_bytes(&bytes)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, &'static str> {
+        let bytes = bytes.try_into().map_err(|_| "address must be 48 bytes")?;
+        Ok(Self {
+            bytes,
+            b58: bs58::encode(bytes).into_string(),
+        })
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 48] {
+        &self.bytes
+    }
+
+    pub fn as_b58(&self) -> &str {
+        &self.b58
+    }
+}
+
+impl std::fmt::Display for Address {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.b58)
+    }
+}
+
+impl std::str::FromStr for Address {
+    type Err = &'static str;
+
+    fn from_str(encoded: &str) -> Result<Self, Self::Err> {
+        Self::from_b58(encoded)
+    }
+}
+
 pub struct BuiltTx {
     pub packed: Vec<u8>,
     pub hash: [u8; 32],
 }
 
-#[cfg(target_arch = "wasm32")]
+/// Coins forwarded to the called contract alongside the action.
+#[derive(Debug, Clone)]
+pub struct Attachment {
+    pub symbol: String,
+    pub amount: Amount,
+}
+
 pub fn build_transfer_tx(
-    sk_bytes: &[u8],
-    receiver: &[u8],
+    sk: &SecretKey,
+    receiver: &Address,
     symbol: &str,
-    amount: i128,
+    amount: Amount,
+    params: &SigningParams,
 ) -> Result<BuiltTx, &'static str> {
-    use bls12_381::Scalar;
-    use group::Curve;
+    build_transfer_tx_with_nonce(sk, receiver, symbol, amount, None, None, None, params)
+}
+
+pub fn build_transfer_tx_with_nonce(
+    sk: &SecretKey,
+    receiver: &Address,
+    symbol: &str,
+    amount: Amount,
+    memo: Option<&str>,
+    attachment: Option<&Attachment>,
+    nonce: Option<i128>,
+    params: &SigningParams,
+) -> Result<BuiltTx, &'static str> {
+    if let Some(memo) = memo {
+        check_memo(memo.as_bytes())?;
+    }
+    build_with_params(
+        sk,
+        "Coin",
+        "transfer",
+        &transfer_args(receiver, symbol, amount, memo),
+        attachment,
+        nonce,
+        params,
+    )
+}
+
+/// Builds an unsigned `Coin.transfer` without any node access. The blob is
+/// byte-identical to one built through `create_transaction` with the same
+/// signer, arguments, and nonce.
+pub fn build_unsigned_transfer(
+    signer_pk: &[u8],
+    receiver: &Address,
+    symbol: &str,
+    amount: Amount,
+    memo: Option<&str>,
+    nonce: Option<i128>,
+) -> Result<UnsignedTx, &'static str> {
+    if amount.is_zero() {
+        return Err("amount must be positive");
+    }
+    if let Some(memo) = memo {
+        check_memo(memo.as_bytes())?;
+    }
+    build_unsigned(
+        signer_pk,
+        "Coin",
+        "transfer",
+        &transfer_args(receiver, symbol, amount, memo),
+        None,
+        None,
+        nonce,
+    )
+}
+
+/// Longest memo a transfer may carry, in bytes of UTF-8.
+pub const MAX_MEMO_BYTES: usize = 256;
+
+/// Memos travel as a fourth `Coin.transfer` argument holding UTF-8 text.
+pub fn check_memo(memo: &[u8]) -> Result<(), &'static str> {
+    if memo.len() > MAX_MEMO_BYTES {
+        return Err("memo exceeds 256 bytes");
+    }
+    std::str::from_utf8(memo)
+        .map(|_| ())
+        .map_err(|_| "memo must be valid UTF-8")
+}
 
-    if sk_bytes.len() != 64 {
-        return Err("secret key must be 64 bytes");
+/// Arguments of `Coin.transfer`: receiver, amount in atoms, symbol, and an
+/// optional memo.
+pub fn transfer_args(
+    receiver: &Address,
+    symbol: &str,
+    amount: Amount,
+    memo: Option<&str>,
+) -> Vec<Vec<u8>> {
+    let mut args = vec![
+        receiver.as_bytes().to_vec(),
+        amount.to_string().as_bytes().to_vec(),
+        symbol.as_bytes().to_vec(),
+    ];
+    if let Some(memo) = memo {
+        args.push(memo.as_bytes().to_vec());
     }
-    let bytes_64: [u8; 64] = sk_bytes.try_into().map_err(|_| "invalid sk length")?;
-    let sk_scalar = Scalar::from_bytes_wide(&bytes_64);
-    let pk = (bls12_381::G1Projective::generator() * sk_scalar).to_affine().to_compressed().to_vec();
+    args
+}
+
+/// Contract that holds delegated stake. Staking attaches the coins to the call;
+/// unstaking names the amount and symbol to release as arguments.
+pub const STAKE_CONTRACT: &str = "Stake";
+
+pub fn build_with_params(
+    sk: &SecretKey,
+    contract: &str,
+    function: &str,
+    args: &[Vec<u8>],
+    attachment: Option<&Attachment>,
+    nonce: Option<i128>,
+    params: &SigningParams,
+) -> Result<BuiltTx, &'static str> {
+    build_with_nonce(
+        sk,
+        contract,
+        function,
+        args,
+        attachment,
+        nonce.unwrap_or_else(default_nonce),
+        params,
+    )
+}
+
+/// Builds and signs a call with exactly `nonce`, e.g. one sequenced from
+/// `get_account_nonce`.
+pub fn build_with_nonce(
+    sk: &SecretKey,
+    contract: &str,
+    function: &str,
+    args: &[Vec<u8>],
+    attachment: Option<&Attachment>,
+    nonce: i128,
+    params: &SigningParams,
+) -> Result<BuiltTx, &'static str> {
+    let sk_scalar = secret_scalar(sk)?;
+    let pk = address_from_public_key(&public_key_from_secret(sk)?)?.to_vec();
 
-    let nonce = js_sys::Date::now() as i128 * 1_000_000;
     let action = TxAction {
-        op: "call".to_string(),
-        contract: "Coin".to_string(),
-        function: "transfer".to_string(),
-        args: vec![receiver.to_vec(), amount.to_string().as_bytes().to_vec(), symbol.as_bytes().to_vec()],
-        attached_symbol: None,
-        attached_amount: None,
+        op: CALL_OP.to_string(),
+        contract: contract.to_string(),
+        function: function.to_string(),
+        args: args.to_vec(),
+        attached_symbol: attachment.map(|a| a.symbol.as_bytes().to_vec()),
+        attached_amount: attachment.map(|a| a.amount.to_string().as_bytes().to_vec()),
     };
 
-    let tx = Tx { signer: pk.clone(), nonce, action };
-    let tx_encoded = vecpak::to_vec(&tx).map_err(|_| "failed to encode tx")?;
-    let hash: [u8; 32] = Sha256::digest(&tx_encoded).into();
+    let tx = Tx {
+        signer: pk.clone(),
+        nonce,
+        action,
+    };
+    let hash = hash_tx(&tx)?;
 
     let mut sk_be = sk_scalar.to_bytes();
     sk_be.reverse();
-    let sk = blst::min_pk::SecretKey::from_bytes(&sk_be).map_err(|_| "invalid secret key")?;
-    let signature = sk.sign(&hash, b"AMADEUS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_TX_", &[]).to_bytes().to_vec();
+    let blst_sk = blst::min_pk::SecretKey::from_bytes(&sk_be).map_err(|_| "invalid secret key")?;
+    let signature = blst_sk.sign(&hash, &params.dst, &[]).to_bytes().to_vec();
 
-    let txu = TxU { hash: hash.to_vec(), signature, tx };
+    let txu = TxU {
+        hash: hash.to_vec(),
+        signature,
+        tx,
+    };
     let packed = vecpak::to_vec(&txu).map_err(|_| "failed to encode txu")?;
     Ok(BuiltTx { packed, hash })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_key() -> SecretKey {
+        SecretKey::from_b58(&bs58::encode([7u8; 64]).into_string()).unwrap()
+    }
+
+    fn unsigned_transfer() -> UnsignedTx {
+        let signer = public_key_from_secret(&test_key()).unwrap();
+        let receiver = Address::from_bytes(&[9u8; 48]).unwrap();
+        let args = transfer_args(&receiver, "AMA", Amount::new(5).unwrap(), None);
+        build_unsigned_with_nonce(&signer, "Coin", "transfer", &args, None, None, 42).unwrap()
+    }
+
+    #[test]
+    fn every_hash_is_the_hash_of_the_encoded_tx() {
+        let unsigned = unsigned_transfer();
+        let decoded = decode(&unsigned.tx_blob).unwrap();
+
+        assert_eq!(unsigned.signing_hash, hash_tx_bytes(&unsigned.tx_blob));
+        assert_eq!(unsigned.signing_hash, hash_tx(&decoded.tx).unwrap());
+        assert_eq!(
+            unsigned.signing_hash,
+            compute_transaction_hash(&unsigned.tx_blob).unwrap()
+        );
+
+        let blob = bs58::encode(&unsigned.tx_blob).into_string();
+        let signature = bs58::encode([1u8; 96]).into_string();
+        let finalized = finalize_transaction(&blob, &signature).unwrap();
+        assert_eq!(finalized.hash, unsigned.signing_hash);
+        assert_eq!(
+            compute_transaction_hash(&finalized.packed).unwrap(),
+            unsigned.signing_hash
+        );
+    }
+
+    #[test]
+    fn explicit_nonce_is_used_as_given() {
+        let nonce: i128 = 1_700_000_000_000_000_042;
+        let params = SigningParams::default();
+        let built =
+            build_with_nonce(&test_key(), "Coin", "transfer", &[], None, nonce, &params).unwrap();
+        assert_eq!(decode(&built.packed).unwrap().tx.nonce, nonce);
+
+        let signer = public_key_from_secret(&test_key()).unwrap();
+        let unsigned =
+            build_unsigned(&signer, "Coin", "transfer", &[], None, None, Some(nonce)).unwrap();
+        assert_eq!(decode(&unsigned.tx_blob).unwrap().tx.nonce, nonce);
+    }
+
+    fn assert_round_trips(built: &BuiltTx) {
+        let decoded = decode(&built.packed).unwrap();
+        let envelope = TxU {
+            hash: decoded.hash.unwrap(),
+            signature: decoded.signature.unwrap(),
+            tx: decoded.tx.clone(),
+        };
+        assert_eq!(vecpak::to_vec(&envelope).unwrap(), built.packed);
+        assert_eq!(vecpak::from_slice::<TxU>(&built.packed).unwrap(), envelope);
+
+        let tx_blob = vecpak::to_vec(&decoded.tx).unwrap();
+        assert_eq!(vecpak::from_slice::<Tx>(&tx_blob).unwrap(), decoded.tx);
+        assert_eq!(hash_tx_bytes(&tx_blob), built.hash);
+    }
+
+    #[test]
+    fn transfer_round_trips_through_vecpak() {
+        let receiver = Address::from_bytes(&[9u8; 48]).unwrap();
+        let built = build_transfer_tx_with_nonce(
+            &test_key(),
+            &receiver,
+            "AMA",
+            Amount::new(1_500_000_000).unwrap(),
+            Some("rent"),
+            None,
+            Some(7),
+            &SigningParams::default(),
+        )
+        .unwrap();
+        assert_round_trips(&built);
+    }
+
+    #[test]
+    fn mint_round_trips_through_vecpak() {
+        let args = vec![b"TOKEN".to_vec(), b"1000".to_vec()];
+        let built = build_with_params(
+            &test_key(),
+            "Coin",
+            "mint",
+            &args,
+            None,
+            Some(8),
+            &SigningParams::default(),
+        )
+        .unwrap();
+        assert_round_trips(&built);
+    }
+
+    #[test]
+    fn call_with_attachment_round_trips_through_vecpak() {
+        let attachment = Attachment {
+            symbol: "AMA".to_string(),
+            amount: Amount::new(25).unwrap(),
+        };
+        let args = vec![b"x".to_vec(), vec![0, 1, 2, 255]];
+        let built = build_with_params(
+            &test_key(),
+            "Vault",
+            "deposit",
+            &args,
+            Some(&attachment),
+            Some(9),
+            &SigningParams::default(),
+        )
+        .unwrap();
+        assert_round_trips(&built);
+
+        let action = decode(&built.packed).unwrap().tx.action;
+        assert_eq!(action.attached_symbol.as_deref(), Some(&b"AMA"[..]));
+        assert_eq!(action.attached_amount.as_deref(), Some(&b"25"[..]));
+    }
+
+    /// Bytes produced by the worker's `create_transaction` before the
+    /// encoder was shared with the native client.
+    #[test]
+    fn unsigned_encoding_matches_golden_bytes() {
+        let signer =
+            bs58::decode("6VoorVmD8FaLN645nsLmM2XGQtExGm2172QYAoofDDYyyBS6JxSG3y7UPP4kg9ktfs")
+                .into_vec()
+                .unwrap();
+        let args = vec![b"a".to_vec(), b"1".to_vec()];
+        let unsigned =
+            build_unsigned_with_nonce(&signer, "Coin", "transfer", &args, None, None, 1).unwrap();
+
+        assert_eq!(
+            hex::encode(&unsigned.tx_blob),
+            "0701030501056e6f6e6365030101050106616374696f6e0701040501026f7005010463616c6c05010461726773060102\
+             0501016105010131050108636f6e7472616374050104436f696e05010866756e6374696f6e0501087472616e73666572\
+             0501067369676e657205013095d837ff1d08effb8b701e1dc7395a43c6dc6512e46464f12bd5dde6fd3ae70166a63642\
+             f5948c2c4e38540cdecd39d2"
+        );
+        assert_eq!(
+            hex::encode(unsigned.signing_hash),
+            "17c3f89ba931001eb6b4c45634e5fe35b06f2abdc7f6e7cc919d38c979e02402"
+        );
+    }
+
+    #[test]
+    fn debug_and_display_redact_the_key() {
+        let bytes = [7u8; 64];
+        let sk = SecretKey::from_b58(&bs58::encode(bytes).into_string()).unwrap();
+        for rendered in [
+            format!("{sk:?}"),
+            format!("{sk}"),
+            format!("{:?}", Some(&sk)),
+        ] {
+            assert!(rendered.contains("SecretKey(redacted)"));
+            assert!(!rendered.contains(&format!("{:?}", &bytes[..])));
+            assert!(!rendered.contains("7, 7"));
+            assert!(!rendered.contains(&bs58::encode(bytes).into_string()));
+        }
+    }
+
+    #[test]
+    fn back_to_back_builds_get_increasing_nonces() {
+        let receiver = Address::from_bytes(&[9u8; 48]).unwrap();
+        let amount = Amount::new(1).unwrap();
+        let params = SigningParams::default();
+        let first = build_transfer_tx(&test_key(), &receiver, "AMA", amount, &params).unwrap();
+        let second = build_transfer_tx(&test_key(), &receiver, "AMA", amount, &params).unwrap();
+
+        let first = decode(&first.packed).unwrap().tx.nonce;
+        let second = decode(&second.packed).unwrap().tx.nonce;
+        assert!(second > first, "{second} should exceed {first}");
+
+        let nonces: Vec<i128> = (0..1000).map(|_| default_nonce()).collect();
+        assert!(nonces.windows(2).all(|pair| pair[1] > pair[0]));
+    }
+
+    #[test]
+    fn attachments_round_trip_and_are_omitted_when_absent() {
+        let signer = public_key_from_secret(&test_key()).unwrap();
+        let with = build_unsigned_with_nonce(
+            &signer,
+            "Vault",
+            "deposit",
+            &[],
+            Some(b"AMA"),
+            Some(b"25"),
+            1,
+        )
+        .unwrap();
+        let tx: Tx = vecpak::from_slice(&with.tx_blob).unwrap();
+        assert_eq!(tx.action.attached_symbol.as_deref(), Some(&b"AMA"[..]));
+        assert_eq!(tx.action.attached_amount.as_deref(), Some(&b"25"[..]));
+        assert_eq!(vecpak::to_vec(&tx).unwrap(), with.tx_blob);
+
+        let without =
+            build_unsigned_with_nonce(&signer, "Vault", "deposit", &[], None, None, 1).unwrap();
+        let tx: Tx = vecpak::from_slice(&without.tx_blob).unwrap();
+        assert_eq!(tx.action.attached_symbol, None);
+        assert_eq!(tx.action.attached_amount, None);
+        assert!(!without
+            .tx_blob
+            .windows(b"attached_".len())
+            .any(|w| w == b"attached_"));
+    }
+
+    #[test]
+    fn memo_is_carried_as_the_fourth_transfer_arg() {
+        let receiver = Address::from_bytes(&[9u8; 48]).unwrap();
+        let amount = Amount::new(5).unwrap();
+        let built = build_transfer_tx_with_nonce(
+            &test_key(),
+            &receiver,
+            "AMA",
+            amount,
+            Some("invoice 17 ✓"),
+            None,
+            None,
+            &SigningParams::default(),
+        )
+        .unwrap();
+        let args = decode(&built.packed).unwrap().tx.action.args;
+        assert_eq!(args.len(), 4);
+        assert_eq!(args[3], "invoice 17 ✓".as_bytes());
+
+        let signer = public_key_from_secret(&test_key()).unwrap();
+        let unsigned =
+            build_unsigned_transfer(&signer, &receiver, "AMA", amount, Some("hi"), Some(1))
+                .unwrap();
+        assert_eq!(decode(&unsigned.tx_blob).unwrap().tx.action.args[3], b"hi");
+
+        let params = SigningParams::default();
+        let plain = build_transfer_tx(&test_key(), &receiver, "AMA", amount, &params).unwrap();
+        assert_eq!(decode(&plain.packed).unwrap().tx.action.args.len(), 3);
+    }
+
+    #[test]
+    fn transfer_is_signed_under_the_configured_dst() {
+        let receiver = Address::from_bytes(&[9u8; 48]).unwrap();
+        let amount = Amount::new(5).unwrap();
+        let params = SigningParams::from_dst(Some("TESTNET_SIG_DST"));
+        let built = build_transfer_tx_with_nonce(
+            &test_key(),
+            &receiver,
+            "AMA",
+            amount,
+            None,
+            None,
+            Some(3),
+            &params,
+        )
+        .unwrap();
+
+        assert!(
+            check_signed(&built.packed, &params.dst)
+                .unwrap()
+                .signature_valid
+        );
+        assert!(
+            !check_signed(&built.packed, &SigningParams::default().dst)
+                .unwrap()
+                .signature_valid
+        );
+    }
+
+    #[test]
+    fn check_memo_limits_length_and_encoding() {
+        assert!(check_memo(&[b'a'; MAX_MEMO_BYTES]).is_ok());
+        assert_eq!(
+            check_memo(&[b'a'; MAX_MEMO_BYTES + 1]),
+            Err("memo exceeds 256 bytes")
+        );
+        assert_eq!(check_memo(&[0xff, 0xfe]), Err("memo must be valid UTF-8"));
+
+        let receiver = Address::from_bytes(&[9u8; 48]).unwrap();
+        let long = "a".repeat(MAX_MEMO_BYTES + 1);
+        let amount = Amount::new(5).unwrap();
+        assert!(build_transfer_tx_with_nonce(
+            &test_key(),
+            &receiver,
+            "AMA",
+            amount,
+            Some(&long),
+            None,
+            None,
+            &SigningParams::default()
+        )
+        .is_err());
+    }
+}